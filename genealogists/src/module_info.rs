@@ -7,11 +7,15 @@ use genealogy_java_apis::service_loader::ServiceLoader;
 /// NOTE: This needs to be manually called on program start because there
 /// is no way to run one time global initialization in rust without an
 /// explicit call somewhere.
+///
+/// Unlike the original, all genealogists are provided. Which of them are used
+/// is configured with `genealogists.enabled`, which defaults to only the tag genealogist
+/// like the original.
 pub fn module_provides() {
 	ServiceLoader::register_many([
-		//GenealogistService::from(crate::repo::repo_genealogist_service::RepoGenealogistService),
-		//GenealogistService::from(crate::silly::silly_genealogist_service::SillyGenealogistService),
+		GenealogistService::from(crate::repo::repo_genealogist_service::RepoGenealogistService),
+		GenealogistService::from(crate::silly::silly_genealogist_service::SillyGenealogistService),
 		GenealogistService::from(crate::tags::tag_genealogist_service::TagGenealogistService),
-		//GenealogistService::from(crate::r#type::type_genealogist_service::TypeGenealogistService),
+		GenealogistService::from(crate::r#type::type_genealogist_service::TypeGenealogistService),
 	])
 }
@@ -37,11 +37,12 @@ impl RepoGenealogist {
 	// ```java
 	// private static final RelationType TYPE = new RelationType("repo");
 	// ```
-	r#static!(TYPE: RelationType = RelationType::new("repo".into()).unwrap());
+	r#static!(pub(crate) TYPE: RelationType = RelationType::new("repo".into()).unwrap());
 
 	/// ```java
 	/// public class RepoGenealogist implements Genealogist {
 	/// ```
+	#[allow(clippy::new_without_default)]
 	pub fn new() -> Self {
 		Self
 	}
@@ -1,5 +1,6 @@
 use crate::repo::repo_genealogist::RepoGenealogist;
 use genealogy::genealogist::genealogist_service::GenealogistServiceTrait;
+use genealogy::genealogist::relation_type::RelationType;
 use genealogy::genealogist::Genealogist;
 use genealogy::post::Post;
 use genealogy_java_apis::collection::Collection;
@@ -24,4 +25,8 @@ impl GenealogistServiceTrait for RepoGenealogistService {
 	fn procure(&self, posts: Collection<Post>) -> Result<Genealogist, Exception> {
 		Ok(RepoGenealogist::new().into())
 	}
+
+	fn relation_type(&self) -> RelationType {
+		RepoGenealogist::TYPE()
+	}
 }
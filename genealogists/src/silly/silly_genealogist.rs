@@ -46,11 +46,12 @@ impl GenealogistTrait for SillyGenealogist {
 /// public class SillyGenealogist implements Genealogist {
 /// ```
 impl SillyGenealogist {
-	r#static!(TYPE: RelationType = RelationType::new("silly".into()).unwrap());
+	r#static!(pub(crate) TYPE: RelationType = RelationType::new("silly".into()).unwrap());
 
 	/// ```java
 	/// public class SillyGenealogist implements Genealogist {
 	/// ```
+	#[allow(clippy::new_without_default)]
 	pub fn new() -> Self {
 		Self
 	}
@@ -1,16 +1,26 @@
+use genealogy::genealogist::genealogist_settings::GenealogistSettings;
 use genealogy::genealogist::relation_type::RelationType;
 use genealogy::genealogist::typed_relation::TypedRelation;
 use genealogy::genealogist::GenealogistTrait;
+use genealogy::post::tag::Tag;
 use genealogy::post::Post;
 use genealogy_java_apis::exception::Exception;
-use genealogy_java_apis::r#static;
+use genealogy_java_apis::exception::Exception::IllegalArgumentException;
+use genealogy_java_apis::set::Set;
+use genealogy_java_apis::string::JString;
+use genealogy_java_apis::{r#static, throw};
 use std::fmt::{Display, Formatter};
 
 /// ```java
 /// public class TagGenealogist implements Genealogist {
 /// ```
+/// Note: Tags that aren't shared, but are related in the tag taxonomy, get partial credit,
+/// see [`Tag::is_related_to`](genealogy::post::tag::Tag::is_related_to). How much can be configured
+/// with the setting `genealogists.settings.tag.related`, a number in the interval [0; 1].
 #[derive(Debug)]
-pub struct TagGenealogist;
+pub struct TagGenealogist {
+	related_credit: f64,
+}
 
 impl TagGenealogist {
 	/// ```java
@@ -18,13 +28,55 @@ impl TagGenealogist {
 	/// ```
 	#[allow(clippy::new_without_default)]
 	pub fn new() -> Self {
-		Self
+		Self {
+			related_credit: Self::DEFAULT_RELATED_CREDIT,
+		}
 	}
 
 	// ```java
 	// private static final RelationType TYPE = new RelationType("tag");
 	// ```
-	r#static!(TYPE: RelationType = RelationType::new("tag".into()).unwrap());
+	r#static!(pub(crate) TYPE: RelationType = RelationType::new("tag".into()).unwrap());
+
+	/// Credit for a related tag, relative to a shared tag.
+	const DEFAULT_RELATED_CREDIT: f64 = 0.5;
+
+	r#static!(RELATED: JString = "related".into());
+
+	pub fn with_settings(settings: GenealogistSettings) -> Result<Self, Exception> {
+		let mut genealogist = Self::new();
+		for key in settings.keys() {
+			if key != Self::RELATED() {
+				throw!(IllegalArgumentException(
+					format!("Unknown setting of the tag genealogist: {}", key).into()
+				));
+			}
+			let credit = settings.get(key).get()?;
+			if !(0.0..=1.0).contains(&credit) {
+				throw!(IllegalArgumentException(
+					format!("Credit for related tags should be in interval [0; 1]: {}", credit).into()
+				));
+			}
+			genealogist.related_credit = credit;
+		}
+		Ok(genealogist)
+	}
+
+	/// The credit of every tag in `tags` for the `other_tags`.
+	fn credit(&self, tags: &Set<Tag>, other_tags: &Set<Tag>) -> f64 {
+		tags.clone()
+			.into_iter()
+			.map(|tag| {
+				if other_tags.contains(&tag) {
+					1.0
+				} else if other_tags.clone().into_iter().any(|other| tag.is_related_to(&other)) {
+					self.related_credit
+				} else {
+					0.0
+				}
+			})
+			.sum()
+	}
 }
 
 /// ```java
@@ -44,20 +96,14 @@ impl GenealogistTrait for TagGenealogist {
 	///		return new TypedRelation(post1, post2, TYPE, score);
 	///	}
 	/// ```
+	/// Note: Counts the credit of every tag of both posts instead of the shared tags: 1 if it is shared,
+	/// the credit for related tags if it is related to a tag of the other post. A shared tag is counted
+	/// for both posts, like `2 * numberOfSharedTags`, so the score is symmetric and at most 100.
 	fn infer(&self, post1: Post, post2: Post) -> Result<TypedRelation, Exception> {
+		let post1_tags = post1.tags();
 		let post2_tags = post2.tags();
-		let number_of_shared_tags = post1
-			.tags()
-			.stream()
-			.filter({
-				let post2_tags = post2_tags.clone();
-				move |tag| post2_tags.contains(tag)
-			})
-			.count()?;
-		let number_of_post1_tags = post1.tags().size();
-		let score = ((100.0 * 2.0 * (number_of_shared_tags as f64))
-			/ ((number_of_post1_tags + post2_tags.size()) as f64))
-			.round() as i64;
+		let credit = self.credit(&post1_tags, &post2_tags) + self.credit(&post2_tags, &post1_tags);
+		let score = ((100.0 * credit) / ((post1_tags.size() + post2_tags.size()) as f64)).round() as i64;
 		TypedRelation::new(post1, post2, Self::TYPE(), score)
 	}
 }
@@ -67,3 +113,119 @@ impl Display for TagGenealogist {
 		formatter.write_str("TagGenealogist")
 	}
 }
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod test {
+	use super::*;
+	use genealogy::post::article::Article;
+	use genealogy::post::description::Description;
+	use genealogy::post::metadata::Metadata;
+	use genealogy::post::slug::Slug;
+	use genealogy::post::title::Title;
+	use genealogy_java_apis::list::List;
+	use genealogy_java_apis::map::Map;
+	use genealogy_java_apis::optional::Optional;
+	use genealogy_java_apis::stream::Stream;
+	use genealogy_java_apis::test::assert_that;
+	use genealogy_java_apis::time::{LocalDate, LocalDateExtension};
+	use std::collections::HashSet;
+
+	fn post(slug: &str, tags: &[Tag]) -> Post {
+		Article::new(
+			Title::new("Title".into()).unwrap(),
+			Set::from(tags.iter().cloned().collect::<HashSet<_>>()),
+			LocalDate::of(2020, 1, 1),
+			Description::new("description".into()).unwrap(),
+			Slug::new(slug.into()).unwrap(),
+			Optional::empty(),
+			(|| Stream::of(["".into()])).into(),
+			Metadata::empty(),
+		)
+		.into()
+	}
+
+	fn tag(text: &str) -> Tag {
+		Tag::with_ancestors(text.into(), List::of(["java".into()]))
+	}
+
+	fn score(genealogist: &TagGenealogist, post1: &Post, post2: &Post) -> i64 {
+		genealogist.infer(post1.clone(), post2.clone()).unwrap().score()
+	}
+
+	fn settings(related: f64) -> GenealogistSettings {
+		GenealogistSettings::new(TagGenealogist::TYPE(), Map::of([(TagGenealogist::RELATED(), related)]))
+	}
+
+	#[test]
+	fn shared_tags__counted_for_both_posts() {
+		let post1 = post("a", &[tag("jpms"), tag("streams")]);
+		let post2 = post("b", &[tag("jpms"), tag("streams"), Tag::new("clean-code".into())]);
+
+		let genealogist = TagGenealogist::new();
+
+		assert_that(score(&genealogist, &post1, &post2)).is_equal_to(80);
+		assert_that(score(&genealogist, &post2, &post1)).is_equal_to(80);
+	}
+
+	#[test]
+	fn related_tags__partial_credit_symmetric() {
+		let post1 = post("a", &[tag("jpms")]);
+		let post2 = post("b", &[tag("java-9"), tag("streams")]);
+
+		let genealogist = TagGenealogist::new();
+
+		// 0.5 for jpms, 0.5 for each of java-9 and streams, of 3 tags
+		assert_that(score(&genealogist, &post1, &post2)).is_equal_to(50);
+		assert_that(score(&genealogist, &post2, &post1)).is_equal_to(50);
+	}
+
+	#[test]
+	fn related_tags__at_most_100() {
+		let post1 = post("a", &[tag("jpms")]);
+		let post2 = post("b", &[tag("jpms"), tag("java-9")]);
+
+		let genealogist = TagGenealogist::with_settings(settings(1.0)).unwrap();
+
+		assert_that(score(&genealogist, &post1, &post2)).is_equal_to(100);
+		assert_that(score(&genealogist, &post2, &post1)).is_equal_to(100);
+	}
+
+	#[test]
+	fn unrelated_tags__zero() {
+		let post1 = post("a", &[tag("jpms")]);
+		let post2 = post("b", &[Tag::new("clean-code".into())]);
+
+		let genealogist = TagGenealogist::new();
+
+		assert_that(score(&genealogist, &post1, &post2)).is_equal_to(0);
+	}
+
+	#[test]
+	fn with_settings__related_credit_used() {
+		let post1 = post("a", &[tag("jpms")]);
+		let post2 = post("b", &[tag("java-9")]);
+
+		let genealogist = TagGenealogist::with_settings(settings(0.25)).unwrap();
+
+		assert_that(score(&genealogist, &post1, &post2)).is_equal_to(25);
+	}
+
+	#[test]
+	fn with_settings__invalid__error() {
+		let unknown = GenealogistSettings::new(TagGenealogist::TYPE(), Map::of([("unknown".into(), 0.5)]));
+
+		assert!(matches!(
+			TagGenealogist::with_settings(settings(1.5)),
+			Err(IllegalArgumentException(_))
+		));
+		assert!(matches!(
+			TagGenealogist::with_settings(settings(-0.5)),
+			Err(IllegalArgumentException(_))
+		));
+		assert!(matches!(
+			TagGenealogist::with_settings(unknown),
+			Err(IllegalArgumentException(_))
+		));
+	}
+}
@@ -1,28 +1,65 @@
+use genealogy::genealogist::genealogist_settings::GenealogistSettings;
 use genealogy::genealogist::relation_type::RelationType;
 use genealogy::genealogist::typed_relation::TypedRelation;
 use genealogy::genealogist::GenealogistTrait;
 use genealogy::post::Post;
 use genealogy_java_apis::exception::Exception;
-use genealogy_java_apis::r#static;
+use genealogy_java_apis::exception::Exception::IllegalArgumentException;
+use genealogy_java_apis::string::JString;
+use genealogy_java_apis::{r#static, throw};
+use std::collections::HashMap;
 use std::fmt::{Display, Formatter};
 
 /// ```java
 /// public class TypeGenealogist implements Genealogist {
 /// ```
+/// Note: The scores of the kinds of posts can be configured with the settings of the genealogist,
+/// `genealogists.settings.type.<kind> = <score>`. Without settings, the scores are those of the Java version,
+/// other kinds of posts score 0.
 #[derive(Debug)]
-pub struct TypeGenealogist;
+pub struct TypeGenealogist {
+	scores_by_kind: HashMap<JString, i64>,
+}
 
 impl TypeGenealogist {
 	// ```java
 	// 	private static final RelationType TYPE = new RelationType("type");
 	// ```
-	r#static!(TYPE: RelationType = RelationType::new("type".into()).unwrap());
+	r#static!(pub(crate) TYPE: RelationType = RelationType::new("type".into()).unwrap());
+
+	/// The scores of the Java version.
+	const DEFAULT_SCORES: [(&'static str, i64); 3] = [("article", 50), ("video", 90), ("talk", 20)];
 
 	/// ```java
 	/// public class TypeGenealogist implements Genealogist {
 	/// ```
+	#[allow(clippy::new_without_default)]
 	pub fn new() -> Self {
-		Self
+		Self {
+			scores_by_kind: Self::DEFAULT_SCORES
+				.into_iter()
+				.map(|(kind, score)| (kind.into(), score))
+				.collect(),
+		}
+	}
+
+	/// The configured scores replace the default ones of the same kinds.
+	pub fn with_settings(settings: GenealogistSettings) -> Result<Self, Exception> {
+		let mut genealogist = Self::new();
+		for kind in settings.keys() {
+			let score = settings.get(kind.clone()).get()?;
+			if score.fract() != 0.0 || !(0.0..=100.0).contains(&score) {
+				throw!(IllegalArgumentException(
+					format!(
+						"Score of '{}' should be a whole number in interval [0; 100]: {}",
+						kind, score
+					)
+					.into()
+				));
+			}
+			genealogist.scores_by_kind.insert(kind, score as i64);
+		}
+		Ok(genealogist)
 	}
 }
 
@@ -42,13 +79,9 @@ impl GenealogistTrait for TypeGenealogist {
 	///		return new TypedRelation(post1, post2, TYPE, score);
 	///	}
 	/// ```
+	/// Note: The scores are looked up by the kind of `post2`, see [`TypeGenealogist`].
 	fn infer(&self, post1: Post, post2: Post) -> Result<TypedRelation, Exception> {
-		use Post::*;
-		let score = match post2 {
-			Article(_) => 50,
-			Video(_) => 90,
-			Talk(_) => 20,
-		};
+		let score = self.scores_by_kind.get(&post2.kind()).copied().unwrap_or(0);
 
 		TypedRelation::new(post1, post2, Self::TYPE(), score)
 	}
@@ -59,3 +92,68 @@ impl Display for TypeGenealogist {
 		formatter.write_str("TypeGenealogist")
 	}
 }
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod test {
+	use super::*;
+	use genealogy::post::article::Article;
+	use genealogy::post::description::Description;
+	use genealogy::post::metadata::Metadata;
+	use genealogy::post::slug::Slug;
+	use genealogy::post::tag::Tag;
+	use genealogy::post::title::Title;
+	use genealogy_java_apis::map::Map;
+	use genealogy_java_apis::optional::Optional;
+	use genealogy_java_apis::stream::Stream;
+	use genealogy_java_apis::test::assert_that;
+	use genealogy_java_apis::time::{LocalDate, LocalDateExtension};
+
+	fn article(slug: &str) -> Post {
+		Article::new(
+			Title::new("Title".into()).unwrap(),
+			Tag::from("[Tag]".into()).unwrap(),
+			LocalDate::of(2020, 1, 1),
+			Description::new("description".into()).unwrap(),
+			Slug::new(slug.into()).unwrap(),
+			Optional::empty(),
+			(|| Stream::of(["".into()])).into(),
+			Metadata::empty(),
+		)
+		.into()
+	}
+
+	fn settings(scores: &[(&str, f64)]) -> GenealogistSettings {
+		GenealogistSettings::new(
+			TypeGenealogist::TYPE(),
+			Map::of(scores.iter().map(|(kind, score)| ((*kind).into(), *score))),
+		)
+	}
+
+	fn score_of_article(genealogist: &TypeGenealogist) -> i64 {
+		genealogist.infer(article("a"), article("b")).unwrap().score()
+	}
+
+	#[test]
+	fn new__scores_of_java_version() {
+		assert_that(score_of_article(&TypeGenealogist::new())).is_equal_to(50);
+	}
+
+	#[test]
+	fn with_settings__configured_kinds_overridden() {
+		let overridden = TypeGenealogist::with_settings(settings(&[("article", 70.0)])).unwrap();
+		let other_kind = TypeGenealogist::with_settings(settings(&[("video", 10.0)])).unwrap();
+
+		assert_that(score_of_article(&overridden)).is_equal_to(70);
+		assert_that(score_of_article(&other_kind)).is_equal_to(50);
+	}
+
+	#[test]
+	fn with_settings__not_whole_number_in_interval__error() {
+		for score in [50.5, -1.0, 101.0] {
+			let genealogist = TypeGenealogist::with_settings(settings(&[("article", score)]));
+
+			assert!(matches!(genealogist, Err(IllegalArgumentException(_))));
+		}
+	}
+}
@@ -1,5 +1,7 @@
 use crate::r#type::type_genealogist::TypeGenealogist;
 use genealogy::genealogist::genealogist_service::GenealogistServiceTrait;
+use genealogy::genealogist::genealogist_settings::GenealogistSettings;
+use genealogy::genealogist::relation_type::RelationType;
 use genealogy::genealogist::Genealogist;
 use genealogy::post::Post;
 use genealogy_java_apis::collection::Collection;
@@ -24,4 +26,17 @@ impl GenealogistServiceTrait for TypeGenealogistService {
 	fn procure(&self, posts: Collection<Post>) -> Result<Genealogist, Exception> {
 		Ok(TypeGenealogist::new().into())
 	}
+
+	fn relation_type(&self) -> RelationType {
+		TypeGenealogist::TYPE()
+	}
+
+	#[allow(unused_variables)]
+	fn procure_with_settings(
+		&self,
+		posts: Collection<Post>,
+		settings: GenealogistSettings,
+	) -> Result<Genealogist, Exception> {
+		Ok(TypeGenealogist::with_settings(settings)?.into())
+	}
 }
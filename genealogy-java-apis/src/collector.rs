@@ -7,6 +7,7 @@ use std::collections::{HashMap, HashSet};
 use std::hash::Hash;
 
 /// The characteristics where left out because they aren't used in the original Java code anyways.
+#[allow(clippy::type_complexity)]
 pub struct Collector<Input, Accumulated, Reduced> {
 	pub supplier: Box<dyn FnOnce() -> Result<Accumulated, Exception>>,
 	pub accumulator: Box<dyn Fn(&mut Accumulated, Input) -> Result<(), Exception>>,
@@ -219,7 +220,6 @@ impl Collectors {
 	/// 		CH_NOID);
 	/// }
 	/// ```
-
 	pub fn averaging_double<Input>(
 		mapper: impl Fn(Input) -> Result<f64, Exception> + 'static,
 	) -> Collector<Input, AccumulatedDoubleAverage, f64> {
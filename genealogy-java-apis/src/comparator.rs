@@ -1,5 +1,6 @@
 use std::cmp::Ordering;
 
+#[allow(clippy::type_complexity)]
 pub struct Comparator<T> {
 	pub compare: Box<dyn Fn(&T, &T) -> Ordering>,
 }
@@ -26,7 +26,7 @@ impl<First, Second, Output> Debug for BiFunction<First, Second, Output> {
 
 impl<First, Second, Output> PartialEq for BiFunction<First, Second, Output> {
 	fn eq(&self, other: &Self) -> bool {
-		#[allow(clippy::vtable_address_comparisons)]
+		#[allow(ambiguous_wide_pointer_comparisons)]
 		std::ptr::eq(self.apply.as_ref(), other.apply.as_ref())
 	}
 }
@@ -27,7 +27,7 @@ impl<First, Second> Debug for BiPredicate<First, Second> {
 
 impl<First, Second> PartialEq for BiPredicate<First, Second> {
 	fn eq(&self, other: &Self) -> bool {
-		#[allow(clippy::vtable_address_comparisons)]
+		#[allow(ambiguous_wide_pointer_comparisons)]
 		std::ptr::eq(self.test.as_ref(), other.test.as_ref())
 	}
 }
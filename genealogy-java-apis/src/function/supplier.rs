@@ -41,7 +41,7 @@ impl<Output> Debug for Supplier<Output> {
 
 impl<Output> PartialEq for Supplier<Output> {
 	fn eq(&self, other: &Self) -> bool {
-		#[allow(clippy::vtable_address_comparisons)]
+		#[allow(ambiguous_wide_pointer_comparisons)]
 		std::ptr::eq(self.get.as_ref(), other.get.as_ref())
 	}
 }
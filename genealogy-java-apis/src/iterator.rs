@@ -17,18 +17,14 @@ where
 	}
 }
 
+#[derive(Default)]
 pub enum ResultIterator<OkIterator, Error> {
 	Ok(OkIterator),
 	Err(Error),
+	#[default]
 	Finished,
 }
 
-impl<OkIterator, Error> Default for ResultIterator<OkIterator, Error> {
-	fn default() -> Self {
-		Self::Finished
-	}
-}
-
 impl<OkIterator, OkType, Error> Iterator for ResultIterator<OkIterator, Error>
 where
 	OkIterator: Iterator<Item = OkType>,
@@ -41,12 +41,7 @@ impl<Key, Value> Map<Key, Value> {
 		Key: Eq + Hash,
 		Value: Clone,
 	{
-		self.map
-			.as_ref()
-			.borrow()
-			.get(&key)
-			.map(Clone::clone)
-			.unwrap_or(default)
+		self.map.as_ref().borrow().get(&key).cloned().unwrap_or(default)
 	}
 
 	pub fn entry_set(self) -> Set<Entry<Key, Value>>
@@ -70,7 +65,7 @@ impl<Key, Value> Map<Key, Value> {
 		&mut self,
 		key: Key,
 		mapping_function: impl FnOnce(Key) -> Value + 'static,
-	) -> RefMut<Value>
+	) -> RefMut<'_, Value>
 	where
 		Key: Clone + Eq + Hash,
 	{
@@ -53,7 +53,7 @@ impl<Service> ServiceLoader<Service> {
 
 pub trait Class {
 	fn class() -> PhantomData<Self> {
-		PhantomData::default()
+		PhantomData
 	}
 }
 
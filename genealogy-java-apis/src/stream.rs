@@ -21,7 +21,7 @@ where
 		NewItem: 'static,
 	{
 		self.iterator
-			.map(move |result| result.map(|item| (&mut mapper)(item)).and_then(identity))
+			.map(move |result| result.map(&mut mapper).and_then(identity))
 			.into()
 	}
 
@@ -40,7 +40,7 @@ where
 
 	pub fn filter(self, mut predicate: impl FnMut(&Item) -> bool + 'static) -> Self {
 		self.iterator
-			.filter(move |result| result.as_ref().ok().map(|item| predicate(item)).unwrap_or(true))
+			.filter(move |result| result.as_ref().ok().map(&mut predicate).unwrap_or(true))
 			.into()
 	}
 
@@ -78,13 +78,13 @@ where
 
 	pub fn drop_while(self, predicate: impl Fn(&Item) -> bool + 'static) -> Self {
 		self.iterator
-			.skip_while(move |result| result.as_ref().map(|item| (&predicate)(item)).unwrap_or(false))
+			.skip_while(move |result| result.as_ref().map(&predicate).unwrap_or(false))
 			.into()
 	}
 
 	pub fn take_while(self, predicate: impl Fn(&Item) -> bool + 'static) -> Self {
 		self.iterator
-			.take_while(move |result| result.as_ref().map(|item| (&predicate)(item)).unwrap_or(false))
+			.take_while(move |result| result.as_ref().map(&predicate).unwrap_or(false))
 			.into()
 	}
 
@@ -102,13 +102,15 @@ where
 		self.iterator
 	}
 
+	#[allow(clippy::manual_try_fold)]
 	pub fn for_each(self, mut action: impl FnMut(Item) -> Result<(), Exception> + 'static) -> Result<(), Exception> {
 		self.iterator.fold(Ok(()), move |result, item| match result {
-			Ok(_) => item.map(|item| action(item)).and_then(identity),
+			Ok(_) => item.map(&mut action).and_then(identity),
 			exception => exception,
 		})
 	}
 
+	#[allow(clippy::manual_try_fold)]
 	pub fn count(self) -> Result<i64, Exception> {
 		self.iterator.fold(Ok(0), |result, item| match result {
 			Ok(count) => item.map(|_| count + 1),
@@ -6,7 +6,7 @@ use std::fmt::{Display, Formatter};
 use std::ops::{Add, Deref};
 use std::sync::Arc;
 
-#[allow(clippy::derive_hash_xor_eq)]
+#[allow(clippy::derived_hash_with_manual_eq)]
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct JString {
 	text: Arc<String>,
@@ -67,7 +67,7 @@ impl JString {
 
 pub fn jstrings<'a>(iterable: impl IntoIterator<Item = &'a str>) -> List<JString> {
 	let vector = iterable.into_iter().map(JString::from).collect::<Vec<_>>();
-	vector.try_into().unwrap()
+	vector.into()
 }
 
 impl Display for JString {
@@ -1,4 +1,5 @@
 use crate::exception::Exception::{self, SecurityException};
+use crate::map::Map;
 use crate::string::JString;
 use crate::throw;
 
@@ -18,7 +19,23 @@ impl System {
 		.map(JString::from)
 	}
 
+	/// Note: Variables whose name or value isn't valid Unicode are left out.
+	pub fn getenv() -> Map<JString, JString> {
+		Map::of(
+			std::env::vars_os()
+				.filter_map(|(name, value)| Some((name.into_string().ok()?.into(), value.into_string().ok()?.into()))),
+		)
+	}
+
 	pub fn out_println(string: JString) {
 		println!("{}", string);
 	}
+
+	pub fn err_println(string: JString) {
+		eprintln!("{}", string);
+	}
+
+	pub fn exit(status: i32) -> ! {
+		std::process::exit(status)
+	}
 }
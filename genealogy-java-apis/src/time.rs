@@ -12,11 +12,11 @@ pub trait LocalDateExtension {
 
 impl LocalDateExtension for LocalDate {
 	fn today() -> LocalDate {
-		chrono::offset::Local::today().naive_local()
+		chrono::offset::Local::now().date_naive()
 	}
 
 	fn of(year: i32, month: u8, day: u8) -> LocalDate {
-		NaiveDate::from_ymd(year, month as u32, day as u32)
+		NaiveDate::from_ymd_opt(year, month as u32, day as u32).unwrap()
 	}
 
 	fn parse(text: JString) -> Result<LocalDate, Exception> {
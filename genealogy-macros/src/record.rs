@@ -105,7 +105,9 @@ impl ToTokens for Record {
 			fields.to_parameters(&mut parameters);
 			let mut initializers = TokenStream::new();
 			fields.to_initializers(&mut initializers);
+			// the canonical constructor takes every component, like in Java
 			quote! {
+				#[allow(clippy::too_many_arguments)]
 				#visibility fn new(#parameters) -> Self {
 					Self {
 						#initializers
@@ -107,7 +107,7 @@ impl RecordField {
 			let struct_name = format!(
 				"_Assert{}{}",
 				self.name.to_string().replace("r#", ""),
-				trait_path.segments.last().unwrap().ident.to_string()
+				trait_path.segments.last().unwrap().ident
 			);
 			let struct_name = Ident::new(&struct_name, Span::call_site());
 			tokens.extend(quote_spanned! {
@@ -0,0 +1,144 @@
+use clap::{Args, Parser, Subcommand};
+use genealogy::output::output_format::OutputFormat;
+use genealogy_java_apis::list::List;
+use genealogy_java_apis::string::JString;
+
+/// Infers relations between posts and recommends related posts.
+///
+/// The config is merged key by key from the defaults, `recommendations.toml` in the home directory,
+/// `recommendations.toml` in the working directory, the `GENEALOGY_*` environment variables and the command line,
+/// later ones take precedence. `config show` prints the effective values with where they come from.
+#[derive(Debug, Parser)]
+#[command(name = "genealogy", version, no_binary_name = true)]
+pub struct Cli {
+	#[command(flatten)]
+	pub options: Options,
+	/// Defaults to `recommend`
+	#[command(subcommand)]
+	pub command: Option<Command>,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+	/// Recommend related posts for every post
+	Recommend,
+	/// List the relations between all pairs of posts
+	Relations {
+		/// Leave out relations with a lower score
+		#[arg(long)]
+		min_score: Option<i64>,
+		/// Keep only this many relations with the highest score per post
+		#[arg(long)]
+		top_k: Option<usize>,
+	},
+	/// Write the score of every genealogist and the aggregated score for every ordered pair of posts,
+	/// one row per pair
+	Matrix,
+	/// Explain why one post is recommended for another: the score of every genealogist, its weight
+	/// and how they combine into the score and rank of the relation
+	Explain {
+		/// Slug of the post the recommendation is for
+		post: String,
+		/// Slug of the recommended post
+		recommended_post: String,
+	},
+	/// Parse all posts, check the corpus as a whole and report all errors, exits with status 1 if there are any
+	Validate,
+	/// Show statistics about the posts: counts per kind and year, tag frequencies, posts without repository,
+	/// talks with and without video and how the scores of every genealogist are distributed
+	Stats,
+	/// Write a self-contained HTML report of all posts with their reading time, tags, recommendations and the posts
+	/// recommending them
+	Report,
+	/// Write the recommended posts into the front matter of every post's file as `recommendations: ["slug", ...]`
+	WriteFrontMatter {
+		/// Print a unified diff of the changes instead of writing the files
+		#[arg(long)]
+		dry_run: bool,
+	},
+	/// Serve posts, relations and recommendations as JSON over HTTP on localhost
+	Serve {
+		/// Port to listen on
+		#[arg(long, default_value_t = 8080)]
+		port: u16,
+	},
+	/// Recommend related posts like `recommend`, then watch the content folders and recommend again
+	/// whenever posts change
+	Watch,
+	/// Inspect the config
+	Config {
+		#[command(subcommand)]
+		command: ConfigCommand,
+	},
+}
+
+#[derive(Debug, Subcommand)]
+pub enum ConfigCommand {
+	/// Print every effective config value and where it comes from
+	Show,
+}
+
+#[derive(Debug, Args)]
+pub struct Options {
+	/// Folder containing the articles
+	#[arg(long, global = true)]
+	pub article_folder: Option<String>,
+	/// Folder containing the talks
+	#[arg(long, global = true)]
+	pub talk_folder: Option<String>,
+	/// Folder containing the videos
+	#[arg(long, global = true)]
+	pub video_folder: Option<String>,
+	/// File to write the output to instead of standard output. For `hugo`, `jekyll` and `zola` this can also be
+	/// the root folder of the site, the data file is then written to where the generator expects it. An output file
+	/// from the config is only used for the recommendations, every other command writes to it only if it's given here
+	#[arg(long, short, global = true)]
+	pub output: Option<String>,
+	/// Number of recommendations per post [default: 3]
+	#[arg(long, global = true)]
+	pub per_post: Option<i32>,
+	/// Relation type of a genealogist to use, can be given multiple times [default: tag]
+	#[arg(long = "genealogist", global = true)]
+	pub genealogists: Vec<String>,
+	/// Output format: `json`, `text`, for recommendations only the data file of a static site generator:
+	/// `hugo`, `jekyll` or `zola`, for relations only a graph: `dot`, `graphml` or `gexf`, and for the matrix
+	/// only `csv` or `jsonl`. Defaults to `json` for recommendations and relations, `csv` for the matrix
+	/// and `text` otherwise
+	#[arg(long, short, global = true, value_parser = parse_format)]
+	pub format: Option<OutputFormat>,
+	/// Set any config key, e.g. `--set genealogists.weights.repo=0.5`, can be given multiple times.
+	/// Lists are separated by commas
+	#[arg(long = "set", global = true, value_name = "KEY=VALUE")]
+	pub config_values: Vec<String>,
+}
+
+impl Options {
+	/// The config values given on the command line in the form `key=value`
+	/// that [`Config::create`](genealogy::config::Config::create) expects.
+	pub fn config_arguments(&self) -> List<JString> {
+		let values = [
+			("folders.articles", self.article_folder.clone()),
+			("folders.talks", self.talk_folder.clone()),
+			("folders.videos", self.video_folder.clone()),
+			("output.file", self.output.clone()),
+			("output.format", self.format.map(|format| format.name().to_string())),
+			(
+				"recommendations.per-post",
+				self.per_post.map(|per_post| per_post.to_string()),
+			),
+			(
+				"genealogists.enabled",
+				Some(self.genealogists.join(",")).filter(|genealogists| !genealogists.is_empty()),
+			),
+		];
+		let values = values
+			.into_iter()
+			.filter_map(|(key, value)| value.map(|value| format!("{}={}", key, value)))
+			.chain(self.config_values.iter().cloned());
+		List::of(values.map(JString::from))
+	}
+}
+
+fn parse_format(name: &str) -> Result<OutputFormat, String> {
+	OutputFormat::from_name(name.into()).map_err(|exception| exception.to_string())
+}
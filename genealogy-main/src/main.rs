@@ -1,31 +1,68 @@
 #![allow(clippy::tabs_in_doc_comments)]
+use crate::cli::{Cli, Command, ConfigCommand, Options};
+use crate::server::Server;
+use crate::watch::Watch;
+use clap::Parser;
 use genealogists::module_info::module_provides;
 use genealogy::config::Config;
 use genealogy::genealogist::genealogist_service::GenealogistService;
 use genealogy::genealogist::Genealogist;
-use genealogy::genealogy::weights::Weights;
+use genealogy::genealogy::relation_scores::RelationScores;
 use genealogy::genealogy::Genealogy;
-use genealogy::post::factories::article_factory::ArticleFactory;
-use genealogy::post::factories::talk_factory::TalkFactory;
-use genealogy::post::factories::video_factory::VideoFactory;
+use genealogy::output::config_text::ConfigText;
+use genealogy::output::explanation_json::ExplanationJson;
+use genealogy::output::explanation_text::ExplanationText;
+use genealogy::output::front_matter_recommendations::FrontMatterRecommendations;
+use genealogy::output::html_report::HtmlReport;
+use genealogy::output::hugo_data::HugoData;
+use genealogy::output::jekyll_data::JekyllData;
+use genealogy::output::output_format::OutputFormat;
+use genealogy::output::recommendation_json::RecommendationJson;
+use genealogy::output::recommendation_text::RecommendationText;
+use genealogy::output::relation_dot::RelationDot;
+use genealogy::output::relation_gexf::RelationGexf;
+use genealogy::output::relation_graph::RelationGraph;
+use genealogy::output::relation_graph_ml::RelationGraphMl;
+use genealogy::output::relation_json::RelationJson;
+use genealogy::output::relation_matrix::RelationMatrix;
+use genealogy::output::relation_text::RelationText;
+use genealogy::output::statistics_json::StatisticsJson;
+use genealogy::output::statistics_text::StatisticsText;
+use genealogy::output::validation_json::ValidationJson;
+use genealogy::output::validation_text::ValidationText;
+use genealogy::output::zola_data::ZolaData;
+use genealogy::post::corpus::Corpus;
+use genealogy::post::dangling_reference::DanglingReference;
+use genealogy::post::slug::Slug;
 use genealogy::post::Post;
 use genealogy::process_details::ProcessDetails;
 use genealogy::recommendation::recommender::Recommender;
-use genealogy::recommendation::Recommendation;
+use genealogy::statistics::Statistics;
 use genealogy::utils::Utils;
+use genealogy::validation::validator::Validator;
 use genealogy_java_apis::collection::Collection;
-use genealogy_java_apis::collector::Collectors;
+use genealogy_java_apis::comparator::Comparator;
 use genealogy_java_apis::exception::Exception;
-use genealogy_java_apis::exception::Exception::IllegalArgumentException;
+use genealogy_java_apis::exception::Exception::{IllegalArgumentException, RuntimeException};
 use genealogy_java_apis::files::Files;
 use genealogy_java_apis::list::List;
+use genealogy_java_apis::optional::Optional;
 use genealogy_java_apis::path::Path;
 use genealogy_java_apis::service_loader::{Class, ServiceLoader};
 use genealogy_java_apis::stream::Stream;
 use genealogy_java_apis::string::JString;
 use genealogy_java_apis::system::System;
 use genealogy_java_apis::throw;
+use similar::TextDiff;
+use std::collections::HashMap;
 use std::env::args;
+use std::fs::File;
+use std::io;
+use std::io::{BufWriter, Write};
+
+mod cli;
+mod server;
+mod watch;
 
 /// ```java
 /// public class Main {
@@ -50,24 +87,265 @@ impl Main {
 	///				() -> System.out.println(recommendationsAsJson));
 	///	}
 	/// ```
+	/// Note: Unlike the original, the arguments are parsed as a command line interface with subcommands,
+	/// see [`Cli`]. The process details are printed to standard error so they don't end up in the output.
 	pub fn main(args: List<JString>) -> Result<(), Exception> {
-		System::out_println(ProcessDetails::details());
+		let cli = Cli::parse_from(args.into_iter().map(|arg| arg.to_string()));
+		System::err_println(ProcessDetails::details());
+
+		let options = cli.options;
+		let config = Config::create(options.config_arguments())?.join()?;
+
+		match cli.command.unwrap_or(Command::Recommend) {
+			Command::Recommend => Self::recommend(config, options),
+			Command::Relations { min_score, top_k } => Self::relations(config, options, min_score, top_k),
+			Command::Matrix => Self::matrix(config, options),
+			Command::Explain { post, recommended_post } => {
+				Self::explain(config, options, post.into(), recommended_post.into())
+			}
+			Command::Validate => Self::validate(config, options),
+			Command::Stats => Self::stats(config, options),
+			Command::Report => Self::report(config),
+			Command::WriteFrontMatter { dry_run } => Self::write_front_matter(config, dry_run),
+			Command::Serve { port } => Server::new(config)?.run(port),
+			Command::Watch => Self::watch(config, options),
+			Command::Config {
+				command: ConfigCommand::Show,
+			} => Self::show_config(config, options),
+		}
+	}
+
+	/// The `main` of the original Java code.
+	/// Note: The format given on the command line takes precedence over the one in the config.
+	fn recommend(config: Config, options: Options) -> Result<(), Exception> {
+		let output = Self::recommendations_output(Self::create_posts(&config)?, &config, &options)?;
+		Self::write_output(Self::recommendations_file(&config, &options)?, output)
+	}
+
+	fn recommendations_output(
+		posts: Collection<Post>,
+		config: &Config,
+		options: &Options,
+	) -> Result<JString, Exception> {
+		let genealogy = Self::create_genealogy(posts, config)?;
+		let recommender = Self::create_recommender(config);
+
+		let relations = genealogy.infer_relations()?;
+		let recommendations = recommender.recommend(relations, config.per_post())?;
+		match Self::recommendations_format(config, options) {
+			OutputFormat::Json => RecommendationJson::to_json(recommendations),
+			OutputFormat::Text => RecommendationText::to_text(recommendations),
+			OutputFormat::Hugo => HugoData::to_yaml(recommendations),
+			OutputFormat::Jekyll => JekyllData::to_yaml(recommendations),
+			OutputFormat::Zola => ZolaData::to_toml(recommendations),
+			format => throw!(Self::unsupported_format(format, "recommendations")),
+		}
+	}
+
+	fn recommendations_format(config: &Config, options: &Options) -> OutputFormat {
+		options
+			.format
+			.or_else(|| config.output_format().into_iter().next())
+			.unwrap_or(OutputFormat::Json)
+	}
+
+	/// If the output file is a folder and the format is the data file of a static site generator,
+	/// the folder is taken to be the root of the site and the data file is written where the generator expects it.
+	fn recommendations_file(config: &Config, options: &Options) -> Result<Optional<Path>, Exception> {
+		let data_file = Self::recommendations_format(config, options).data_file();
+		let output_file = config.output_file();
+		if !output_file.is_present() || !data_file.is_present() || !Files::is_directory(output_file.get()?) {
+			return Ok(output_file);
+		}
+
+		let file = output_file.get()?.resolve(data_file.get()?);
+		if let Some(parent) = file.as_ref().parent() {
+			std::fs::create_dir_all(parent)?;
+		}
+		Ok(Optional::of(file))
+	}
+
+	/// Errors while recommending, e.g. because of invalid posts, are reported but don't stop watching.
+	fn watch(config: Config, options: Options) -> Result<(), Exception> {
+		let output_file = Self::recommendations_file(&config, &options)?;
+		Watch::new(&config)?.run(|posts| {
+			let result = posts
+				.and_then(|posts| Self::recommendations_output(posts, &config, &options))
+				.and_then(|output| Self::write_output(output_file.clone(), output));
+			match result {
+				Ok(()) => System::err_println("Recommendations updated, watching for changes.".into()),
+				Err(exception) => System::err_println(exception.to_string().into()),
+			}
+		})
+	}
 
-		let config = Config::create(args)?.join()?;
-		let genealogy = Self::create_genealogy(config.article_folder(), config.talk_folder(), config.video_folder())?;
-		let recommender = Recommender::new();
+	/// Note: With `dry_run`, a unified diff of the changes is printed instead of writing the files.
+	fn write_front_matter(config: Config, dry_run: bool) -> Result<(), Exception> {
+		let posts_by_file = Self::create_posts_by_file(&config)?;
+		let posts = List::of(posts_by_file.iter().map(|(_, post)| post.clone()));
+		let genealogy = Self::create_genealogy(posts, &config)?;
+		let recommender = Self::create_recommender(&config);
 
 		let relations = genealogy.infer_relations()?;
-		let recommendations = recommender.recommend(relations, 3)?;
-		let recommendations_as_json = Self::recommendations_to_json(recommendations)?;
+		let mut recommendations_by_slug = HashMap::new();
+		for recommendation in recommender.recommend(relations, config.per_post())?.into_iterator() {
+			let recommendation = recommendation?;
+			recommendations_by_slug.insert(recommendation.post().slug(), recommendation);
+		}
+
+		// posts without recommendations, e.g. drafts, mustn't keep the ones they were recommended before
+		for (file, post) in posts_by_file {
+			let content = std::fs::read_to_string(&file)?;
+			let written = match recommendations_by_slug.get(&post.slug()) {
+				Some(recommendation) => FrontMatterRecommendations::write(content.as_str().into(), recommendation),
+				None => FrontMatterRecommendations::clear(content.as_str().into()),
+			}
+			.map_err(|exception| RuntimeException("Writing recommendations failed: " + &file, exception.into()))?
+			.to_string();
+			if written == content {
+				continue;
+			}
+
+			if dry_run {
+				let diff = TextDiff::from_lines(&content, &written)
+					.unified_diff()
+					.header(&file.to_string(), &file.to_string())
+					.to_string();
+				System::out_println(diff.trim_end().into());
+			} else {
+				std::fs::write(&file, written)?;
+			}
+		}
+		Ok(())
+	}
+
+	fn relations(
+		config: Config,
+		options: Options,
+		min_score: Option<i64>,
+		top_k: Option<usize>,
+	) -> Result<(), Exception> {
+		let genealogy = Self::create_genealogy(Self::create_posts(&config)?, &config)?;
+
+		let relations = genealogy.infer_relations()?;
+		let graph = RelationGraph::new(relations, min_score.into(), top_k.into())?;
+		let output = match options.format.unwrap_or(OutputFormat::Json) {
+			OutputFormat::Json => RelationJson::to_json(graph.edges().stream())?,
+			OutputFormat::Text => RelationText::to_text(graph.edges().stream())?,
+			OutputFormat::Dot => RelationDot::to_dot(&graph),
+			OutputFormat::GraphMl => RelationGraphMl::to_graph_ml(&graph),
+			OutputFormat::Gexf => RelationGexf::to_gexf(&graph),
+			format => throw!(Self::unsupported_format(format, "relations")),
+		};
+
+		Self::write_output(config.explicit_output_file(), output)
+	}
+
+	/// The rows are written while they are inferred instead of being collected first.
+	/// The format is checked before the output file is created, so an unsupported format doesn't truncate it.
+	fn matrix(config: Config, options: Options) -> Result<(), Exception> {
+		type WriteMatrix = fn(Stream<RelationScores>, Box<dyn Write>) -> Result<(), Exception>;
+		let write_matrix: WriteMatrix = match options.format.unwrap_or(OutputFormat::Csv) {
+			OutputFormat::Csv => RelationMatrix::write_csv,
+			OutputFormat::JsonLines => RelationMatrix::write_json_lines,
+			format => throw!(Self::unsupported_format(format, "the matrix")),
+		};
+		let genealogy = Self::create_genealogy(Self::create_posts(&config)?, &config)?;
+
+		let relation_scores = genealogy.infer_relation_scores();
+		let output_file = config.explicit_output_file();
+		let writer: Box<dyn Write> = if output_file.is_present() {
+			Box::new(BufWriter::new(File::create(output_file.get()?)?))
+		} else {
+			Box::new(BufWriter::new(io::stdout().lock()))
+		};
+		write_matrix(relation_scores, writer)
+	}
+
+	fn explain(config: Config, options: Options, post: JString, recommended_post: JString) -> Result<(), Exception> {
+		let genealogy = Self::create_genealogy(Self::create_posts(&config)?, &config)?;
+
+		let recommender = Self::create_recommender(&config);
+		let explanation = genealogy.explain(Slug::new(post)?, Slug::new(recommended_post)?, &recommender)?;
+		let output = match options.format.unwrap_or(OutputFormat::Text) {
+			OutputFormat::Json => ExplanationJson::to_json(&explanation)?,
+			OutputFormat::Text => ExplanationText::to_text(&explanation),
+			format => throw!(Self::unsupported_format(format, "explanations")),
+		};
+
+		Self::write_output(config.explicit_output_file(), output)
+	}
+
+	/// Note: Exits with status 1 if there are errors, after reporting all of them.
+	fn validate(config: Config, options: Options) -> Result<(), Exception> {
+		let mut files = Vec::new();
+		for content_root in config.content_roots() {
+			files.push((content_root.post_type(), content_root.files()?));
+		}
+		let errors = Validator::validate(files, &config.slug_rules())?;
+		let output = match options.format.unwrap_or(OutputFormat::Text) {
+			OutputFormat::Json => ValidationJson::to_json(errors.clone())?,
+			OutputFormat::Text => ValidationText::to_text(errors.clone()),
+			format => throw!(Self::unsupported_format(format, "validation errors")),
+		};
 
-		config.output_file().if_present_or_else(
-			|output_file| Utils::unchecked_files_write(output_file, recommendations_as_json.clone()),
-			|| System::out_println(recommendations_as_json.clone()),
-		)?;
+		Self::write_output(config.explicit_output_file(), output)?;
+		if !errors.is_empty() {
+			System::exit(1);
+		}
 		Ok(())
 	}
 
+	fn stats(config: Config, options: Options) -> Result<(), Exception> {
+		let posts = Self::create_posts(&config)?;
+		let genealogy = Self::create_genealogy(posts.clone(), &config)?;
+
+		let statistics = Statistics::compute(posts, genealogy.infer_relation_scores())?;
+		let output = match options.format.unwrap_or(OutputFormat::Text) {
+			OutputFormat::Json => StatisticsJson::to_json(&statistics)?,
+			OutputFormat::Text => StatisticsText::to_text(&statistics),
+			format => throw!(Self::unsupported_format(format, "statistics")),
+		};
+
+		Self::write_output(config.explicit_output_file(), output)
+	}
+
+	fn report(config: Config) -> Result<(), Exception> {
+		let genealogy = Self::create_genealogy(Self::create_posts(&config)?, &config)?;
+		let recommender = Self::create_recommender(&config);
+
+		let relations = genealogy.infer_relations()?.to_list()?;
+		let recommendations = recommender.recommend(relations.stream(), config.per_post())?;
+		let output = HtmlReport::to_html(relations.stream(), recommendations)?;
+
+		Self::write_output(config.explicit_output_file(), output)
+	}
+
+	fn show_config(config: Config, options: Options) -> Result<(), Exception> {
+		let output = match options.format.unwrap_or(OutputFormat::Text) {
+			OutputFormat::Text => ConfigText::to_text(&config),
+			format => throw!(Self::unsupported_format(format, "the config")),
+		};
+
+		Self::write_output(config.explicit_output_file(), output)
+	}
+
+	fn unsupported_format(format: OutputFormat, output: &str) -> Exception {
+		IllegalArgumentException(format!("The output format {} isn't supported for {}", format, output).into())
+	}
+
+	/// ```java
+	/// config.outputFile().ifPresentOrElse(
+	/// 		outputFile -> Utils.uncheckedFilesWrite(outputFile, recommendationsAsJson),
+	/// 		() -> System.out.println(recommendationsAsJson));
+	/// ```
+	fn write_output(output_file: Optional<Path>, output: JString) -> Result<(), Exception> {
+		output_file.if_present_or_else(
+			|output_file| Utils::unchecked_files_write(output_file, output.clone()),
+			|| System::out_println(output.clone()),
+		)
+	}
+
 	/// ```java
 	/// private static Genealogy createGenealogy(Path articleFolder, Path talkFolder, Path videoFolder) {
 	///		List<Post> posts = concat(
@@ -79,35 +357,52 @@ impl Main {
 	///		return new Genealogy(posts, genealogists, Weights.allEqual());
 	///	}
 	/// ```
-	fn create_genealogy(article_folder: Path, talk_folder: Path, video_folder: Path) -> Result<Genealogy, Exception> {
-		let posts = Utils::concat([
-			Self::markdown_files_in(article_folder)?
-				.map(ArticleFactory::create_article)
-				.map(|article| Ok(Post::from(article))),
-			Self::markdown_files_in(talk_folder)?
-				.map(TalkFactory::create_talk)
-				.map(|talk| Ok(Post::from(talk))),
-			Self::markdown_files_in(video_folder)?
-				.map(VideoFactory::create_video)
-				.map(|video| Ok(Post::from(video))),
-		])
-		.to_list()?;
-		let genealogists = Self::get_genealogists(posts.clone())?;
-		Ok(Genealogy::new(posts, genealogists, Weights::all_equal()))
+	/// Note: Creating the posts was split off into [`Main::create_posts`] so that subcommands
+	/// can use the posts without creating a genealogy. The genealogists and weights come from the config.
+	fn create_genealogy(posts: Collection<Post>, config: &Config) -> Result<Genealogy, Exception> {
+		let genealogist_types = config
+			.genealogists()
+			.into_iter()
+			.map(|genealogist| genealogist.to_string())
+			.collect::<Vec<_>>();
+		let genealogists = Self::get_genealogists(posts.clone(), &genealogist_types, config)?;
+		Ok(Genealogy::new(posts, genealogists, config.weights()))
 	}
 
-	/// ```java
-	/// private static Stream<Path> markdownFilesIn(Path folder) {
-	///		return Utils.uncheckedFilesList(folder)
-	///				.filter(Files::isRegularFile)
-	///				.filter(file -> file.toString().endsWith(".md"));
-	///	}
-	/// ```
-	fn markdown_files_in(folder: Path) -> Result<Stream<Path>, Exception> {
-		#[allow(clippy::redundant_closure)] // doesn't compile without the closure
-		Ok(Utils::unchecked_files_list(folder)?
-			.filter(|file| Files::is_regular_file(file))
-			.filter(|file| file.to_string().ends_with(".md")))
+	/// Posts that aren't published as of the configured date aren't recommended,
+	/// and only get recommendations themselves for previews.
+	fn create_recommender(config: &Config) -> Recommender {
+		Recommender::as_of(config.as_of(), config.preview())
+	}
+
+	/// The posts are ordered by slug, so that everything inferred from them comes out in the same order
+	/// on every run, independent of the order in which the file system lists the files.
+	fn create_posts(config: &Config) -> Result<Collection<Post>, Exception> {
+		let posts = Self::create_posts_by_file(config)?.into_iter().map(|(_, post)| post);
+		Stream::of(posts).sorted(Comparator::comparing(Post::slug))?.to_list()
+	}
+
+	/// Like [`Main::create_posts`], but keeps track of the file every post was created from.
+	/// Two posts with the same slug are an error, see [`Corpus::check_unique_slugs`]. Talks and videos are linked,
+	/// talks whose video doesn't exist are reported on standard error, see [`Corpus::link_talks_and_videos`].
+	fn create_posts_by_file(config: &Config) -> Result<Vec<(Path, Post)>, Exception> {
+		let mut posts = Vec::new();
+		for content_root in config.content_roots() {
+			for file in content_root.files()?.into_iterator() {
+				let file = file?;
+				posts.push((file.clone(), content_root.create_post(file)?));
+			}
+		}
+		Corpus::check_unique_slugs(&posts)?;
+		let (posts, dangling_references) = Corpus::link_talks_and_videos(posts);
+		Self::report_dangling_references(dangling_references);
+		Ok(posts)
+	}
+
+	fn report_dangling_references(dangling_references: List<DanglingReference>) {
+		for dangling_reference in dangling_references {
+			System::err_println(dangling_reference.describe());
+		}
 	}
 
 	/// ```java
@@ -122,88 +417,47 @@ impl Main {
 	///		return genealogists;
 	///	}
 	/// ```
-	fn get_genealogists(posts: Collection<Post>) -> Result<Collection<Genealogist>, Exception> {
-		let genealogists = ServiceLoader::load(GenealogistService::class())
+	/// Note: Only the genealogists with the given relation types are procured, with their settings from the config.
+	fn get_genealogists(
+		posts: Collection<Post>,
+		genealogist_types: &[String],
+		config: &Config,
+	) -> Result<Collection<Genealogist>, Exception> {
+		let services = ServiceLoader::load(GenealogistService::class())
 			.stream()?
 			.map(|provider| Ok(provider.get()))
-			.map(move |service| service.procure(posts.clone()))
+			.to_list()?;
+		for genealogist_type in genealogist_types {
+			let is_known = services
+				.clone()
+				.into_iter()
+				.any(|service| service.relation_type().value() == genealogist_type.as_str());
+			if !is_known {
+				throw!(IllegalArgumentException(
+					JString::from("Unknown genealogist: ") + genealogist_type
+				));
+			}
+		}
+
+		let genealogist_types = genealogist_types.to_vec();
+		let config = config.clone();
+		let genealogists = services
+			.stream()
+			.filter(move |service| {
+				genealogist_types
+					.iter()
+					.any(|genealogist_type| service.relation_type().value() == genealogist_type.as_str())
+			})
+			.map(move |service| {
+				let settings = config.settings_of(service.relation_type());
+				service.procure_with_settings(posts.clone(), settings)
+			})
 			.to_list()?;
 		if genealogists.is_empty() {
 			throw!(IllegalArgumentException("No genealogists found.".into()));
 		}
 		Ok(genealogists)
 	}
-
-	/// ```java
-	/// private static String recommendationsToJson(Stream<Recommendation> recommendations) {
-	///		var frame = """
-	///				[
-	///				$RECOMMENDATIONS
-	///				]
-	///				""";
-	///		var recommendation = """
-	///					{
-	///						"title": "$TITLE",
-	///						"recommendations": [
-	///				$RECOMMENDED_POSTS
-	///						]
-	///					}
-	///				""";
-	///		var recommendedPost = """
-	///				\t\t\t{ "title": "$TITLE" }""";
-	///
-	///		var recs = recommendations
-	///				.map(rec -> {
-	///					String posts = rec
-	///							.recommendedPosts().stream()
-	///							.map(recArt -> recArt.title().text())
-	///							.map(recTitle -> recommendedPost.replace("$TITLE", recTitle))
-	///							.collect(joining(",\n"));
-	///					return recommendation
-	///							.replace("$TITLE", rec.post().title().text())
-	///							.replace("$RECOMMENDED_POSTS", posts);
-	///				})
-	///				.collect(joining(",\n"));
-	///		return frame.replace("$RECOMMENDATIONS", recs);
-	///	}
-	/// ```
-	fn recommendations_to_json(recommendations: Stream<Recommendation>) -> Result<JString, Exception> {
-		let frame = JString::from(
-			r#"[
-$RECOMMENDATIONS
-]
-"#,
-		);
-		let recommendation = JString::from(
-			r#"	{
-	"title": "$TITLE",
-	"recommendations": [
-$RECOMMENDED_POSTS
-		]
-	}
-"#,
-		);
-		let recommended_post = JString::from(r#"			{ "title": "$TITLE" }"#);
-
-		let recs = recommendations
-			.map(move |rec| {
-				let posts = rec
-					.recommended_posts()
-					.stream()
-					.map(|rec_art| Ok(rec_art.title().text()))
-					.map({
-						let recommended_post = recommended_post.clone();
-						move |rec_title| Ok(recommended_post.clone().replace("$TITLE", rec_title))
-					})
-					.collect(Collectors::joining(",\n"))?;
-				Ok(recommendation
-					.clone()
-					.replace("$TITLE", rec.post().title().text())
-					.replace("$RECOMMENDED_POSTS", posts))
-			})
-			.collect(Collectors::joining(",\n"))?;
-		Ok(frame.replace("$RECOMMENDATIONS", recs))
-	}
 }
 
 fn main() -> Result<(), Exception> {
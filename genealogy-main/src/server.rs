@@ -0,0 +1,285 @@
+use crate::Main;
+use genealogy::config::Config;
+use genealogy::genealogy::relation::Relation;
+use genealogy::output::post_json::PostJson;
+use genealogy::output::recommendation_json::RecommendationJson;
+use genealogy::output::relation_json::RelationJson;
+use genealogy::post::Post;
+use genealogy_java_apis::collection::Collection;
+use genealogy_java_apis::exception::Exception;
+use genealogy_java_apis::exception::Exception::RuntimeException;
+use genealogy_java_apis::list::List;
+use genealogy_java_apis::string::JString;
+use genealogy_java_apis::system::System;
+use percent_encoding::percent_decode_str;
+use serde_json::json;
+use tiny_http::{Header, Method, Request, Response};
+
+/// Serves the posts, their relations and recommendations as JSON over HTTP on localhost.
+///
+/// | Endpoint                                   | Content                                                        |
+/// |--------------------------------------------|----------------------------------------------------------------|
+/// | `GET /posts`                               | all posts, see [`PostJson`]                                    |
+/// | `GET /posts/{slug}/recommendations?limit=n` | the recommendations for one post, see [`RecommendationJson`]  |
+/// | `GET /posts/{slug}/relations`              | the relations of one post to all others, see [`RelationJson`]  |
+/// | `POST /reload`                             | reads all posts again and responds like `GET /posts`           |
+///
+/// Slugs that aren't URL-safe are percent-encoded in the path, e.g. `/posts/caf%C3%A9/relations`.
+/// The posts are read and their relations inferred once on start and on every reload.
+pub struct Server {
+	config: Config,
+	posts: Collection<Post>,
+	relations: List<Relation>,
+}
+
+impl Server {
+	pub fn new(config: Config) -> Result<Server, Exception> {
+		let mut server = Server {
+			config,
+			posts: List::new(),
+			relations: List::new(),
+		};
+		server.reload()?;
+		Ok(server)
+	}
+
+	/// Handles requests until the process is stopped.
+	pub fn run(mut self, port: u16) -> Result<(), Exception> {
+		let server = tiny_http::Server::http(("127.0.0.1", port))
+			.map_err(|error| RuntimeException("Starting the server failed.".into(), error))?;
+		System::err_println(format!("Serving {} posts on http://127.0.0.1:{}", self.posts.length(), port).into());
+
+		for request in server.incoming_requests() {
+			self.handle(request);
+		}
+		Ok(())
+	}
+
+	fn reload(&mut self) -> Result<(), Exception> {
+		let posts = Main::create_posts(&self.config)?;
+		let genealogy = Main::create_genealogy(posts.clone(), &self.config)?;
+		self.relations = genealogy.infer_relations()?.to_list()?;
+		self.posts = posts;
+		Ok(())
+	}
+
+	fn handle(&mut self, request: Request) {
+		let (status, body) = match self.respond(request.method(), request.url()) {
+			Ok(response) => response,
+			Err(exception) => (500, Self::error(exception.to_string())),
+		};
+		let header = Header::from_bytes("Content-Type", "application/json").expect("header is valid");
+		let response = Response::from_string(body.to_string())
+			.with_status_code(status)
+			.with_header(header);
+		if let Err(error) = request.respond(response) {
+			System::err_println(format!("Responding failed: {}", error).into());
+		}
+	}
+
+	fn respond(&mut self, method: &Method, url: &str) -> Result<(u16, JString), Exception> {
+		let (path, query) = url.split_once('?').unwrap_or((url, ""));
+		let segments = match path
+			.trim_matches('/')
+			.split('/')
+			.map(|segment| percent_decode_str(segment).decode_utf8())
+			.collect::<Result<Vec<_>, _>>()
+		{
+			Ok(segments) => segments,
+			Err(_) => return Ok((400, Self::error(format!("Invalid path: {}", path)))),
+		};
+		let segments = segments.iter().map(AsRef::as_ref).collect::<Vec<&str>>();
+		match (method, segments.as_slice()) {
+			(Method::Get, ["posts"]) => Ok((200, PostJson::to_json(self.posts.stream())?)),
+			(Method::Get, ["posts", slug, "recommendations"]) => {
+				let limit = match Self::query_parameter(query, "limit") {
+					None => self.config.per_post(),
+					Some(limit) => match limit.parse() {
+						Ok(limit) => limit,
+						Err(_) => return Ok((400, Self::error(format!("Invalid limit: {}", limit)))),
+					},
+				};
+				if limit < 1 {
+					return Ok((400, Self::error(format!("Invalid limit: {}", limit))));
+				}
+				match self.relations_of(slug) {
+					Some(relations) => {
+						let recommendations =
+							Main::create_recommender(&self.config).recommend(relations.stream(), limit)?;
+						Ok((200, RecommendationJson::to_json(recommendations)?))
+					}
+					None => Ok((404, Self::error(format!("No post with slug: {}", slug)))),
+				}
+			}
+			(Method::Get, ["posts", slug, "relations"]) => match self.relations_of(slug) {
+				Some(relations) => Ok((200, RelationJson::to_json(relations.stream())?)),
+				None => Ok((404, Self::error(format!("No post with slug: {}", slug)))),
+			},
+			(Method::Post, ["reload"]) => {
+				self.reload()?;
+				Ok((200, PostJson::to_json(self.posts.stream())?))
+			}
+			(_, ["posts"] | ["posts", _, "recommendations" | "relations"] | ["reload"]) => {
+				Ok((405, Self::error(format!("Method not allowed: {}", method))))
+			}
+			_ => Ok((404, Self::error(format!("Not found: {}", path)))),
+		}
+	}
+
+	/// The relations from the post with the given slug to all other posts, `None` if there is no such post.
+	fn relations_of(&self, slug: &str) -> Option<List<Relation>> {
+		if !self.posts.clone().into_iter().any(|post| post.slug().value() == slug) {
+			return None;
+		}
+		Some(List::of(
+			self.relations
+				.clone()
+				.into_iter()
+				.filter(|relation| relation.post1().slug().value() == slug),
+		))
+	}
+
+	fn query_parameter<'query>(query: &'query str, name: &str) -> Option<&'query str> {
+		query
+			.split('&')
+			.filter_map(|parameter| parameter.split_once('='))
+			.find(|(key, _)| *key == name)
+			.map(|(_, value)| value)
+	}
+
+	fn error(message: impl Into<String>) -> JString {
+		json!({ "error": message.into() }).to_string().into()
+	}
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod test {
+	use super::*;
+	use genealogy_java_apis::test::assert_that;
+	use serde_json::Value;
+	use std::path::PathBuf;
+	use std::sync::Once;
+
+	/// A server for the articles `a`, `b` and `café` in a temporary folder, which has to be removed after the test.
+	fn server(name: &str) -> (Server, PathBuf) {
+		// the tests share the registered services
+		static MODULE_PROVIDES: Once = Once::new();
+		MODULE_PROVIDES.call_once(genealogists::module_info::module_provides);
+
+		let folder = std::env::temp_dir().join(format!("genealogy-server-{}-{}", name, std::process::id()));
+		std::fs::create_dir_all(&folder).unwrap();
+		for (slug, tags) in [("a", "[java, streams]"), ("b", "[java]"), ("café", "[streams]")] {
+			let article = format!(
+				"---\ntitle: {}\ntags: {}\ndate: 2020-01-23\ndescription: \"Description\"\nslug: {}\n---\n",
+				slug, tags, slug
+			);
+			std::fs::write(folder.join(format!("{}.md", slug)), article).unwrap();
+		}
+		let config = Config::from_arguments(List::of([
+			format!("folders.articles={}", folder.display()).into(),
+			"slugs.url-safe=false".into(),
+		]))
+		.unwrap();
+		(Server::new(config).unwrap(), folder)
+	}
+
+	fn respond(name: &str, method: Method, url: &str) -> (u16, Value) {
+		let (mut server, folder) = server(name);
+		let (status, body) = server.respond(&method, url).unwrap();
+		std::fs::remove_dir_all(&folder).unwrap();
+		(status, serde_json::from_str(body.to_string().as_str()).unwrap())
+	}
+
+	#[test]
+	fn posts__all_posts() {
+		let (status, body) = respond("posts", Method::Get, "/posts");
+
+		assert_that(status).is_equal_to(200);
+		assert_that(body["posts"].as_array().unwrap().len()).is_equal_to(3);
+	}
+
+	#[test]
+	fn recommendations__limited() {
+		let (status, body) = respond("recommendations", Method::Get, "/posts/a/recommendations?limit=1");
+
+		assert_that(status).is_equal_to(200);
+		let recommendation = &body["recommendations"][0];
+		assert_that(recommendation["post"]["slug"].clone()).is_equal_to(Value::from("a"));
+		assert_that(recommendation["recommendedPosts"].as_array().unwrap().len()).is_equal_to(1);
+	}
+
+	#[test]
+	fn recommendations__invalid_limit__bad_request() {
+		let (status, body) = respond("invalid-limit", Method::Get, "/posts/a/recommendations?limit=0");
+
+		assert_that(status).is_equal_to(400);
+		assert_that(body["error"].clone()).is_equal_to(Value::from("Invalid limit: 0"));
+	}
+
+	#[test]
+	fn relations__to_all_other_posts() {
+		let (status, body) = respond("relations", Method::Get, "/posts/a/relations");
+
+		assert_that(status).is_equal_to(200);
+		let relations = body["relations"].as_array().unwrap();
+		assert_that(relations.len()).is_equal_to(2);
+		assert!(relations.iter().all(|relation| relation["post1"] == "a"));
+	}
+
+	#[test]
+	fn relations__percent_encoded_slug__decoded() {
+		let (status, body) = respond("encoded", Method::Get, "/posts/caf%C3%A9/relations");
+
+		assert_that(status).is_equal_to(200);
+		assert_that(body["relations"][0]["post1"].clone()).is_equal_to(Value::from("café"));
+	}
+
+	#[test]
+	fn unknown_slug__not_found() {
+		for url in ["/posts/unknown/relations", "/posts/unknown/recommendations"] {
+			let (status, body) = respond("unknown-slug", Method::Get, url);
+
+			assert_that(status).is_equal_to(404);
+			assert_that(body["error"].clone()).is_equal_to(Value::from("No post with slug: unknown"));
+		}
+	}
+
+	#[test]
+	fn unknown_path__not_found() {
+		let (status, _) = respond("unknown-path", Method::Get, "/tags");
+
+		assert_that(status).is_equal_to(404);
+	}
+
+	#[test]
+	fn not_get__method_not_allowed() {
+		for (method, url) in [
+			(Method::Post, "/posts"),
+			(Method::Delete, "/posts/a/relations"),
+			(Method::Put, "/posts/a/recommendations"),
+			(Method::Get, "/reload"),
+		] {
+			let (status, _) = respond("method", method, url);
+
+			assert_that(status).is_equal_to(405);
+		}
+	}
+
+	#[test]
+	fn reload__new_posts_read() {
+		let (mut server, folder) = server("reload");
+		std::fs::write(
+			folder.join("d.md"),
+			"---\ntitle: D\ntags: [java]\ndate: 2020-01-23\ndescription: \"Description\"\nslug: d\n---\n",
+		)
+		.unwrap();
+
+		let (status, body) = server.respond(&Method::Post, "/reload").unwrap();
+
+		std::fs::remove_dir_all(&folder).unwrap();
+		assert_that(status).is_equal_to(200);
+		let body = serde_json::from_str::<Value>(body.to_string().as_str()).unwrap();
+		assert_that(body["posts"].as_array().unwrap().len()).is_equal_to(4);
+	}
+}
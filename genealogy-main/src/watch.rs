@@ -0,0 +1,305 @@
+use crate::Main;
+use genealogy::config::content_root::ContentRoot;
+use genealogy::config::Config;
+use genealogy::post::corpus::Corpus;
+use genealogy::post::Post;
+use genealogy_java_apis::collection::Collection;
+use genealogy_java_apis::exception::Exception;
+use genealogy_java_apis::exception::Exception::RuntimeException;
+use genealogy_java_apis::list::List;
+use genealogy_java_apis::path::Path;
+use genealogy_java_apis::system::System;
+use notify::event::ModifyKind;
+use notify::{EventKind, RecursiveMode, Watcher};
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::PathBuf;
+use std::sync::mpsc;
+use std::time::Duration;
+use walkdir::WalkDir;
+
+/// Watches the content folders including their subfolders and keeps the posts up to date by re-parsing only the files that changed.
+///
+/// Files that can't be parsed are reported on standard error and left out until they are fixed.
+/// Posts with the same slug are an error until one of them is changed, see [`Corpus::check_unique_slugs`].
+/// Talks and videos are linked like in [`Main::create_posts`].
+pub struct Watch {
+	folders: Vec<ContentFolder>,
+	posts: BTreeMap<PathBuf, Post>,
+}
+
+impl Watch {
+	/// Changes that happen within this time after the first one are handled together,
+	/// editors often write a file in several steps.
+	const DEBOUNCE: Duration = Duration::from_millis(200);
+
+	pub fn new(config: &Config) -> Result<Watch, Exception> {
+		let mut folders = Vec::new();
+		for content_root in config.content_roots() {
+			folders.push(ContentFolder::new(content_root)?);
+		}
+
+		let mut watch = Watch {
+			folders,
+			posts: BTreeMap::new(),
+		};
+		for index in 0..watch.folders.len() {
+			let folder = &watch.folders[index];
+			let files = folder.content_root.files()?.to_list()?;
+			let paths = files
+				.into_iter()
+				.map(|file| folder.canonical_path(&file))
+				.collect::<Vec<_>>();
+			for path in paths {
+				watch.update(path);
+			}
+		}
+		Ok(watch)
+	}
+
+	pub fn posts(&self) -> Result<Collection<Post>, Exception> {
+		let posts_by_file = self
+			.posts
+			.iter()
+			.map(|(file, post)| (Path::of(file), post.clone()))
+			.collect::<Vec<_>>();
+		Corpus::check_unique_slugs(&posts_by_file)?;
+		let (posts_by_file, dangling_references) = Corpus::link_talks_and_videos(posts_by_file);
+		Main::report_dangling_references(dangling_references);
+		Ok(List::of(posts_by_file.into_iter().map(|(_, post)| post)))
+	}
+
+	/// Calls `on_change` with the current posts, then again every time they changed. Never returns unless
+	/// watching the folders fails.
+	pub fn run(mut self, mut on_change: impl FnMut(Result<Collection<Post>, Exception>)) -> Result<(), Exception> {
+		let (sender, receiver) = mpsc::channel();
+		let mut watcher = notify::recommended_watcher(sender).map_err(Self::watch_failed)?;
+		for folder in &self.folders {
+			watcher
+				.watch(&folder.path, RecursiveMode::Recursive)
+				.map_err(Self::watch_failed)?;
+		}
+
+		on_change(self.posts());
+		while let Ok(event) = receiver.recv() {
+			let mut changed_paths = BTreeSet::new();
+			Self::collect_paths(event, &mut changed_paths);
+			while let Ok(event) = receiver.recv_timeout(Self::DEBOUNCE) {
+				Self::collect_paths(event, &mut changed_paths);
+			}
+
+			let mut changed = false;
+			for path in changed_paths {
+				changed |= self.update(path);
+			}
+			if changed {
+				on_change(self.posts());
+			}
+		}
+		Ok(())
+	}
+
+	fn collect_paths(event: notify::Result<notify::Event>, paths: &mut BTreeSet<PathBuf>) {
+		match event {
+			// reading the files while parsing them causes access events as well
+			Ok(event)
+				if matches!(
+					event.kind,
+					EventKind::Access(_) | EventKind::Modify(ModifyKind::Metadata(_))
+				) => {}
+			Ok(event) => paths.extend(event.paths),
+			Err(error) => System::err_println(format!("Watching the content failed: {}", error).into()),
+		}
+	}
+
+	/// Re-parses the file at `path` or forgets about it if it was removed, together with every post below it
+	/// if it was a folder. Returns whether the posts changed.
+	fn update(&mut self, path: PathBuf) -> bool {
+		if !path.exists() {
+			let count = self.posts.len();
+			self.posts.retain(|file, _| !file.starts_with(&path));
+			return self.posts.len() != count;
+		}
+		if path.is_dir() {
+			return self.update_folder(&path);
+		}
+
+		let Some(folder) = self.folders.iter().find(|folder| folder.contains(&path)) else {
+			return false;
+		};
+		if !path.is_file() {
+			return false;
+		}
+		match folder.content_root.create_post(Path::of(&path)) {
+			Ok(post) => {
+				self.posts.insert(path, post);
+			}
+			Err(exception) => {
+				System::err_println(exception.to_string().into());
+				self.posts.remove(&path);
+			}
+		}
+		true
+	}
+
+	/// Re-parses every file below the folder at `path` that matches the patterns of its content root.
+	/// E.g. if a folder is moved into a content root, only the folder is reported, not the files in it.
+	fn update_folder(&mut self, path: &std::path::Path) -> bool {
+		let Some(folder) = self.folders.iter().find(|folder| path.starts_with(&folder.path)) else {
+			return false;
+		};
+		let mut files = Vec::new();
+		let entries = WalkDir::new(path)
+			.follow_links(folder.content_root.follow_symlinks())
+			.sort_by_file_name();
+		for entry in entries {
+			match entry {
+				Ok(entry) if entry.path().is_file() && folder.contains(entry.path()) => files.push(entry.into_path()),
+				Ok(_) => {}
+				Err(error) => System::err_println(format!("Reading {} failed: {}", path.display(), error).into()),
+			}
+		}
+
+		let mut changed = false;
+		for file in files {
+			changed |= self.update(file);
+		}
+		changed
+	}
+
+	fn watch_failed(error: notify::Error) -> Exception {
+		RuntimeException("Watching the content failed.".into(), error.into())
+	}
+}
+
+/// A content root and its canonical path.
+struct ContentFolder {
+	path: PathBuf,
+	content_root: ContentRoot,
+}
+
+impl ContentFolder {
+	/// The path is canonicalized because file system events contain canonical paths.
+	fn new(content_root: ContentRoot) -> Result<ContentFolder, Exception> {
+		Ok(ContentFolder {
+			path: std::fs::canonicalize(content_root.folder())?,
+			content_root,
+		})
+	}
+
+	fn contains(&self, path: &std::path::Path) -> bool {
+		path.strip_prefix(&self.path)
+			.is_ok_and(|relative_path| self.content_root.matches(relative_path))
+	}
+
+	/// The file of a post below the canonical path, without resolving symbolic links to files.
+	fn canonical_path(&self, file: &Path) -> PathBuf {
+		let folder = self.content_root.folder();
+		match file.as_ref().strip_prefix(folder.as_ref()) {
+			Ok(relative_path) => self.path.join(relative_path),
+			Err(_) => file.as_ref().to_path_buf(),
+		}
+	}
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod test {
+	use super::*;
+	use genealogy_java_apis::test::assert_that;
+
+	fn article(slug: &str, title: &str) -> String {
+		format!(
+			"---\ntitle: {}\ntags: [java]\ndate: 2020-01-23\ndescription: \"Description\"\nslug: {}\n---\n",
+			title, slug
+		)
+	}
+
+	fn titles(watch: &Watch) -> Vec<String> {
+		watch
+			.posts()
+			.unwrap()
+			.into_iter()
+			.map(|post| post.title().text().to_string())
+			.collect()
+	}
+
+	/// A watch of the content root `articles` in a temporary folder, next to the folder `other`.
+	fn watch(name: &str) -> (Watch, PathBuf) {
+		let folder = std::env::temp_dir().join(format!("genealogy-watch-{}-{}", name, std::process::id()));
+		std::fs::create_dir_all(folder.join("articles/2023")).unwrap();
+		std::fs::create_dir_all(folder.join("other")).unwrap();
+		let folder = std::fs::canonicalize(folder).unwrap();
+		std::fs::write(folder.join("articles/a.md"), article("a", "A")).unwrap();
+		let config = Config::from_arguments(List::of([format!(
+			"folders.articles={}",
+			folder.join("articles").display()
+		)
+		.into()]))
+		.unwrap();
+		(Watch::new(&config).unwrap(), folder)
+	}
+
+	#[test]
+	fn update__outside_content_root__ignored() {
+		let (mut watch, folder) = watch("outside");
+		let file = folder.join("other/b.md");
+		std::fs::write(&file, article("b", "B")).unwrap();
+		let notes = folder.join("articles/notes.txt");
+		std::fs::write(&notes, "").unwrap();
+
+		let changed = [watch.update(file), watch.update(notes)];
+
+		let titles = titles(&watch);
+		std::fs::remove_dir_all(&folder).unwrap();
+		assert_that(changed).is_equal_to([false, false]);
+		assert_that(titles).is_equal_to(vec!["A".to_string()]);
+	}
+
+	#[test]
+	fn update__inside_content_root__picked_up() {
+		let (mut watch, folder) = watch("inside");
+		let edited = folder.join("articles/a.md");
+		std::fs::write(&edited, article("a", "Edited")).unwrap();
+		let added = folder.join("articles/2023/b.md");
+		std::fs::write(&added, article("b", "B")).unwrap();
+
+		let changed = [watch.update(edited), watch.update(added)];
+
+		let titles = titles(&watch);
+		std::fs::remove_dir_all(&folder).unwrap();
+		assert_that(changed).is_equal_to([true, true]);
+		assert_that(titles).is_equal_to(vec!["B".to_string(), "Edited".to_string()]);
+	}
+
+	#[test]
+	fn update__folder_moved_into_content_root__posts_picked_up() {
+		let (mut watch, folder) = watch("moved");
+		std::fs::create_dir_all(folder.join("other/2024/01")).unwrap();
+		std::fs::write(folder.join("other/2024/01/c.md"), article("c", "C")).unwrap();
+		std::fs::write(folder.join("other/2024/notes.txt"), "").unwrap();
+		std::fs::rename(folder.join("other/2024"), folder.join("articles/2024")).unwrap();
+
+		let changed = watch.update(folder.join("articles/2024"));
+
+		let titles = titles(&watch);
+		std::fs::remove_dir_all(&folder).unwrap();
+		assert!(changed);
+		assert_that(titles).is_equal_to(vec!["C".to_string(), "A".to_string()]);
+	}
+
+	#[test]
+	fn update__removed_folder__posts_forgotten() {
+		let (mut watch, folder) = watch("removed");
+		let added = folder.join("articles/2023/b.md");
+		std::fs::write(&added, article("b", "B")).unwrap();
+		watch.update(added);
+		std::fs::remove_dir_all(folder.join("articles/2023")).unwrap();
+
+		let changed = watch.update(folder.join("articles/2023"));
+
+		let titles = titles(&watch);
+		std::fs::remove_dir_all(&folder).unwrap();
+		assert!(changed);
+		assert_that(titles).is_equal_to(vec!["A".to_string()]);
+	}
+}
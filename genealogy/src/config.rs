@@ -1,13 +1,28 @@
+use crate::config::config_file::{ConfigFile, Content};
+use crate::config::config_layers::ConfigLayers;
+use crate::config::config_source::ConfigSource;
+use crate::config::config_value::ConfigValue;
+use crate::config::content_root::ContentRoot;
+use crate::genealogist::genealogist_settings::GenealogistSettings;
+use crate::genealogist::relation_type::RelationType;
+use crate::genealogy::weights::Weights;
+use crate::output::output_format::OutputFormat;
+use crate::post::post_type::PostType;
+use crate::post::slug_rules::SlugRules;
+use crate::post::tag_taxonomy::TagTaxonomy;
 use genealogy_java_apis::completable_future::CompletableFuture;
 use genealogy_java_apis::exception::Exception;
 use genealogy_java_apis::exception::Exception::IllegalArgumentException;
 use genealogy_java_apis::files::Files;
 use genealogy_java_apis::list::List;
+use genealogy_java_apis::map::Map;
 use genealogy_java_apis::optional::Optional;
 use genealogy_java_apis::path::Path;
 use genealogy_java_apis::string::JString;
 use genealogy_java_apis::system::System;
+use genealogy_java_apis::time::{LocalDate, LocalDateExtension};
 use genealogy_java_apis::{r#static, record, throw};
+use toml::Value;
 
 /// ```java
 /// public record Config(
@@ -16,19 +31,44 @@ use genealogy_java_apis::{r#static, record, throw};
 /// 	Path videoFolder,
 /// 	Optional<Path> outputFile) {
 /// ```
-#[record(constructor = false)]
+/// Note: Unlike the Java version, the config is merged from several sources, see [`Config::create`],
+/// and the config file is TOML with named keys, see [`ConfigFile`]. Instead of exactly one folder per post type,
+/// there can be any number of content roots, see [`ContentRoot`]. It can configure more than the folders
+/// and the output file, everything after `output_file` doesn't exist in the Java version. `values` are the effective
+/// values of all keys with where they come from.
+#[record(constructor = false, equals = false, hash = false)]
 pub struct Config {
-	article_folder: Path,
-	talk_folder: Path,
-	video_folder: Path,
+	content_roots: List<ContentRoot>,
 	output_file: Optional<Path>,
+	output_format: Optional<OutputFormat>,
+	per_post: i32,
+	as_of: LocalDate,
+	preview: bool,
+	genealogists: List<JString>,
+	weights: Weights,
+	genealogist_settings: List<GenealogistSettings>,
+	tag_taxonomy: TagTaxonomy,
+	slug_rules: SlugRules,
+	values: List<ConfigValue>,
 }
 
+pub(crate) mod config_file;
+mod config_layers;
+pub mod config_source;
+pub mod config_value;
+pub mod content_root;
+
 impl Config {
 	// ```java
 	// private static final String CONFIG_FILE_NAME = "recommendations.config";
 	// ```
-	r#static!(pub CONFIG_FILE_NAME: JString = "recommendations.config".into());
+	r#static!(pub CONFIG_FILE_NAME: JString = "recommendations.toml".into());
+
+	/// Number of recommendations per post if none is configured.
+	pub const DEFAULT_PER_POST: i32 = 3;
+
+	/// Relation type of the genealogist that is used if none are configured.
+	pub const DEFAULT_GENEALOGIST: &'static str = "tag";
 
 	/// ```java
 	/// // use static factory method(s)
@@ -36,12 +76,21 @@ impl Config {
 	/// public Config { }
 	/// ```
 	#[deprecated(note = "use static factory methods")]
+	#[allow(clippy::new_without_default)]
 	pub fn new() -> Self {
 		Self {
-			article_folder: Default::default(),
-			talk_folder: Default::default(),
-			video_folder: Default::default(),
+			content_roots: List::new(),
 			output_file: Optional::empty(),
+			output_format: Optional::empty(),
+			per_post: Self::DEFAULT_PER_POST,
+			as_of: LocalDate::today(),
+			preview: false,
+			genealogists: List::of([Self::DEFAULT_GENEALOGIST.into()]),
+			weights: Weights::all_equal(),
+			genealogist_settings: List::new(),
+			tag_taxonomy: TagTaxonomy::empty(),
+			slug_rules: SlugRules::default(),
+			values: List::new(),
 		}
 	}
 
@@ -68,38 +117,315 @@ impl Config {
 	///		return new Config(articleFolder, talkFolder, videoFolder, outputFile);
 	///	}
 	/// ```
-	fn from_raw_config(raw: List<JString>) -> Result<Config, Exception> {
-		#[allow(clippy::len_zero)]
-		if raw.length() == 0 {
-			throw!(IllegalArgumentException("No article path defined".into()));
+	/// Note: Reads the merged values of all sources instead of positional arguments. Reports all invalid values
+	/// at once, together with the errors of reading the sources, with their keys and where they come from.
+	fn from_layers(layers: ConfigLayers, mut errors: Vec<String>) -> Result<Config, Exception> {
+		let invalid = |errors: Vec<String>| {
+			IllegalArgumentException(format!("Invalid config: \n\t{}", errors.join("\n\t")).into())
+		};
+		let config_file = match layers.to_config_file() {
+			Ok(config_file) => config_file,
+			Err(exception) => {
+				errors.push(ConfigLayers::message_of(exception));
+				throw!(invalid(errors));
+			}
+		};
+		let ConfigFile {
+			folders,
+			content,
+			output,
+			recommendations,
+			genealogists,
+			tags,
+			slugs,
+		} = config_file;
+
+		let folders = [
+			("folders.articles", PostType::Article, folders.articles),
+			("folders.talks", PostType::Talk, folders.talks),
+			("folders.videos", PostType::Video, folders.videos),
+		];
+		let content = content.unwrap_or_default();
+		if content.is_empty() && folders.iter().all(|(_, _, folder)| folder.is_none()) {
+			errors.push(
+				"content: No content folders configured, set folders.articles, folders.talks, folders.videos \
+				 or content."
+					.to_string(),
+			);
+		}
+		let mut content_roots = Vec::new();
+		for (key, post_type, folder) in folders {
+			let Some(folder) = folder else {
+				continue;
+			};
+			let content_root = Self::read_folder(folder.as_str().into()).and_then(|folder| {
+				ContentRoot::new(post_type, folder, ContentRoot::default_include(), List::new(), false)
+			});
+			content_roots.extend(Self::check(key, content_root, &layers, &mut errors));
 		}
+		for (index, content) in content.into_iter().enumerate() {
+			let content_root = Self::read_content_root(content).map_err(|exception| {
+				IllegalArgumentException(format!("Entry {}: {}", index + 1, ConfigLayers::message_of(exception)).into())
+			});
+			content_roots.extend(Self::check("content", content_root, &layers, &mut errors));
+		}
+		let output_file = Self::check(
+			"output.file",
+			output
+				.file
+				.map(|output_file| Self::read_output_file(output_file.as_str().into()))
+				.transpose(),
+			&layers,
+			&mut errors,
+		);
+		let output_format = Self::check(
+			"output.format",
+			output
+				.format
+				.map(|format| OutputFormat::from_name(format.as_str().into()))
+				.transpose(),
+			&layers,
+			&mut errors,
+		);
+		let per_post = Self::check(
+			"recommendations.per-post",
+			Self::required("recommendations.per-post", recommendations.per_post).and_then(Self::read_per_post),
+			&layers,
+			&mut errors,
+		);
+		let as_of = Self::check(
+			"recommendations.as-of",
+			recommendations
+				.as_of
+				.map(|as_of| LocalDate::parse(as_of.as_str().into()))
+				.transpose(),
+			&layers,
+			&mut errors,
+		);
+		let enabled_genealogists = Self::check(
+			"genealogists.enabled",
+			Self::required("genealogists.enabled", genealogists.enabled).and_then(Self::read_genealogists),
+			&layers,
+			&mut errors,
+		);
+		let default_weight = Self::check(
+			"genealogists.default-weight",
+			Self::required("genealogists.default-weight", genealogists.default_weight).and_then(Self::read_weight),
+			&layers,
+			&mut errors,
+		);
+		let mut weights_by_type = Vec::new();
+		for (relation_type, weight) in genealogists.weights {
+			let key = format!("genealogists.weights.{}", relation_type);
+			let weight = Self::read_weight(weight)
+				.and_then(|weight| Ok((RelationType::new(relation_type.as_str().into())?, weight)));
+			weights_by_type.extend(Self::check(&key, weight, &layers, &mut errors));
+		}
+		let mut genealogist_settings = Vec::new();
+		for (relation_type, settings) in genealogists.settings {
+			let key = format!("genealogists.settings.{}", relation_type);
+			let settings = RelationType::new(relation_type.as_str().into()).map(|relation_type| {
+				GenealogistSettings::new(
+					relation_type,
+					Map::of(settings.into_iter().map(|(key, value)| (key.as_str().into(), value))),
+				)
+			});
+			genealogist_settings.extend(Self::check(&key, settings, &layers, &mut errors));
+		}
+		let tag_taxonomy = Self::check(
+			"tags.taxonomy",
+			tags.taxonomy
+				.map(|taxonomy| TagTaxonomy::from_file(Path::of(taxonomy.as_str())))
+				.transpose(),
+			&layers,
+			&mut errors,
+		);
+		let slug_url_safe = Self::check(
+			"slugs.url-safe",
+			Self::required("slugs.url-safe", slugs.url_safe),
+			&layers,
+			&mut errors,
+		);
+		let slug_lowercase = Self::check(
+			"slugs.lowercase",
+			Self::required("slugs.lowercase", slugs.lowercase),
+			&layers,
+			&mut errors,
+		);
+		let slug_max_length = Self::check(
+			"slugs.max-length",
+			slugs.max_length.map(Self::read_slug_max_length).transpose(),
+			&layers,
+			&mut errors,
+		);
 
-		let article_folder = Self::read_folder(raw.get(0)?)?;
-		let talk_folder = Self::read_folder(raw.get(1)?)?;
-		let video_folder = Self::read_folder(raw.get(2)?)?;
+		match (
+			(slug_url_safe, slug_lowercase, slug_max_length),
+			as_of,
+			tag_taxonomy,
+			output_file,
+			output_format,
+			per_post,
+			enabled_genealogists,
+			default_weight,
+		) {
+			(
+				(Some(slug_url_safe), Some(slug_lowercase), Some(slug_max_length)),
+				Some(as_of),
+				Some(tag_taxonomy),
+				Some(output_file),
+				Some(output_format),
+				Some(per_post),
+				Some(genealogists),
+				Some(default_weight),
+			) if errors.is_empty() => {
+				let tag_taxonomy = tag_taxonomy.unwrap_or_default();
+				let slug_rules = SlugRules::new(slug_url_safe, slug_lowercase, slug_max_length.into());
+				Ok(Config {
+					content_roots: List::of(
+						content_roots
+							.into_iter()
+							.map(|content_root| content_root.with_tag_taxonomy(tag_taxonomy.clone()))
+							.map(|content_root| content_root.with_slug_rules(slug_rules.clone())),
+					),
+					output_file: output_file.into(),
+					output_format: output_format.into(),
+					per_post,
+					as_of: as_of.unwrap_or_else(LocalDate::today),
+					preview: recommendations.preview.unwrap_or(false),
+					genealogists,
+					weights: Weights::new(Map::of(weights_by_type), default_weight),
+					genealogist_settings: List::of(genealogist_settings),
+					tag_taxonomy,
+					slug_rules,
+					values: layers.values(),
+				})
+			}
+			_ => Err(invalid(errors)),
+		}
+	}
 
-		let output_filename = if raw.length() >= 4 {
-			Optional::of(raw.get(3)?)
+	/// The settings of the genealogist with the given relation type, empty if there are none.
+	pub fn settings_of(&self, relation_type: RelationType) -> GenealogistSettings {
+		self.genealogist_settings
+			.clone()
+			.into_iter()
+			.find(|settings| settings.relation_type() == relation_type)
+			.unwrap_or_else(|| GenealogistSettings::empty(relation_type))
+	}
+
+	/// The output file if it was given on the command line. Only the recommendations are written
+	/// to an output file from a config file or the environment, everything else needs it to be given explicitly.
+	pub fn explicit_output_file(&self) -> Optional<Path> {
+		let explicit = self
+			.values
+			.clone()
+			.into_iter()
+			.any(|value| value.key() == "output.file" && value.source() == ConfigSource::CommandLine);
+		if explicit {
+			self.output_file.clone()
 		} else {
 			Optional::empty()
-		};
+		}
+	}
 
-		let output_file = output_filename
-			.map(|file| Ok::<_, Exception>(Path::of(System::get_property("user.dir")?).resolve(file)))?;
-		output_file.if_present(|file| {
-			let not_writable = Files::exists(file.clone()) && !Files::is_writable(file.clone());
-			if not_writable {
-				throw!(IllegalArgumentException("Output path is not writable: " + file));
+	fn check<T>(key: &str, result: Result<T, Exception>, layers: &ConfigLayers, errors: &mut Vec<String>) -> Option<T> {
+		match result {
+			Ok(value) => Some(value),
+			Err(exception) => {
+				let message = ConfigLayers::message_of(exception);
+				errors.push(match layers.source_of(key) {
+					Some(source) => format!("{}: {} (from {})", key, message, source),
+					None => format!("{}: {}", key, message),
+				});
+				None
 			}
-			Ok(())
-		})?;
+		}
+	}
 
-		Ok(Config {
-			article_folder,
-			talk_folder,
-			video_folder,
-			output_file,
-		})
+	fn required<T>(key: &str, value: Option<T>) -> Result<T, Exception> {
+		match value {
+			Some(value) => Ok(value),
+			None => throw!(IllegalArgumentException(
+				format!(
+					"Missing, set it in {}, with the environment variable {} or on the command line.",
+					Self::CONFIG_FILE_NAME(),
+					ConfigLayers::variable_of(key)
+				)
+				.into()
+			)),
+		}
+	}
+
+	fn read_content_root(content: Content) -> Result<ContentRoot, Exception> {
+		let patterns = |patterns: Vec<String>| List::of(patterns.iter().map(|pattern| pattern.as_str().into()));
+		ContentRoot::new(
+			PostType::from_name(content.r#type.as_str().into())?,
+			Self::read_folder(content.folder.as_str().into())?,
+			content.include.map_or_else(ContentRoot::default_include, patterns),
+			patterns(content.exclude.unwrap_or_default()),
+			content.follow_symlinks.unwrap_or(false),
+		)
+	}
+
+	fn read_per_post(per_post: i32) -> Result<i32, Exception> {
+		if per_post < 1 {
+			throw!(IllegalArgumentException(
+				JString::from("Number of recommendations per post must be greater zero: ") + per_post
+			));
+		}
+		Ok(per_post)
+	}
+
+	fn read_genealogists(genealogists: Vec<String>) -> Result<List<JString>, Exception> {
+		if genealogists.is_empty() {
+			throw!(IllegalArgumentException(
+				"At least one genealogist must be enabled.".into()
+			));
+		}
+		for genealogist in &genealogists {
+			RelationType::new(genealogist.as_str().into())?;
+		}
+		Ok(List::of(
+			genealogists.iter().map(|genealogist| genealogist.as_str().into()),
+		))
+	}
+
+	fn read_slug_max_length(max_length: i64) -> Result<usize, Exception> {
+		if max_length < 1 {
+			throw!(IllegalArgumentException(
+				JString::from("The maximum length of slugs must be greater zero: ") + max_length
+			));
+		}
+		Ok(max_length as usize)
+	}
+
+	fn read_weight(weight: f64) -> Result<f64, Exception> {
+		if !weight.is_finite() || weight < 0.0 {
+			throw!(IllegalArgumentException(
+				format!("Weights must be a number of at least 0: {}", weight).into()
+			));
+		}
+		Ok(weight)
+	}
+
+	/// Extracted from `fromRawConfig`.
+	/// ```java
+	/// var outputFile = outputFileName
+	/// 		.map(file -> Path.of(System.getProperty("user.dir")).resolve(file));
+	/// outputFile.ifPresent(file -> {
+	/// 	boolean notWritable = Files.exists(file) && !Files.isWritable(file);
+	/// 	if (notWritable)
+	/// 		throw new IllegalArgumentException("Output path is not writable: " + outputFile.get());
+	/// });
+	/// ```
+	fn read_output_file(raw: JString) -> Result<Path, Exception> {
+		let file = Path::of(System::get_property("user.dir")?).resolve(raw);
+		let not_writable = Files::exists(file.clone()) && !Files::is_writable(file.clone());
+		if not_writable {
+			throw!(IllegalArgumentException("Output path is not writable: " + file));
+		}
+		Ok(file)
 	}
 
 	/// ```java
@@ -140,17 +466,60 @@ impl Config {
 	///				.thenApply(Config::fromRawConfig);
 	///	}
 	/// ```
+	/// Note: Instead of taking the first source that exists, the values of all sources are merged key by key,
+	/// see [`ConfigLayers`]: the defaults, the user config file, the project config file, the `GENEALOGY_*`
+	/// environment variables and the `args` of the form `key=value` from the command line. Only missing config files
+	/// are skipped, invalid ones are reported.
 	pub fn create(args: List<JString>) -> Result<CompletableFuture<Config>, Exception> {
-		#[allow(clippy::len_zero)]
-		let raw_config = if args.length() > 0 {
-			CompletableFuture::completed_future(args)
-		} else {
-			Self::read_project_config()?
-		}
-		.exceptionally_compose_async(|_| Self::read_user_config())
-		.exceptionally_compose(|_| Ok(List::new()));
+		let project_config = Self::read_project_config()?;
+		Ok(Self::read_user_config()?.then_apply(move |user_config| {
+			let mut layers = ConfigLayers::default();
+			let mut errors = Vec::new();
+			Self::add_defaults(&mut layers);
+			for (config, source) in [
+				(user_config, ConfigSource::UserFile as fn(Path) -> ConfigSource),
+				(project_config.join()?, ConfigSource::ProjectFile),
+			] {
+				for (file, text) in config {
+					if let Err(exception) = layers.add_file(text, source(file.clone())) {
+						errors.push(format!("{}: {}", file, ConfigLayers::message_of(exception)));
+					}
+				}
+			}
+			let variables = System::getenv().entry_set().into_iter();
+			layers.add_environment(variables.map(|entry| (entry.get_key(), entry.get_value())), &mut errors);
+			layers.add_arguments(args, &mut errors);
+			Config::from_layers(layers, errors)
+		}))
+	}
+
+	/// Like [`Config::create`], but only from the defaults and the `args`, without the config files and the
+	/// environment variables, so that e.g. tests don't depend on the machine they run on.
+	pub fn from_arguments(args: List<JString>) -> Result<Config, Exception> {
+		let mut layers = ConfigLayers::default();
+		let mut errors = Vec::new();
+		Self::add_defaults(&mut layers);
+		layers.add_arguments(args, &mut errors);
+		Config::from_layers(layers, errors)
+	}
 
-		Ok(raw_config.then_apply(Config::from_raw_config))
+	fn add_defaults(layers: &mut ConfigLayers) {
+		let defaults = [
+			(
+				"recommendations.per-post",
+				Value::Integer(Self::DEFAULT_PER_POST.into()),
+			),
+			(
+				"genealogists.enabled",
+				Value::Array(vec![Value::String(Self::DEFAULT_GENEALOGIST.to_string())]),
+			),
+			("genealogists.default-weight", Value::Float(1.0)),
+			("slugs.url-safe", Value::Boolean(SlugRules::default().url_safe())),
+			("slugs.lowercase", Value::Boolean(SlugRules::default().lowercase())),
+		];
+		for (key, value) in defaults {
+			layers.add(key, value, ConfigSource::Default);
+		}
 	}
 
 	/// ```java
@@ -159,7 +528,7 @@ impl Config {
 	/// 	return readConfig(workingDir);
 	/// }
 	/// ```
-	fn read_project_config() -> Result<CompletableFuture<List<JString>>, Exception> {
+	fn read_project_config() -> Result<CompletableFuture<Optional<(Path, JString)>>, Exception> {
 		let working_dir = Path::of(System::get_property("user.dir")?).resolve(Self::CONFIG_FILE_NAME());
 		Ok(Self::read_config(working_dir))
 	}
@@ -170,7 +539,7 @@ impl Config {
 	/// 	return readConfig(workingDir);
 	/// }
 	/// ```
-	fn read_user_config() -> Result<CompletableFuture<List<JString>>, Exception> {
+	fn read_user_config() -> Result<CompletableFuture<Optional<(Path, JString)>>, Exception> {
 		let working_dir = Path::of(System::get_property("user.home")?).resolve(Self::CONFIG_FILE_NAME());
 		Ok(Self::read_config(working_dir))
 	}
@@ -186,7 +555,250 @@ impl Config {
 	/// 	});
 	/// }
 	/// ```
-	fn read_config(working_dir: Path) -> CompletableFuture<List<JString>> {
-		CompletableFuture::supply_async(move || Files::read_all_lines(&working_dir))
+	/// Note: Reads the whole file as one string for the TOML parser and keeps the path for error messages.
+	/// A missing file is empty instead of an error.
+	fn read_config(working_dir: Path) -> CompletableFuture<Optional<(Path, JString)>> {
+		CompletableFuture::supply_async(move || match std::fs::read_to_string(&working_dir) {
+			Ok(text) => Ok(Optional::of((working_dir, text.as_str().into()))),
+			Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(Optional::empty()),
+			Err(error) => Err(error.into()),
+		})
+	}
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod test {
+	use super::*;
+	use genealogy_java_apis::test::assert_that;
+
+	fn project_file() -> ConfigSource {
+		ConfigSource::ProjectFile(Path::of("recommendations.toml"))
+	}
+
+	fn layers_with_folders(rest: &str) -> ConfigLayers {
+		let folder = std::env::temp_dir().display().to_string();
+		let text = format!(
+			"[folders]\narticles = '{}'\ntalks = '{}'\nvideos = '{}'\n{}",
+			folder, folder, folder, rest
+		);
+		let mut layers = ConfigLayers::default();
+		Config::add_defaults(&mut layers);
+		layers.add_file(text.as_str().into(), project_file()).unwrap();
+		layers
+	}
+
+	#[test]
+	fn only_folders__defaults() {
+		let config = Config::from_layers(layers_with_folders(""), Vec::new()).unwrap();
+
+		assert!(!config.output_file().is_present());
+		assert!(!config.output_format().is_present());
+		assert_that(config.per_post()).is_equal_to(Config::DEFAULT_PER_POST);
+		assert_that(config.as_of()).is_equal_to(LocalDate::today());
+		assert!(!config.preview());
+		assert_that(config.genealogists()).is_equal_to(List::of([Config::DEFAULT_GENEALOGIST.into()]));
+	}
+
+	#[test]
+	fn all_keys__read() {
+		let layers = layers_with_folders(
+			"[output]\nformat = 'hugo'\n\
+			 [recommendations]\nper-post = 5\nas-of = '2021-03-16'\npreview = true\n\
+			 [genealogists]\nenabled = ['tag', 'repo']\ndefault-weight = 0.5\n\
+			 [genealogists.weights]\nrepo = 0.25\n",
+		);
+
+		let config = Config::from_layers(layers, Vec::new()).unwrap();
+
+		assert_that(config.output_format().get().unwrap()).is_equal_to(OutputFormat::Hugo);
+		assert_that(config.per_post()).is_equal_to(5);
+		assert_that(config.as_of()).is_equal_to(LocalDate::of(2021, 3, 16));
+		assert!(config.preview());
+		assert_that(config.genealogists()).is_equal_to(List::of(["tag".into(), "repo".into()]));
+		let weights = config.weights();
+		assert_that(weights.weight_of(RelationType::new("repo".into()).unwrap())).is_equal_to(0.25);
+		assert_that(weights.weight_of(RelationType::new("tag".into()).unwrap())).is_equal_to(0.5);
+	}
+
+	#[test]
+	fn layers__merged_key_by_key_with_sources() {
+		let mut layers = layers_with_folders("[recommendations]\nper-post = 5\n[genealogists]\nenabled = ['repo']\n");
+		let mut errors = Vec::new();
+		layers.add_environment([("GENEALOGY_RECOMMENDATIONS_PER_POST".into(), "7".into())], &mut errors);
+		layers.add_arguments(List::of(["recommendations.per-post=9".into()]), &mut errors);
+
+		let config = Config::from_layers(layers, errors).unwrap();
+
+		assert_that(config.per_post()).is_equal_to(9);
+		assert_that(config.genealogists()).is_equal_to(List::of(["repo".into()]));
+		let source_of = |key: &str| {
+			config
+				.values()
+				.into_iter()
+				.find(|value| value.key() == key)
+				.map(|value| value.source())
+		};
+		assert_that(source_of("recommendations.per-post")).is_equal_to(Some(ConfigSource::CommandLine));
+		assert_that(source_of("genealogists.enabled")).is_equal_to(Some(project_file()));
+		assert_that(source_of("genealogists.default-weight")).is_equal_to(Some(ConfigSource::Default));
+	}
+
+	#[test]
+	fn from_arguments__only_defaults_and_arguments() {
+		let folder = std::env::temp_dir().display().to_string();
+
+		let config = Config::from_arguments(List::of([
+			format!("folders.articles={}", folder).into(),
+			"recommendations.per-post=5".into(),
+		]))
+		.unwrap();
+
+		assert_that(config.per_post()).is_equal_to(5);
+		assert_that(config.content_roots().length()).is_equal_to(1);
+		let sources = config
+			.values()
+			.into_iter()
+			.map(|value| value.source())
+			.collect::<Vec<_>>();
+		assert!(sources
+			.iter()
+			.all(|source| matches!(source, ConfigSource::Default | ConfigSource::CommandLine)));
+	}
+
+	#[test]
+	fn explicit_output_file__only_from_command_line() {
+		let from_file = Config::from_layers(layers_with_folders("[output]\nfile = 'a.json'\n"), Vec::new()).unwrap();
+		let mut layers = layers_with_folders("[output]\nfile = 'a.json'\n");
+		let mut errors = Vec::new();
+		layers.add_arguments(List::of(["output.file=b.json".into()]), &mut errors);
+		let from_command_line = Config::from_layers(layers, errors).unwrap();
+
+		assert!(from_file.output_file().is_present());
+		assert!(!from_file.explicit_output_file().is_present());
+		assert!(from_command_line
+			.explicit_output_file()
+			.get()
+			.unwrap()
+			.to_string()
+			.ends_with("b.json"));
+	}
+
+	#[test]
+	fn invalid_values__all_reported_with_keys_and_sources() {
+		let layers = layers_with_folders("[recommendations]\nper-post = 0\n[genealogists.weights]\ntag = -1.0\n");
+
+		let exception =
+			Config::from_layers(layers, vec!["GENEALOGY_X: Unknown config variable.".to_string()]).unwrap_err();
+
+		let message = exception.to_string();
+		assert!(message.contains(
+			"recommendations.per-post: Number of recommendations per post must be greater zero: 0 \
+			 (from project config recommendations.toml)"
+		));
+		assert!(message.contains("genealogists.weights.tag: Weights must be a number of at least 0: -1"));
+		assert!(message.contains("GENEALOGY_X: Unknown config variable."));
+	}
+
+	#[test]
+	fn content__read_with_patterns_and_other_types_left_out() {
+		let folder = std::env::temp_dir().display().to_string();
+		let text = format!(
+			"[[content]]\ntype = 'Talk'\nfolder = '{}'\nexclude = ['drafts/**']\nfollow-symlinks = true\n",
+			folder
+		);
+		let mut layers = ConfigLayers::default();
+		Config::add_defaults(&mut layers);
+		layers.add_file(text.as_str().into(), project_file()).unwrap();
+
+		let config = Config::from_layers(layers, Vec::new()).unwrap();
+
+		let content_roots = config.content_roots();
+		assert_that(content_roots.length()).is_equal_to(1);
+		let content_root = content_roots.get(0).unwrap();
+		assert_that(content_root.post_type()).is_equal_to(PostType::Talk);
+		assert!(content_root.follow_symlinks());
+		assert!(content_root.matches(std::path::Path::new("2023/05/talk.markdown")));
+		assert!(!content_root.matches(std::path::Path::new("drafts/talk.md")));
+	}
+
+	#[test]
+	fn invalid_content__error_with_entry() {
+		let folder = std::env::temp_dir().display().to_string();
+		let text = format!("[[content]]\ntype = 'podcast'\nfolder = '{}'\n", folder);
+		let mut layers = ConfigLayers::default();
+		Config::add_defaults(&mut layers);
+		layers.add_file(text.as_str().into(), project_file()).unwrap();
+
+		let exception = Config::from_layers(layers, Vec::new()).unwrap_err();
+
+		assert!(exception
+			.to_string()
+			.contains("content: Entry 1: Unknown post type: podcast (from project config recommendations.toml)"));
+	}
+
+	#[test]
+	fn tag_taxonomy__read_from_file() {
+		let file = std::env::temp_dir().join("genealogy-config-tag-taxonomy.toml");
+		std::fs::write(&file, "[java]\naliases = ['jdk']\n").unwrap();
+		let layers = layers_with_folders(&format!("[tags]\ntaxonomy = '{}'\n", file.display()));
+
+		let config = Config::from_layers(layers, Vec::new()).unwrap();
+
+		assert_that(config.tag_taxonomy().to_string()).is_equal_to("TagTaxonomy[tags=[java]]".to_string());
+	}
+
+	#[test]
+	fn missing_tag_taxonomy__error() {
+		let layers = layers_with_folders("[tags]\ntaxonomy = 'does-not-exist.toml'\n");
+
+		let exception = Config::from_layers(layers, Vec::new()).unwrap_err();
+
+		assert!(exception
+			.to_string()
+			.contains("tags.taxonomy: Reading the tag taxonomy does-not-exist.toml failed"));
+	}
+
+	#[test]
+	fn slugs__read_with_defaults() {
+		let defaults = Config::from_layers(layers_with_folders(""), Vec::new()).unwrap();
+		let layers = layers_with_folders("[slugs]\nlowercase = true\nmax-length = 60\n");
+
+		let config = Config::from_layers(layers, Vec::new()).unwrap();
+
+		assert_that(defaults.slug_rules()).is_equal_to(SlugRules::default());
+		assert_that(config.slug_rules()).is_equal_to(SlugRules::new(true, true, Optional::of(60)));
+	}
+
+	#[test]
+	fn invalid_slug_max_length__error() {
+		let layers = layers_with_folders("[slugs]\nmax-length = 0\n");
+
+		let exception = Config::from_layers(layers, Vec::new()).unwrap_err();
+
+		assert!(exception
+			.to_string()
+			.contains("slugs.max-length: The maximum length of slugs must be greater zero: 0"));
+	}
+
+	#[test]
+	fn no_content__error() {
+		let mut layers = ConfigLayers::default();
+		Config::add_defaults(&mut layers);
+
+		let exception = Config::from_layers(layers, Vec::new()).unwrap_err();
+
+		assert!(exception.to_string().contains("content: No content folders configured"));
+	}
+
+	#[test]
+	fn unknown_key__error() {
+		let mut layers = ConfigLayers::default();
+
+		let exception = layers
+			.add_file("[output]\nfiel = 'recommendations.json'\n".into(), project_file())
+			.unwrap_err();
+
+		assert!(exception.to_string().contains("unknown field `fiel`"));
 	}
 }
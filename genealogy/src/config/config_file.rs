@@ -0,0 +1,137 @@
+use genealogy_java_apis::exception::Exception;
+use genealogy_java_apis::exception::Exception::IllegalArgumentException;
+use genealogy_java_apis::string::JString;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+/// The structure of the TOML config file, see [`Config`](super::Config):
+/// ```toml
+/// [folders]
+/// articles = "./content/articles/"
+/// talks = "./content/talks/"
+/// videos = "./content/videos/"
+///
+/// [[content]]
+/// type = "article"
+/// folder = "./content/blog/"
+/// include = ["**/*.md", "**/*.markdown", "**/*.mdx"]
+/// exclude = ["**/drafts/**"]
+/// follow-symlinks = false
+///
+/// [output]
+/// file = "recommendations.json"
+/// format = "json"
+///
+/// [recommendations]
+/// per-post = 3
+/// as-of = "2021-03-16"
+/// preview = false
+///
+/// [genealogists]
+/// enabled = ["tag", "repo", "type"]
+/// default-weight = 1.0
+///
+/// [genealogists.weights]
+/// repo = 0.5
+///
+/// [genealogists.settings.type]
+/// podcast = 70
+///
+/// [tags]
+/// taxonomy = "tags.toml"
+///
+/// [slugs]
+/// url-safe = true
+/// lowercase = false
+/// max-length = 80
+/// ```
+/// The `folders` are a shorthand for `content` with the default patterns, every post type without a folder is left out.
+/// What the `settings` of a genealogist mean is up to the genealogist, e.g. the type genealogist reads
+/// the score of every kind of post. The tag `taxonomy` is a file as described in
+/// [`TagTaxonomy`](crate::post::tag_taxonomy::TagTaxonomy). Posts that are drafts or dated after `as-of`, today by default,
+/// aren't recommended, with `preview` they still get recommendations themselves. The `slugs` table configures
+/// the rules every slug has to follow, see [`SlugRules`](crate::post::slug_rules::SlugRules).
+/// Every key is optional in a single file because the files are merged with the other sources of config values,
+/// see [`ConfigLayers`](super::config_layers::ConfigLayers). Unknown keys are errors.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+pub(super) struct ConfigFile {
+	#[serde(default)]
+	pub(super) folders: Folders,
+	pub(super) content: Option<Vec<Content>>,
+	#[serde(default)]
+	pub(super) output: Output,
+	#[serde(default)]
+	pub(super) recommendations: Recommendations,
+	#[serde(default)]
+	pub(super) genealogists: Genealogists,
+	#[serde(default)]
+	pub(super) tags: Tags,
+	#[serde(default)]
+	pub(super) slugs: Slugs,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(super) struct Folders {
+	pub(super) articles: Option<String>,
+	pub(super) talks: Option<String>,
+	pub(super) videos: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+pub(super) struct Content {
+	pub(super) r#type: String,
+	pub(super) folder: String,
+	pub(super) include: Option<Vec<String>>,
+	pub(super) exclude: Option<Vec<String>>,
+	pub(super) follow_symlinks: Option<bool>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(super) struct Output {
+	pub(super) file: Option<String>,
+	pub(super) format: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+pub(super) struct Recommendations {
+	pub(super) per_post: Option<i32>,
+	pub(super) as_of: Option<String>,
+	pub(super) preview: Option<bool>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+pub(super) struct Genealogists {
+	pub(super) enabled: Option<Vec<String>>,
+	pub(super) default_weight: Option<f64>,
+	#[serde(default)]
+	pub(super) weights: BTreeMap<String, f64>,
+	#[serde(default)]
+	pub(super) settings: BTreeMap<String, BTreeMap<String, f64>>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(super) struct Tags {
+	pub(super) taxonomy: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+pub(super) struct Slugs {
+	pub(super) url_safe: Option<bool>,
+	pub(super) lowercase: Option<bool>,
+	pub(super) max_length: Option<i64>,
+}
+
+impl ConfigFile {
+	pub(super) fn parse(text: JString) -> Result<ConfigFile, Exception> {
+		toml::from_str(text.to_string().as_str())
+			.map_err(|error| IllegalArgumentException(error.to_string().trim_end().into()))
+	}
+}
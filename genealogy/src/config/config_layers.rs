@@ -0,0 +1,326 @@
+use crate::config::config_file::ConfigFile;
+use crate::config::config_source::ConfigSource;
+use crate::config::config_value::ConfigValue;
+use genealogy_java_apis::exception::Exception;
+use genealogy_java_apis::exception::Exception::IllegalArgumentException;
+use genealogy_java_apis::list::List;
+use genealogy_java_apis::string::JString;
+use genealogy_java_apis::throw;
+use std::collections::BTreeMap;
+use toml::{Table, Value};
+
+/// The values of the config keys from all sources, merged key by key: a value replaces the one
+/// of the same key from an earlier source, every other key keeps its value.
+///
+/// The keys are the dotted keys of the config file, e.g. `recommendations.per-post`. In environment variables,
+/// they are upper case with `_` instead of `.` and `-` and prefixed with `GENEALOGY_`,
+/// e.g. `GENEALOGY_RECOMMENDATIONS_PER_POST`. Lists like `genealogists.enabled` are separated by commas there
+/// and on the command line. Settings of genealogists are read from variables like `GENEALOGY_GENEALOGISTS_SETTINGS_TYPE_PODCAST`,
+/// where the part after the relation type is the key of the setting.
+#[derive(Debug, Default)]
+pub(super) struct ConfigLayers {
+	values: BTreeMap<String, (Value, ConfigSource)>,
+}
+
+impl ConfigLayers {
+	const KEYS: [&'static str; 14] = [
+		"folders.articles",
+		"folders.talks",
+		"folders.videos",
+		"output.file",
+		"output.format",
+		"recommendations.per-post",
+		"recommendations.as-of",
+		"recommendations.preview",
+		"genealogists.enabled",
+		"genealogists.default-weight",
+		"tags.taxonomy",
+		"slugs.url-safe",
+		"slugs.lowercase",
+		"slugs.max-length",
+	];
+	const WEIGHTS_PREFIX: &'static str = "genealogists.weights.";
+	const SETTINGS_PREFIX: &'static str = "genealogists.settings.";
+	const ENVIRONMENT_PREFIX: &'static str = "GENEALOGY_";
+
+	pub(super) fn add(&mut self, key: &str, value: Value, source: ConfigSource) {
+		self.values.insert(key.to_string(), (value, source));
+	}
+
+	/// Adds every key of a config file, which is checked against [`ConfigFile`] first.
+	pub(super) fn add_file(&mut self, text: JString, source: ConfigSource) -> Result<(), Exception> {
+		ConfigFile::parse(text.clone())?;
+		let table = text
+			.to_string()
+			.parse::<Table>()
+			.map_err(|error| IllegalArgumentException(error.to_string().trim_end().into()))?;
+		self.add_table("", table, &source);
+		Ok(())
+	}
+
+	fn add_table(&mut self, prefix: &str, table: Table, source: &ConfigSource) {
+		for (key, value) in table {
+			let key = format!("{}{}", prefix, key);
+			match value {
+				Value::Table(table) => self.add_table(&format!("{}.", key), table, source),
+				value => self.add(&key, value, source.clone()),
+			}
+		}
+	}
+
+	/// Adds the `GENEALOGY_*` variables, other variables are ignored. Invalid ones are collected in `errors`.
+	pub(super) fn add_environment(
+		&mut self,
+		variables: impl IntoIterator<Item = (JString, JString)>,
+		errors: &mut Vec<String>,
+	) {
+		let mut variables = variables
+			.into_iter()
+			.map(|(name, value)| (name.to_string(), value))
+			.filter(|(name, _)| name.starts_with(Self::ENVIRONMENT_PREFIX))
+			.collect::<Vec<_>>();
+		variables.sort_by(|(name1, _), (name2, _)| name1.cmp(name2));
+
+		for (name, value) in variables {
+			let result =
+				Self::key_of_variable(&name).and_then(|key| Ok((Self::parse_value(&key, value.as_ref())?, key)));
+			match result {
+				Ok((value, key)) => self.add(&key, value, ConfigSource::Environment(name.as_str().into())),
+				Err(exception) => errors.push(format!("{}: {}", name, Self::message_of(exception))),
+			}
+		}
+	}
+
+	/// Adds arguments of the form `key=value`. Invalid ones are collected in `errors`.
+	pub(super) fn add_arguments(&mut self, arguments: List<JString>, errors: &mut Vec<String>) {
+		for argument in arguments {
+			let argument = argument.to_string();
+			let result = match argument.split_once('=') {
+				Some((key, value)) => Self::parse_value(key.trim(), value).map(|value| (key.trim(), value)),
+				None => Err(IllegalArgumentException("Expected key=value.".into())),
+			};
+			match result {
+				Ok((key, value)) => self.add(key, value, ConfigSource::CommandLine),
+				Err(exception) => errors.push(format!("{}: {}", argument, Self::message_of(exception))),
+			}
+		}
+	}
+
+	/// The name of the environment variable for `key`.
+	pub(super) fn variable_of(key: &str) -> String {
+		format!(
+			"{}{}",
+			Self::ENVIRONMENT_PREFIX,
+			key.to_uppercase().replace(['.', '-'], "_")
+		)
+	}
+
+	fn key_of_variable(name: &str) -> Result<String, Exception> {
+		if let Some(key) = Self::KEYS.iter().find(|key| Self::variable_of(key) == name) {
+			return Ok(key.to_string());
+		}
+		let weights_prefix = Self::variable_of(Self::WEIGHTS_PREFIX);
+		if let Some(relation_type) = name.strip_prefix(&weights_prefix).filter(|rest| !rest.is_empty()) {
+			return Ok(format!("{}{}", Self::WEIGHTS_PREFIX, relation_type.to_lowercase()));
+		}
+		let settings_prefix = Self::variable_of(Self::SETTINGS_PREFIX);
+		match name
+			.strip_prefix(&settings_prefix)
+			.and_then(|rest| rest.split_once('_'))
+		{
+			Some((relation_type, key)) if !relation_type.is_empty() && !key.is_empty() => Ok(format!(
+				"{}{}.{}",
+				Self::SETTINGS_PREFIX,
+				relation_type.to_lowercase(),
+				key.to_lowercase()
+			)),
+			_ => throw!(IllegalArgumentException("Unknown config variable.".into())),
+		}
+	}
+
+	/// Parses the text of an environment variable or argument according to the type of `key`.
+	fn parse_value(key: &str, text: &str) -> Result<Value, Exception> {
+		let invalid = |expected: &str| IllegalArgumentException(format!("Expected {}: {}", expected, text).into());
+		match key {
+			"folders.articles"
+			| "folders.talks"
+			| "folders.videos"
+			| "output.file"
+			| "output.format"
+			| "recommendations.as-of"
+			| "tags.taxonomy" => Ok(Value::String(text.to_string())),
+			"recommendations.per-post" | "slugs.max-length" => text
+				.trim()
+				.parse()
+				.map(Value::Integer)
+				.map_err(|_| invalid("an integer")),
+			"recommendations.preview" | "slugs.url-safe" | "slugs.lowercase" => text
+				.trim()
+				.parse()
+				.map(Value::Boolean)
+				.map_err(|_| invalid("true or false")),
+			"genealogists.enabled" => Ok(Value::Array(
+				text.split(',')
+					.map(str::trim)
+					.filter(|genealogist| !genealogist.is_empty())
+					.map(|genealogist| Value::String(genealogist.to_string()))
+					.collect(),
+			)),
+			"genealogists.default-weight" => Self::parse_weight(text).map_err(|_| invalid("a number")),
+			key if key.starts_with(Self::WEIGHTS_PREFIX) && key.len() > Self::WEIGHTS_PREFIX.len() => {
+				Self::parse_weight(text).map_err(|_| invalid("a number"))
+			}
+			key if Self::is_setting(key) => Self::parse_weight(text).map_err(|_| invalid("a number")),
+			_ => throw!(IllegalArgumentException("Unknown config key.".into())),
+		}
+	}
+
+	/// Settings have keys of the form `genealogists.settings.<relation type>.<key>`.
+	fn is_setting(key: &str) -> bool {
+		key.strip_prefix(Self::SETTINGS_PREFIX)
+			.and_then(|rest| rest.split_once('.'))
+			.is_some_and(|(relation_type, key)| !relation_type.is_empty() && !key.is_empty() && !key.contains('.'))
+	}
+
+	fn parse_weight(text: &str) -> Result<Value, std::num::ParseFloatError> {
+		text.trim().parse().map(Value::Float)
+	}
+
+	pub(super) fn source_of(&self, key: &str) -> Option<&ConfigSource> {
+		self.values.get(key).map(|(_, source)| source)
+	}
+
+	/// The effective values, sorted by key.
+	pub(super) fn values(&self) -> List<ConfigValue> {
+		List::of(self.values.iter().map(|(key, (value, source))| {
+			ConfigValue::new(key.as_str().into(), value.to_string().into(), source.clone())
+		}))
+	}
+
+	/// The merged values in the structure of the config file.
+	pub(super) fn to_config_file(&self) -> Result<ConfigFile, Exception> {
+		let mut table = Table::new();
+		for (key, (value, _)) in &self.values {
+			let mut parts = key.split('.').collect::<Vec<_>>();
+			let last = parts.pop().unwrap_or_default();
+			let mut current = &mut table;
+			for part in parts {
+				let entry = current
+					.entry(part.to_string())
+					.or_insert_with(|| Value::Table(Table::new()));
+				current = match entry {
+					Value::Table(table) => table,
+					_ => throw!(IllegalArgumentException(format!("{}: Not a table.", key).into())),
+				};
+			}
+			current.insert(last.to_string(), value.clone());
+		}
+		Value::Table(table)
+			.try_into()
+			.map_err(|error: toml::de::Error| IllegalArgumentException(error.to_string().trim_end().into()))
+	}
+
+	pub(super) fn message_of(exception: Exception) -> String {
+		match exception {
+			IllegalArgumentException(message) => message.to_string(),
+			exception => exception.to_string(),
+		}
+	}
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod test {
+	use super::*;
+	use genealogy_java_apis::path::Path;
+	use genealogy_java_apis::test::assert_that;
+
+	#[test]
+	fn later_source__replaces_only_its_keys() {
+		let mut layers = ConfigLayers::default();
+		let file = ConfigSource::ProjectFile(Path::of("recommendations.toml"));
+		layers
+			.add_file("[output]\nfile = 'a.json'\nformat = 'text'\n".into(), file.clone())
+			.unwrap();
+		let mut errors = Vec::new();
+
+		layers.add_arguments(List::of(["output.file=b.json".into()]), &mut errors);
+
+		assert!(errors.is_empty());
+		let values = layers.values();
+		assert_that(values.get(0).unwrap()).is_equal_to(ConfigValue::new(
+			"output.file".into(),
+			"\"b.json\"".into(),
+			ConfigSource::CommandLine,
+		));
+		assert_that(values.get(1).unwrap()).is_equal_to(ConfigValue::new(
+			"output.format".into(),
+			"\"text\"".into(),
+			file,
+		));
+	}
+
+	#[test]
+	fn environment__typed_by_key() {
+		let mut layers = ConfigLayers::default();
+		let mut errors = Vec::new();
+
+		layers.add_environment(
+			[
+				("GENEALOGY_GENEALOGISTS_ENABLED".into(), "tag, repo".into()),
+				("GENEALOGY_GENEALOGISTS_WEIGHTS_REPO".into(), "0.5".into()),
+				("GENEALOGY_RECOMMENDATIONS_PER_POST".into(), "5".into()),
+				("PATH".into(), "/bin".into()),
+			],
+			&mut errors,
+		);
+
+		assert!(errors.is_empty());
+		let config_file = layers.to_config_file().unwrap();
+		assert_that(config_file.genealogists.enabled).is_equal_to(Some(vec!["tag".to_string(), "repo".to_string()]));
+		assert_that(config_file.genealogists.weights.get("repo").copied()).is_equal_to(Some(0.5));
+		assert_that(config_file.recommendations.per_post).is_equal_to(Some(5));
+		assert_that(layers.source_of("recommendations.per-post").cloned()).is_equal_to(Some(
+			ConfigSource::Environment("GENEALOGY_RECOMMENDATIONS_PER_POST".into()),
+		));
+	}
+
+	#[test]
+	fn genealogist_settings__from_environment_and_arguments() {
+		let mut layers = ConfigLayers::default();
+		let mut errors = Vec::new();
+
+		layers.add_environment(
+			[("GENEALOGY_GENEALOGISTS_SETTINGS_TYPE_PODCAST".into(), "70".into())],
+			&mut errors,
+		);
+		layers.add_arguments(
+			List::of(["genealogists.settings.type.newsletter=10".into()]),
+			&mut errors,
+		);
+
+		assert!(errors.is_empty());
+		let settings = layers.to_config_file().unwrap().genealogists.settings;
+		assert_that(settings["type"].get("podcast").copied()).is_equal_to(Some(70.0));
+		assert_that(settings["type"].get("newsletter").copied()).is_equal_to(Some(10.0));
+	}
+
+	#[test]
+	fn invalid_environment__all_reported() {
+		let mut layers = ConfigLayers::default();
+		let mut errors = Vec::new();
+
+		layers.add_environment(
+			[
+				("GENEALOGY_RECOMMENDATIONS_PER_POST".into(), "five".into()),
+				("GENEALOGY_FOLDER".into(), "content".into()),
+			],
+			&mut errors,
+		);
+
+		assert_that(errors).is_equal_to(vec![
+			"GENEALOGY_FOLDER: Unknown config variable.".to_string(),
+			"GENEALOGY_RECOMMENDATIONS_PER_POST: Expected an integer: five".to_string(),
+		]);
+	}
+}
@@ -0,0 +1,27 @@
+use genealogy_java_apis::path::Path;
+use genealogy_java_apis::string::JString;
+use std::fmt::{Display, Formatter};
+
+/// Where the effective value of a config key comes from, see [`Config`](super::Config).
+/// The sources are listed from lowest to highest precedence.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ConfigSource {
+	Default,
+	UserFile(Path),
+	ProjectFile(Path),
+	/// The name of the environment variable.
+	Environment(JString),
+	CommandLine,
+}
+
+impl Display for ConfigSource {
+	fn fmt(&self, formatter: &mut Formatter) -> std::fmt::Result {
+		match self {
+			ConfigSource::Default => write!(formatter, "default"),
+			ConfigSource::UserFile(file) => write!(formatter, "user config {}", file),
+			ConfigSource::ProjectFile(file) => write!(formatter, "project config {}", file),
+			ConfigSource::Environment(variable) => write!(formatter, "environment variable {}", variable),
+			ConfigSource::CommandLine => write!(formatter, "command line"),
+		}
+	}
+}
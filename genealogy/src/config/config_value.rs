@@ -0,0 +1,11 @@
+use crate::config::config_source::ConfigSource;
+use genealogy_java_apis::record;
+use genealogy_java_apis::string::JString;
+
+/// The effective value of one config key, formatted like in the config file, and where it comes from.
+#[record]
+pub struct ConfigValue {
+	key: JString,
+	value: JString,
+	source: ConfigSource,
+}
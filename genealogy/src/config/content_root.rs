@@ -0,0 +1,213 @@
+use crate::post::post_type::PostType;
+use crate::post::slug_rules::SlugRules;
+use crate::post::tag_taxonomy::TagTaxonomy;
+use crate::post::Post;
+use genealogy_java_apis::exception::Exception;
+use genealogy_java_apis::exception::Exception::{IllegalArgumentException, RuntimeException};
+use genealogy_java_apis::list::List;
+use genealogy_java_apis::path::Path;
+use genealogy_java_apis::stream::Stream;
+use genealogy_java_apis::string::JString;
+use genealogy_java_apis::throw;
+use globset::{Glob, GlobSet, GlobSetBuilder};
+use std::fmt::{Display, Formatter};
+use walkdir::WalkDir;
+
+/// A folder with posts of one type, see [`Config`](super::Config). The folder is walked recursively,
+/// its files are posts if their path relative to the folder matches one of the `include` patterns
+/// and none of the `exclude` patterns.
+///
+/// Symbolic links to files are always read. Symbolic links to folders are only followed with `follow_symlinks`,
+/// links that lead back into a folder that is already being walked are errors.
+///
+/// The tags of the posts are normalized with the `tag_taxonomy` and their slugs have to follow the `slug_rules`,
+/// see [`ContentRoot::create_post`].
+#[derive(Debug, Clone)]
+pub struct ContentRoot {
+	post_type: PostType,
+	folder: Path,
+	include: GlobSet,
+	exclude: GlobSet,
+	follow_symlinks: bool,
+	tag_taxonomy: TagTaxonomy,
+	slug_rules: SlugRules,
+}
+
+impl ContentRoot {
+	/// Markdown files in any subfolder.
+	pub const DEFAULT_INCLUDE: [&'static str; 3] = ["**/*.md", "**/*.markdown", "**/*.mdx"];
+
+	pub fn default_include() -> List<JString> {
+		List::of(Self::DEFAULT_INCLUDE.map(JString::from))
+	}
+
+	pub fn new(
+		post_type: PostType,
+		folder: Path,
+		include: List<JString>,
+		exclude: List<JString>,
+		follow_symlinks: bool,
+	) -> Result<ContentRoot, Exception> {
+		Ok(ContentRoot {
+			post_type,
+			folder,
+			include: Self::glob_set(include)?,
+			exclude: Self::glob_set(exclude)?,
+			follow_symlinks,
+			tag_taxonomy: TagTaxonomy::empty(),
+			slug_rules: SlugRules::default(),
+		})
+	}
+
+	/// The same content root, normalizing the tags of its posts with `tag_taxonomy`.
+	pub fn with_tag_taxonomy(self, tag_taxonomy: TagTaxonomy) -> ContentRoot {
+		ContentRoot { tag_taxonomy, ..self }
+	}
+
+	/// The same content root, checking the slugs of its posts against `slug_rules`.
+	pub fn with_slug_rules(self, slug_rules: SlugRules) -> ContentRoot {
+		ContentRoot { slug_rules, ..self }
+	}
+
+	fn glob_set(patterns: List<JString>) -> Result<GlobSet, Exception> {
+		let mut builder = GlobSetBuilder::new();
+		for pattern in patterns {
+			match Glob::new(pattern.to_string().as_str()) {
+				Ok(glob) => builder.add(glob),
+				Err(error) => throw!(IllegalArgumentException(format!("Invalid pattern: {}", error).into())),
+			};
+		}
+		builder
+			.build()
+			.map_err(|error| IllegalArgumentException(format!("Invalid pattern: {}", error).into()))
+	}
+
+	pub fn post_type(&self) -> PostType {
+		self.post_type.clone()
+	}
+
+	pub fn folder(&self) -> Path {
+		self.folder.clone()
+	}
+
+	pub fn follow_symlinks(&self) -> bool {
+		self.follow_symlinks
+	}
+
+	/// Creates the post from one of the [`ContentRoot::files`] with its [`PostType`], checks its slug
+	/// against the slug rules and normalizes its tags with the tag taxonomy, see [`Post::with_tags`].
+	pub fn create_post(&self, file: Path) -> Result<Post, Exception> {
+		let post = self.post_type.create_post(file.clone())?;
+		self.slug_rules.check(&post.slug()).map_err(|exception| {
+			RuntimeException(
+				format!("Creating {} failed: {}", self.post_type, file).into(),
+				exception.into(),
+			)
+		})?;
+		if self.tag_taxonomy.is_empty() {
+			return Ok(post);
+		}
+		Ok(post.with_tags(self.tag_taxonomy.normalize_all(post.tags())))
+	}
+
+	/// Whether a file is a post, given its path relative to the folder.
+	pub fn matches(&self, relative_path: &std::path::Path) -> bool {
+		self.include.is_match(relative_path) && !self.exclude.is_match(relative_path)
+	}
+
+	/// ```java
+	/// private static Stream<Path> markdownFilesIn(Path folder) {
+	///		return Utils.uncheckedFilesList(folder)
+	///				.filter(Files::isRegularFile)
+	///				.filter(file -> file.toString().endsWith(".md"));
+	///	}
+	/// ```
+	/// Note: Moved here from `Main`. Walks the subfolders as well, filters with the patterns
+	/// instead of the `.md` extension and sorts the files by path.
+	pub fn files(&self) -> Result<Stream<Path>, Exception> {
+		let mut files = Vec::new();
+		let entries = WalkDir::new(&self.folder)
+			.follow_links(self.follow_symlinks)
+			.sort_by_file_name();
+		for entry in entries {
+			let entry = entry.map_err(|error| {
+				RuntimeException(
+					format!("Reading the content folder {} failed.", self.folder).into(),
+					error.into(),
+				)
+			})?;
+			let relative_path = entry.path().strip_prefix(&self.folder).unwrap_or(entry.path());
+			if entry.path().is_file() && self.matches(relative_path) {
+				files.push(Path::of(entry.path()));
+			}
+		}
+		Ok(Stream::of(files))
+	}
+}
+
+impl Display for ContentRoot {
+	fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+		write!(formatter, "{}: {}", self.post_type, self.folder)
+	}
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod test {
+	use super::*;
+	use genealogy_java_apis::test::assert_that;
+
+	fn content_root(exclude: &[&str]) -> ContentRoot {
+		ContentRoot::new(
+			PostType::Article,
+			Path::of("content"),
+			ContentRoot::default_include(),
+			List::of(exclude.iter().map(|pattern| (*pattern).into())),
+			false,
+		)
+		.unwrap()
+	}
+
+	#[test]
+	fn nested_markdown_files__match() {
+		let content_root = content_root(&[]);
+
+		for path in ["post.md", "2023/05/post.markdown", "2023/post.mdx"] {
+			assert!(content_root.matches(std::path::Path::new(path)), "{}", path);
+		}
+		assert!(!content_root.matches(std::path::Path::new("2023/image.png")));
+	}
+
+	#[test]
+	fn excluded_files__no_match() {
+		let content_root = content_root(&["**/drafts/**"]);
+
+		assert!(!content_root.matches(std::path::Path::new("2023/drafts/post.md")));
+		assert!(content_root.matches(std::path::Path::new("2023/post.md")));
+	}
+
+	#[test]
+	fn files__walked_recursively_and_sorted() {
+		let folder = std::env::temp_dir().join(format!("genealogy-content-root-{}", std::process::id()));
+		std::fs::create_dir_all(folder.join("2023/05")).unwrap();
+		for file in ["b.md", "2023/05/a.markdown", "2023/notes.txt"] {
+			std::fs::write(folder.join(file), "").unwrap();
+		}
+		let content_root = ContentRoot::new(
+			PostType::Talk,
+			Path::of(&folder),
+			ContentRoot::default_include(),
+			List::new(),
+			false,
+		)
+		.unwrap();
+
+		let files = content_root.files().unwrap().to_list().unwrap();
+
+		std::fs::remove_dir_all(&folder).unwrap();
+		assert_that(files).is_equal_to(List::of([
+			Path::of(folder.join("2023/05/a.markdown")),
+			Path::of(folder.join("b.md")),
+		]));
+	}
+}
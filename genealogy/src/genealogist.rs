@@ -7,6 +7,7 @@ use std::hash::{Hash, Hasher};
 use std::rc::Rc;
 
 pub mod genealogist_service;
+pub mod genealogist_settings;
 pub mod relation_type;
 pub mod typed_relation;
 
@@ -65,7 +66,7 @@ impl Display for Genealogist {
 
 impl PartialEq for Genealogist {
 	fn eq(&self, other: &Self) -> bool {
-		#[allow(clippy::vtable_address_comparisons)]
+		#[allow(ambiguous_wide_pointer_comparisons)]
 		std::ptr::eq(self.geneaologist.as_ref(), other.geneaologist.as_ref())
 	}
 }
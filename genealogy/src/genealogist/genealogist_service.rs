@@ -1,3 +1,5 @@
+use crate::genealogist::genealogist_settings::GenealogistSettings;
+use crate::genealogist::relation_type::RelationType;
 use crate::genealogist::Genealogist;
 use crate::post::Post;
 use genealogy_java_apis::collection::Collection;
@@ -25,6 +27,20 @@ impl GenealogistService {
 	pub fn procure(&self, posts: Collection<Post>) -> Result<Genealogist, Exception> {
 		self.genealogist_service.procure(posts)
 	}
+
+	/// See [`GenealogistServiceTrait::procure_with_settings`].
+	pub fn procure_with_settings(
+		&self,
+		posts: Collection<Post>,
+		settings: GenealogistSettings,
+	) -> Result<Genealogist, Exception> {
+		self.genealogist_service.procure_with_settings(posts, settings)
+	}
+
+	/// The [`RelationType`] of the [`Genealogist`]s procured by this service.
+	pub fn relation_type(&self) -> RelationType {
+		self.genealogist_service.relation_type()
+	}
 }
 
 /// ```java
@@ -40,6 +56,24 @@ pub trait GenealogistServiceTrait {
 	///	Genealogist procure(Collection<Post> posts);
 	/// ```
 	fn procure(&self, posts: Collection<Post>) -> Result<Genealogist, Exception>;
+
+	/// The [`RelationType`] of the [`Genealogist`]s procured by this service.
+	///
+	/// Not part of the original Java interface, it allows selecting genealogists by name
+	/// without having to procure them first.
+	fn relation_type(&self) -> RelationType;
+
+	/// Like [`GenealogistServiceTrait::procure`], but with the settings of the genealogist from the config.
+	///
+	/// Not part of the original Java interface, only genealogists that have settings need to implement it.
+	#[allow(unused_variables)]
+	fn procure_with_settings(
+		&self,
+		posts: Collection<Post>,
+		settings: GenealogistSettings,
+	) -> Result<Genealogist, Exception> {
+		self.procure(posts)
+	}
 }
 
 /// Helper to create instance of the type erased wrapper.
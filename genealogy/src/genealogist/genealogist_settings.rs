@@ -0,0 +1,66 @@
+use crate::genealogist::relation_type::RelationType;
+use genealogy_java_apis::map::Map;
+use genealogy_java_apis::optional::Optional;
+use genealogy_java_apis::string::JString;
+use std::fmt::{Display, Formatter};
+
+/// The settings of a genealogist from the config table `genealogists.settings.<relation type>`,
+/// e.g. the scores of the type genealogist by kind of post. All settings are numbers,
+/// what they mean is up to the genealogist.
+#[derive(Debug, Clone)]
+pub struct GenealogistSettings {
+	relation_type: RelationType,
+	settings: Map<JString, f64>,
+}
+
+impl GenealogistSettings {
+	pub fn new(relation_type: RelationType, settings: Map<JString, f64>) -> Self {
+		Self {
+			relation_type,
+			settings: Map::copy_of(settings),
+		}
+	}
+
+	/// No settings, which leaves every genealogist with its defaults.
+	pub fn empty(relation_type: RelationType) -> Self {
+		Self::new(relation_type, Map::new())
+	}
+
+	pub fn relation_type(&self) -> RelationType {
+		self.relation_type.clone()
+	}
+
+	pub fn get(&self, key: JString) -> Optional<f64> {
+		Optional::of_nullable(self.settings.get(key))
+	}
+
+	/// The keys, sorted.
+	pub fn keys(&self) -> Vec<JString> {
+		let mut keys = self
+			.settings
+			.clone()
+			.entry_set()
+			.into_iter()
+			.map(|entry| entry.get_key())
+			.collect::<Vec<_>>();
+		keys.sort();
+		keys
+	}
+}
+
+/// Note: Settings only need to be displayed as part of the [`Config`](crate::config::Config).
+impl Display for GenealogistSettings {
+	fn fmt(&self, formatter: &mut Formatter) -> std::fmt::Result {
+		let settings = self
+			.keys()
+			.into_iter()
+			.map(|key| format!("{}={}", key, self.settings.get(key.clone()).unwrap_or_default()))
+			.collect::<Vec<_>>();
+		write!(
+			formatter,
+			"GenealogistSettings[relationType={}, settings={{{}}}]",
+			self.relation_type.value(),
+			settings.join(", ")
+		)
+	}
+}
@@ -1,16 +1,24 @@
 use crate::genealogist::typed_relation::TypedRelation;
 use crate::genealogist::Genealogist;
+use crate::genealogy::explanation::{Explanation, WeightedRelation};
 use crate::genealogy::relation::Relation;
+use crate::genealogy::relation_scores::RelationScores;
 use crate::genealogy::weights::Weights;
+use crate::post::slug::Slug;
 use crate::post::Post;
+use crate::recommendation::recommender::Recommender;
 use genealogy_java_apis::collection::Collection;
 use genealogy_java_apis::exception::Exception;
-use genealogy_java_apis::list::ArrayList;
+use genealogy_java_apis::exception::Exception::IllegalArgumentException;
+use genealogy_java_apis::list::{ArrayList, List};
 use genealogy_java_apis::map::{JHashMap, Map};
-use genealogy_java_apis::record;
 use genealogy_java_apis::stream::Stream;
+use genealogy_java_apis::string::JString;
+use genealogy_java_apis::{record, throw};
 
+pub mod explanation;
 pub mod relation;
+pub mod relation_scores;
 #[cfg(test)]
 pub mod relation_test_helper;
 pub mod weights;
@@ -52,6 +60,95 @@ impl Genealogy {
 		self.aggregate_typed_relations(self.infer_typed_relations())
 	}
 
+	/// Infers the relation for every ordered pair of posts together with the typed relations it was aggregated from.
+	///
+	/// Note: Not part of the original Java code. Unlike [`Genealogy::infer_relations`], the pairs are inferred
+	/// lazily one after the other, so the relations don't have to fit in memory all at once.
+	pub fn infer_relation_scores(&self) -> Stream<RelationScores> {
+		let posts = self.posts.clone();
+		let genealogists = self.genealogists.clone();
+		let weights = self.weights.clone();
+		self.posts.stream().flat_map(move |post1| {
+			let genealogists = genealogists.clone();
+			let weights = weights.clone();
+			posts
+				.stream()
+				.filter({
+					let post1 = post1.clone();
+					move |post2| *post2 != post1
+				})
+				.map(move |post2| {
+					let typed_relations = genealogists
+						.stream()
+						.map({
+							let post1 = post1.clone();
+							move |genealogist| genealogist.infer(post1.clone(), post2.clone())
+						})
+						.to_list()?;
+					let relation = Relation::aggregate(typed_relations.stream(), weights.clone())?;
+					Ok(RelationScores::new(relation, typed_relations))
+				})
+		})
+	}
+
+	/// Explains the relation from the post with slug `post1` to the post with slug `post2`:
+	/// The score every genealogist inferred, the weight of its relation type and where `post2`
+	/// ranks among the posts the `recommender` would recommend for `post1`.
+	///
+	/// Note: Not part of the original Java code.
+	pub fn explain(&self, post1: Slug, post2: Slug, recommender: &Recommender) -> Result<Explanation, Exception> {
+		let post1 = self.find_post(post1)?;
+		let post2 = self.find_post(post2)?;
+		if post1 == post2 {
+			throw!(IllegalArgumentException(
+				"Posts are never related to themselves.".into()
+			));
+		}
+
+		let typed_relations = self
+			.infer_typed_relations_between(post1.clone(), post2.clone())
+			.to_list()?;
+		let relation = Relation::aggregate(typed_relations.stream(), self.weights.clone())?;
+		let weighted_relations = List::of(typed_relations.into_iter().map(|typed_relation| {
+			let weight = self.weights.weight_of(typed_relation.r#type());
+			WeightedRelation::new(typed_relation, weight)
+		}));
+
+		let mut relations = Vec::new();
+		for other in self.posts.clone() {
+			if other != post1 {
+				relations.push(Relation::aggregate(
+					self.infer_typed_relations_between(post1.clone(), other),
+					self.weights.clone(),
+				)?);
+			}
+		}
+		let ranked = recommender.rank(Stream::of(relations))?;
+		let rank = ranked
+			.clone()
+			.into_iter()
+			.position(|other_relation| other_relation.post2() == post2)
+			.map(|index| index + 1);
+		let candidates = ranked.length();
+
+		Ok(Explanation::new(relation, weighted_relations, rank.into(), candidates))
+	}
+
+	fn find_post(&self, slug: Slug) -> Result<Post, Exception> {
+		match self.posts.clone().into_iter().find(|post| post.slug() == slug) {
+			Some(post) => Ok(post),
+			None => throw!(IllegalArgumentException(
+				JString::from("No post with slug: ") + slug.value()
+			)),
+		}
+	}
+
+	fn infer_typed_relations_between(&self, post1: Post, post2: Post) -> Stream<TypedRelation> {
+		self.genealogists
+			.stream()
+			.map(move |genealogist| genealogist.infer(post1.clone(), post2.clone()))
+	}
+
 	/// ```java
 	/// private Stream<Relation> aggregateTypedRelations(Stream<TypedRelation> typedRelations) {
 	///		Map<Post, Map<Post, Collection<TypedRelation>>> sortedTypedRelations = new HashMap<>();
@@ -525,6 +622,104 @@ mod test {
 			]);
 			Ok(())
 		}
+
+		fn two_genealogists__explain(&self) -> Result<(), Exception> {
+			let genealogy = Genealogy::new(
+				List::of([self.posts.a.clone(), self.posts.b.clone(), self.posts.c.clone()]),
+				List::of([self.tag_genealogist.clone(), self.link_genealogist.clone()]),
+				self.weights.clone(),
+			);
+
+			let explanation = genealogy.explain(self.posts.a.slug(), self.posts.c.slug(), &Recommender::new())?;
+
+			let tag_score = (Self::TAG_SCORE_A_C as f64) * Self::TAG_WEIGHT;
+			let link_score = (Self::LINK_SCORE_A_C as f64) * Self::LINK_WEIGHT;
+			assert_that(explanation.relation()).is_equal_to(Relation::new(
+				self.posts.a.clone(),
+				self.posts.c.clone(),
+				((tag_score + link_score) / 2.0).round() as i64,
+			)?);
+			assert_that(
+				explanation
+					.typed_relations()
+					.into_iter()
+					.map(|typed_relation| (typed_relation.typed_relation().score(), typed_relation.weight()))
+					.collect::<Vec<_>>(),
+			)
+			.is_equal_to(vec![
+				(Self::TAG_SCORE_A_C as i64, Self::TAG_WEIGHT),
+				(Self::LINK_SCORE_A_C as i64, Self::LINK_WEIGHT),
+			]);
+			assert_that(explanation.weighted_average()).is_equal_to((tag_score + link_score) / 2.0);
+			assert_that(explanation.rank().get()?).is_equal_to(2);
+			assert_that(explanation.candidates()).is_equal_to(2);
+			Ok(())
+		}
+
+		fn two_genealogists__relation_scores(&self) -> Result<(), Exception> {
+			let genealogy = Genealogy::new(
+				List::of([self.posts.a.clone(), self.posts.b.clone()]),
+				List::of([self.tag_genealogist.clone(), self.link_genealogist.clone()]),
+				self.weights.clone(),
+			);
+
+			let relation_scores = genealogy.infer_relation_scores().to_list()?;
+
+			assert_that(relation_scores.length()).is_equal_to(2);
+			let scores_a_b = relation_scores.get(0)?;
+			assert_that(scores_a_b.relation()).is_equal_to(Relation::new(
+				self.posts.a.clone(),
+				self.posts.b.clone(),
+				(((Self::TAG_SCORE_A_B as f64) * Self::TAG_WEIGHT + (Self::LINK_SCORE_A_B as f64) * Self::LINK_WEIGHT)
+					/ 2.0)
+					.round() as i64,
+			)?);
+			assert_that(
+				scores_a_b
+					.typed_relations()
+					.into_iter()
+					.map(|typed_relation| (typed_relation.r#type(), typed_relation.score()))
+					.collect::<Vec<_>>(),
+			)
+			.is_equal_to(vec![
+				(self.tag_relation.clone(), Self::TAG_SCORE_A_B as i64),
+				(self.link_relation.clone(), Self::LINK_SCORE_A_B as i64),
+			]);
+			assert_that(relation_scores.get(1)?.relation().post1()).is_equal_to(self.posts.b.clone());
+			Ok(())
+		}
+
+		fn explain__unknown_slug(&self) -> Result<(), Exception> {
+			let genealogy = Genealogy::new(
+				List::of([self.posts.a.clone(), self.posts.b.clone()]),
+				List::of([self.tag_genealogist.clone()]),
+				self.weights.clone(),
+			);
+
+			let explanation = genealogy.explain(self.posts.a.slug(), Slug::new("unknown".into())?, &Recommender::new());
+
+			assert!(matches!(explanation, Err(IllegalArgumentException(_))));
+			Ok(())
+		}
+
+		fn explain__tied_scores__ranked_by_slug(&self) -> Result<(), Exception> {
+			let tag_relation = self.tag_relation.clone();
+			let tied_genealogist = BiFunction::from(move |post1: Post, post2: Post| {
+				TypedRelation::new(post1, post2, tag_relation.clone(), 50)
+			})
+			.into();
+			let genealogy = Genealogy::new(
+				List::of([self.posts.a.clone(), self.posts.b.clone(), self.posts.c.clone()]),
+				List::of([tied_genealogist]),
+				self.weights.clone(),
+			);
+
+			let explanation = genealogy.explain(self.posts.a.slug(), self.posts.c.slug(), &Recommender::new())?;
+
+			assert_that(explanation.rank().get()?).is_equal_to(2);
+			assert_that(explanation.candidates()).is_equal_to(2);
+			Ok(())
+		}
 	}
 
 	/// tagScore and linkScore access the posts, but are also called when
@@ -650,4 +845,30 @@ mod test {
 	fn two_genealogists__three_posts() {
 		GenealogyTests::new().unwrap().two_genealogists__three_posts().unwrap();
 	}
+
+	#[test]
+	fn two_genealogists__explain() {
+		GenealogyTests::new().unwrap().two_genealogists__explain().unwrap();
+	}
+
+	#[test]
+	fn two_genealogists__relation_scores() {
+		GenealogyTests::new()
+			.unwrap()
+			.two_genealogists__relation_scores()
+			.unwrap();
+	}
+
+	#[test]
+	fn explain__unknown_slug() {
+		GenealogyTests::new().unwrap().explain__unknown_slug().unwrap();
+	}
+
+	#[test]
+	fn explain__tied_scores__ranked_by_slug() {
+		GenealogyTests::new()
+			.unwrap()
+			.explain__tied_scores__ranked_by_slug()
+			.unwrap();
+	}
 }
@@ -0,0 +1,50 @@
+use crate::genealogist::typed_relation::TypedRelation;
+use crate::genealogy::relation::Relation;
+use genealogy_java_apis::list::List;
+use genealogy_java_apis::optional::Optional;
+use genealogy_java_apis::record;
+
+/// Explains how the [`Relation`] between two posts came to be, see [`Genealogy::explain`].
+///
+/// `rank` is the position of `relation.post2()` among the posts recommended for `relation.post1()`,
+/// starting at 1, out of `candidates` posts that could be recommended for `relation.post1()`.
+/// It is empty if `relation.post2()` can't be recommended for `relation.post1()`, e.g. because it is a draft.
+///
+/// [`Genealogy::explain`]: crate::genealogy::Genealogy::explain
+#[record(equals = false, hash = false)]
+pub struct Explanation {
+	relation: Relation,
+	typed_relations: List<WeightedRelation>,
+	rank: Optional<usize>,
+	candidates: usize,
+}
+
+impl Explanation {
+	/// Average of the weighted scores of all genealogists, rounding it results in the score of the relation.
+	pub fn weighted_average(&self) -> f64 {
+		let typed_relations = self.typed_relations();
+		if typed_relations.is_empty() {
+			return 0.0;
+		}
+
+		let sum = typed_relations
+			.clone()
+			.into_iter()
+			.map(|typed_relation| typed_relation.weighted_score())
+			.sum::<f64>();
+		sum / (typed_relations.length() as f64)
+	}
+}
+
+/// A [`TypedRelation`] inferred by one genealogist together with the weight of its relation type.
+#[record(equals = false, hash = false)]
+pub struct WeightedRelation {
+	typed_relation: TypedRelation,
+	weight: f64,
+}
+
+impl WeightedRelation {
+	pub fn weighted_score(&self) -> f64 {
+		(self.typed_relation.score() as f64) * self.weight
+	}
+}
@@ -0,0 +1,14 @@
+use crate::genealogist::typed_relation::TypedRelation;
+use crate::genealogy::relation::Relation;
+use genealogy_java_apis::list::List;
+use genealogy_java_apis::record;
+
+/// A [`Relation`] together with the [`TypedRelation`]s it was aggregated from,
+/// see [`Genealogy::infer_relation_scores`].
+///
+/// [`Genealogy::infer_relation_scores`]: crate::genealogy::Genealogy::infer_relation_scores
+#[record(equals = false, hash = false)]
+pub struct RelationScores {
+	relation: Relation,
+	typed_relations: List<TypedRelation>,
+}
@@ -1,6 +1,7 @@
 use crate::genealogist::relation_type::RelationType;
 use genealogy_java_apis::map::Map;
 use genealogy_java_apis::map_of;
+use std::fmt::{Display, Formatter};
 
 /// ```java
 /// public class Weights {
@@ -42,6 +43,26 @@ impl Weights {
 	}
 }
 
+/// Note: Weights only need to be displayed as part of the [`Config`](crate::config::Config).
+impl Display for Weights {
+	fn fmt(&self, formatter: &mut Formatter) -> std::fmt::Result {
+		let mut weights = self
+			.weights
+			.clone()
+			.entry_set()
+			.into_iter()
+			.map(|entry| format!("{}={}", entry.get_key().value(), entry.get_value()))
+			.collect::<Vec<_>>();
+		weights.sort();
+		write!(
+			formatter,
+			"Weights[weights={{{}}}, defaultWeight={}]",
+			weights.join(", "),
+			self.default_weight
+		)
+	}
+}
+
 #[allow(non_snake_case)]
 #[cfg(test)]
 mod test {
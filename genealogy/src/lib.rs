@@ -2,9 +2,11 @@
 pub mod config;
 pub mod genealogist;
 pub mod genealogy;
+pub mod output;
 pub mod post;
 pub mod process_details;
 pub mod recommendation;
+pub mod statistics;
 
 /// ```java
 /// public class TextParserTests {
@@ -13,3 +15,4 @@ pub mod recommendation;
 pub mod text_parser_tests;
 
 pub mod utils;
+pub mod validation;
@@ -0,0 +1,24 @@
+pub mod config_text;
+pub mod explanation_json;
+pub mod explanation_text;
+pub mod front_matter_recommendations;
+pub mod html_report;
+pub mod hugo_data;
+pub mod jekyll_data;
+pub mod output_format;
+mod output_utils;
+pub mod post_json;
+pub mod recommendation_json;
+pub mod recommendation_text;
+pub mod relation_dot;
+pub mod relation_gexf;
+pub mod relation_graph;
+pub mod relation_graph_ml;
+pub mod relation_json;
+pub mod relation_matrix;
+pub mod relation_text;
+pub mod statistics_json;
+pub mod statistics_text;
+pub mod validation_json;
+pub mod validation_text;
+pub mod zola_data;
@@ -0,0 +1,34 @@
+use crate::config::config_source::ConfigSource;
+use crate::config::Config;
+use genealogy_java_apis::string::JString;
+
+/// Formats the effective values of a [`Config`] like a config file with flat keys, every value followed by
+/// where it comes from:
+/// ```text
+/// folders.articles = "./content/articles/" # project config /home/user/blog/recommendations.toml
+/// recommendations.per-post = 5 # environment variable GENEALOGY_RECOMMENDATIONS_PER_POST
+/// output.file = "recommendations.json" # project config /home/user/blog/recommendations.toml, recommendations only
+/// ```
+/// An output file that wasn't given on the command line is only used for the recommendations,
+/// see [`Config::explicit_output_file`].
+///
+/// The empty enum has the same effect as a private constructor, preventing instantiation.
+pub enum ConfigText {}
+
+impl ConfigText {
+	pub fn to_text(config: &Config) -> JString {
+		let lines = config
+			.values()
+			.into_iter()
+			.map(|value| {
+				let scope = if value.key() == "output.file" && value.source() != ConfigSource::CommandLine {
+					", recommendations only"
+				} else {
+					""
+				};
+				format!("{} = {} # {}{}", value.key(), value.value(), value.source(), scope)
+			})
+			.collect::<Vec<_>>();
+		lines.join("\n").into()
+	}
+}
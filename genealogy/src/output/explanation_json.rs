@@ -0,0 +1,84 @@
+use crate::genealogy::explanation::{Explanation, WeightedRelation};
+use crate::output::output_utils::OutputUtils;
+use genealogy_java_apis::exception::Exception;
+use genealogy_java_apis::string::JString;
+use serde::Serialize;
+
+/// Serializes an [`Explanation`] to JSON.
+///
+/// ```json
+/// {
+/// 	"schemaVersion": 1,
+/// 	"post1": "some-slug",
+/// 	"post2": "other-slug",
+/// 	"genealogists": [
+/// 		{ "type": "tag", "score": 60, "weight": 1.0, "weightedScore": 60.0 }
+/// 	],
+/// 	"weightedAverage": 60.0,
+/// 	"score": 60,
+/// 	"rank": 1,
+/// 	"candidates": 42
+/// }
+/// ```
+/// `rank` is `null` if `post2` can't be recommended for `post1`.
+///
+/// The empty enum has the same effect as a private constructor, preventing instantiation.
+pub enum ExplanationJson {}
+
+impl ExplanationJson {
+	/// Version of the output schema, see [`RecommendationJson::SCHEMA_VERSION`](crate::output::recommendation_json::RecommendationJson::SCHEMA_VERSION).
+	pub const SCHEMA_VERSION: u32 = 1;
+
+	pub fn to_json(explanation: &Explanation) -> Result<JString, Exception> {
+		let relation = explanation.relation();
+		let document = ExplanationDocument {
+			schema_version: Self::SCHEMA_VERSION,
+			post1: relation.post1().slug().value().to_string(),
+			post2: relation.post2().slug().value().to_string(),
+			genealogists: explanation
+				.typed_relations()
+				.into_iter()
+				.map(|typed_relation| GenealogistEntry::from(&typed_relation))
+				.collect(),
+			weighted_average: explanation.weighted_average(),
+			score: relation.score(),
+			rank: explanation.rank().get().ok(),
+			candidates: explanation.candidates(),
+		};
+		OutputUtils::to_json(&document)
+	}
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ExplanationDocument {
+	schema_version: u32,
+	post1: String,
+	post2: String,
+	genealogists: Vec<GenealogistEntry>,
+	weighted_average: f64,
+	score: i64,
+	rank: Option<usize>,
+	candidates: usize,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GenealogistEntry {
+	r#type: String,
+	score: i64,
+	weight: f64,
+	weighted_score: f64,
+}
+
+impl From<&WeightedRelation> for GenealogistEntry {
+	fn from(weighted_relation: &WeightedRelation) -> Self {
+		let typed_relation = weighted_relation.typed_relation();
+		Self {
+			r#type: typed_relation.r#type().value().to_string(),
+			score: typed_relation.score(),
+			weight: weighted_relation.weight(),
+			weighted_score: weighted_relation.weighted_score(),
+		}
+	}
+}
@@ -0,0 +1,95 @@
+use crate::genealogy::explanation::Explanation;
+use crate::output::recommendation_text::RecommendationText;
+use genealogy_java_apis::string::JString;
+
+/// Formats an [`Explanation`] as human readable text:
+/// ```text
+/// Other Title [video, other-slug] for Some Title [article, some-slug]
+/// 	tag: 60 × 1 = 60
+/// 	link: 40 × 0.75 = 30
+/// 	average: 45.00 → score 45
+/// 	rank: 2 of 42
+/// ```
+/// The empty enum has the same effect as a private constructor, preventing instantiation.
+pub enum ExplanationText {}
+
+impl ExplanationText {
+	pub fn to_text(explanation: &Explanation) -> JString {
+		let relation = explanation.relation();
+		let mut text = format!(
+			"{} for {}",
+			RecommendationText::describe(&relation.post2()),
+			RecommendationText::describe(&relation.post1())
+		);
+		for weighted_relation in explanation.typed_relations() {
+			let typed_relation = weighted_relation.typed_relation();
+			text += &format!(
+				"\n\t{}: {} × {} = {}",
+				typed_relation.r#type().value(),
+				typed_relation.score(),
+				weighted_relation.weight(),
+				weighted_relation.weighted_score()
+			);
+		}
+		text += &format!(
+			"\n\taverage: {:.2} → score {}",
+			explanation.weighted_average(),
+			relation.score()
+		);
+		text += &match explanation.rank().get() {
+			Ok(rank) => format!("\n\trank: {} of {}", rank, explanation.candidates()),
+			Err(_) => format!("\n\trank: not recommended, {} candidates", explanation.candidates()),
+		};
+		text.into()
+	}
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::genealogist::relation_type::RelationType;
+	use crate::genealogist::typed_relation::TypedRelation;
+	use crate::genealogy::explanation::WeightedRelation;
+	use crate::genealogy::relation_test_helper::RelationTestHelper;
+	use crate::post::test::PostTestHelper;
+	use genealogy_java_apis::list::List;
+	use genealogy_java_apis::optional::Optional;
+	use genealogy_java_apis::test::assert_that;
+
+	#[test]
+	fn explanation__scores_weights_and_rank() {
+		let post_a = PostTestHelper::create_with_slug("a".into()).unwrap();
+		let post_b = PostTestHelper::create_with_slug("b".into()).unwrap();
+		let tag = TypedRelation::new(
+			post_a.clone(),
+			post_b.clone(),
+			RelationType::new("tag".into()).unwrap(),
+			60,
+		)
+		.unwrap();
+		let link = TypedRelation::new(
+			post_a.clone(),
+			post_b.clone(),
+			RelationType::new("link".into()).unwrap(),
+			40,
+		)
+		.unwrap();
+		let explanation = Explanation::new(
+			RelationTestHelper::create(post_a, post_b, 45).unwrap(),
+			List::of([WeightedRelation::new(tag, 1.0), WeightedRelation::new(link, 0.75)]),
+			Optional::of(2),
+			3,
+		);
+
+		let text = ExplanationText::to_text(&explanation);
+
+		assert_that(text).is_equal_to(
+			"Title [article, b] for Title [article, a]\n\
+			\ttag: 60 × 1 = 60\n\
+			\tlink: 40 × 0.75 = 30\n\
+			\taverage: 45.00 → score 45\n\
+			\trank: 2 of 3",
+		);
+	}
+}
@@ -0,0 +1,312 @@
+use crate::post::factories::front_matter_format::FrontMatterFormat;
+use crate::post::factories::post_factory::PostFactory;
+use crate::post::slug::Slug;
+use crate::recommendation::Recommendation;
+use genealogy_java_apis::exception::Exception;
+use genealogy_java_apis::exception::Exception::IllegalArgumentException;
+use genealogy_java_apis::string::JString;
+use genealogy_java_apis::{r#static, throw};
+
+/// Writes the slugs of the recommended posts into the front matter of the file of the post they are recommended for:
+/// ```text
+/// recommendations: ["some-slug", "other-slug"]
+/// ```
+/// In TOML front matter, it's `recommendations = ["some-slug", "other-slug"]`, JSON front matter isn't supported.
+/// The slugs are quoted, so that slugs with e.g. `,`, `:` or `#` are read back as they are.
+/// An existing top-level `recommendations` key is replaced together with the rest of its value, e.g. the items of a YAML block list
+/// or the lines of a multi-line TOML array. Otherwise the key is added at the end of the front matter, in TOML before
+/// the first table, so it doesn't end up in the table. Every other line is left exactly as it is, including its line ending.
+///
+/// The empty enum has the same effect as a private constructor, preventing instantiation.
+pub enum FrontMatterRecommendations {}
+
+impl FrontMatterRecommendations {
+	r#static!(pub KEY: JString = "recommendations".into());
+
+	/// Returns the content of a post's file with the recommended posts in its front matter.
+	pub fn write(content: JString, recommendation: &Recommendation) -> Result<JString, Exception> {
+		let slugs = recommendation
+			.recommended_posts()
+			.into_iter()
+			.map(|post| post.slug())
+			.collect::<Vec<_>>();
+		Self::write_slugs(content, &slugs, true)
+	}
+
+	/// Returns the content of the file of a post that isn't recommended any posts, e.g. because it became a draft,
+	/// with an existing `recommendations` key emptied. Without that key, the content is returned unchanged.
+	pub fn clear(content: JString) -> Result<JString, Exception> {
+		Self::write_slugs(content, &[], false)
+	}
+
+	fn write_slugs(content: JString, slugs: &[Slug], add_missing: bool) -> Result<JString, Exception> {
+		let content = content.to_string();
+		let lines = content.split_inclusive('\n').collect::<Vec<_>>();
+		let format = FrontMatterFormat::detect(lines.first().copied().unwrap_or_default());
+		let (separator, assignment) = match format {
+			FrontMatterFormat::Yaml => (PostFactory::FRONT_MATTER_SEPARATOR(), ':'),
+			FrontMatterFormat::Toml => (PostFactory::TOML_FRONT_MATTER_SEPARATOR(), '='),
+			// recommendations are never written into JSON front matter, so there are none to clear
+			FrontMatterFormat::Json if !add_missing => return Ok(content.into()),
+			FrontMatterFormat::Json => throw!(IllegalArgumentException(
+				"Writing recommendations into JSON front matter isn't supported.".into()
+			)),
+		};
+
+		// like `PostFactory::read_front_matter`, the front matter starts after the first separator
+		let Some(start) = lines.iter().position(|line| line.trim() == separator) else {
+			throw!(IllegalArgumentException("No front matter found.".into()));
+		};
+		let Some(end) = lines[start + 1..]
+			.iter()
+			.position(|line| line.trim() == separator)
+			.map(|position| start + 1 + position)
+		else {
+			throw!(IllegalArgumentException("Front matter isn't closed.".into()));
+		};
+
+		let line_ending = if lines[end].ends_with("\r\n") { "\r\n" } else { "\n" };
+		let slugs = slugs.iter().map(Self::quoted).collect::<Vec<_>>();
+		let recommendations = match format {
+			FrontMatterFormat::Toml => format!("{} = [{}]", Self::KEY(), slugs.join(", ")),
+			_ => format!("{}: [{}]", Self::KEY(), slugs.join(", ")),
+		};
+
+		// keys after the first TOML table belong to the table
+		let top_level_end = match format {
+			FrontMatterFormat::Toml => lines[start + 1..end]
+				.iter()
+				.position(|line| line.trim_start().starts_with('['))
+				.map_or(end, |position| start + 1 + position),
+			_ => end,
+		};
+		let existing = lines[start + 1..top_level_end]
+			.iter()
+			.position(|line| Self::is_recommendations(line, format, assignment))
+			.map(|position| start + 1 + position);
+		if existing.is_none() && !add_missing {
+			return Ok(content.into());
+		}
+		let replaced = existing.map(|index| index..Self::end_of_value(&lines, index, top_level_end, format));
+		let mut written = String::with_capacity(content.len() + recommendations.len());
+		for (index, line) in lines.iter().enumerate() {
+			if let Some(replaced) = &replaced {
+				if index == replaced.start {
+					let line_ending = &line[line.trim_end_matches(['\r', '\n']).len()..];
+					written += &recommendations;
+					written += line_ending;
+				}
+				if replaced.contains(&index) {
+					continue;
+				}
+			} else if index == top_level_end {
+				written += &recommendations;
+				written += line_ending;
+			}
+			written += line;
+		}
+		Ok(written.into())
+	}
+
+	/// YAML and TOML basic strings escape quotation marks and backslashes the same way.
+	fn quoted(slug: &Slug) -> String {
+		let escaped = slug.value().to_string().replace('\\', "\\\\").replace('"', "\\\"");
+		format!("\"{}\"", escaped)
+	}
+
+	/// In YAML, only keys that start at the beginning of the line are top-level keys, indented ones belong to
+	/// a nested map or are part of the value of the key before them.
+	fn is_recommendations(line: &str, format: FrontMatterFormat, assignment: char) -> bool {
+		let line = match format {
+			FrontMatterFormat::Toml => line.trim_start(),
+			_ => line,
+		};
+		line.strip_prefix(Self::KEY().as_ref() as &str)
+			.is_some_and(|rest| rest.trim_start().starts_with(assignment))
+	}
+
+	/// The index after the last line of the value of the key at `index`. In YAML, lines that are indented
+	/// or start with `-` belong to the value of the key before them, as do blank lines between them,
+	/// in TOML the lines up to the end of an array.
+	fn end_of_value(lines: &[&str], index: usize, end: usize, format: FrontMatterFormat) -> usize {
+		match format {
+			FrontMatterFormat::Toml => {
+				let mut depth = 0_i32;
+				let mut current = index;
+				while current < end {
+					depth += lines[current].matches('[').count() as i32 - lines[current].matches(']').count() as i32;
+					current += 1;
+					if depth <= 0 {
+						break;
+					}
+				}
+				current
+			}
+			_ => {
+				let mut value_end = index + 1;
+				for (current, line) in lines.iter().enumerate().take(end).skip(index + 1) {
+					if line.trim().is_empty() {
+						continue;
+					}
+					if !line.starts_with([' ', '\t', '-']) {
+						break;
+					}
+					value_end = current + 1;
+				}
+				value_end
+			}
+		}
+	}
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod test {
+	use super::*;
+	use genealogy_java_apis::list::List;
+	use genealogy_java_apis::test::assert_that;
+
+	fn slugs(slugs: &[&str]) -> Vec<Slug> {
+		slugs.iter().map(|slug| Slug::new((*slug).into()).unwrap()).collect()
+	}
+
+	#[test]
+	fn without_recommendations__key_added_at_end_of_front_matter() {
+		let content = "---\ntitle: Title\nslug: a\n---\n\nContent\n";
+
+		let written = FrontMatterRecommendations::write_slugs(content.into(), &slugs(&["b", "c"]), true).unwrap();
+
+		assert_that(written)
+			.is_equal_to("---\ntitle: Title\nslug: a\nrecommendations: [\"b\", \"c\"]\n---\n\nContent\n");
+	}
+
+	#[test]
+	fn with_recommendations__key_replaced_and_other_lines_kept() {
+		let content = "---\r\ntitle:   Title  \r\nrecommendations: [x]\r\n# comment\r\n---\r\nContent";
+
+		let written = FrontMatterRecommendations::write_slugs(content.into(), &slugs(&["b"]), true).unwrap();
+
+		assert_that(written)
+			.is_equal_to("---\r\ntitle:   Title  \r\nrecommendations: [\"b\"]\r\n# comment\r\n---\r\nContent");
+	}
+
+	#[test]
+	fn recommendations_in_content__not_replaced() {
+		let content = "---\nslug: a\n---\nrecommendations: [x]\n";
+
+		let written = FrontMatterRecommendations::write_slugs(content.into(), &slugs(&["b"]), true).unwrap();
+
+		assert_that(written).is_equal_to("---\nslug: a\nrecommendations: [\"b\"]\n---\nrecommendations: [x]\n");
+	}
+
+	#[test]
+	fn toml_front_matter__key_written_as_toml() {
+		let content = "+++\nslug = \"a\"\nrecommendations = [\"x\"]\n+++\nContent\n";
+
+		let written = FrontMatterRecommendations::write_slugs(content.into(), &slugs(&["b", "c"]), true).unwrap();
+
+		assert_that(written).is_equal_to("+++\nslug = \"a\"\nrecommendations = [\"b\", \"c\"]\n+++\nContent\n");
+	}
+
+	#[test]
+	fn yaml_block_list__all_items_replaced() {
+		let content = "---\nrecommendations:\n  - x\n  - y\nslug: a\n---\nContent\n";
+
+		let written = FrontMatterRecommendations::write_slugs(content.into(), &slugs(&["b"]), true).unwrap();
+
+		assert_that(written).is_equal_to("---\nrecommendations: [\"b\"]\nslug: a\n---\nContent\n");
+	}
+
+	#[test]
+	fn yaml_nested_key__top_level_key_added_and_nested_kept() {
+		let content = "---\nseries:\n  recommendations: [x]\nintro: |\n  recommendations: [y]\nslug: a\n---\n";
+
+		let written = FrontMatterRecommendations::write_slugs(content.into(), &slugs(&["b"]), true).unwrap();
+
+		assert_that(written).is_equal_to(
+			"---\nseries:\n  recommendations: [x]\nintro: |\n  recommendations: [y]\nslug: a\nrecommendations: [\"b\"]\n---\n",
+		);
+	}
+
+	#[test]
+	fn yaml_block_list_with_blank_line__all_items_replaced() {
+		let content = "---\nrecommendations:\n  - x\n\n  - y\n\nslug: a\n---\n";
+
+		let written = FrontMatterRecommendations::write_slugs(content.into(), &slugs(&["b"]), true).unwrap();
+
+		assert_that(written).is_equal_to("---\nrecommendations: [\"b\"]\n\nslug: a\n---\n");
+	}
+
+	#[test]
+	fn slugs_with_special_characters__read_back_as_they_are() {
+		let content = "---\nslug: a\n---\n";
+		let special_slugs = ["b,c", "d: e", "f #g", "h\"i\\"];
+
+		for format in ["yaml", "toml"] {
+			let content = match format {
+				"toml" => content.replace("---", "+++").replace("slug: a", "slug = \"a\""),
+				_ => content.to_string(),
+			};
+			let written =
+				FrontMatterRecommendations::write_slugs(content.into(), &slugs(&special_slugs), true).unwrap();
+
+			let lines = List::of(written.to_string().lines().map(JString::from));
+			let post = PostFactory::read_post(lines).unwrap();
+			let recommendations = post
+				.front_matter()
+				.typed_value_of(FrontMatterRecommendations::KEY())
+				.get()
+				.unwrap()
+				.as_list()
+				.unwrap()
+				.into_iter()
+				.map(|value| value.text())
+				.collect::<Vec<_>>();
+			assert_that(recommendations).contains_exactly(special_slugs);
+		}
+	}
+
+	#[test]
+	fn toml_multi_line_array__all_lines_replaced() {
+		let content = "+++\nrecommendations = [\n  \"x\",\n  \"y\",\n]\nslug = \"a\"\n+++\n";
+
+		let written = FrontMatterRecommendations::write_slugs(content.into(), &slugs(&["b"]), true).unwrap();
+
+		assert_that(written).is_equal_to("+++\nrecommendations = [\"b\"]\nslug = \"a\"\n+++\n");
+	}
+
+	#[test]
+	fn toml_with_table__key_added_before_table() {
+		let content = "+++\nslug = \"a\"\n[params]\nrecommendations = [\"x\"]\n+++\n";
+
+		let written = FrontMatterRecommendations::write_slugs(content.into(), &slugs(&["b"]), true).unwrap();
+
+		assert_that(written)
+			.is_equal_to("+++\nslug = \"a\"\nrecommendations = [\"b\"]\n[params]\nrecommendations = [\"x\"]\n+++\n");
+	}
+
+	#[test]
+	fn without_front_matter__error() {
+		let written = FrontMatterRecommendations::write_slugs("Content\n".into(), &slugs(&["b"]), true);
+
+		assert!(matches!(written, Err(IllegalArgumentException(_))));
+	}
+
+	#[test]
+	fn clear__existing_key_emptied() {
+		let content = "---\nrecommendations:\n  - x\nslug: a\n---\nContent\n";
+
+		let written = FrontMatterRecommendations::clear(content.into()).unwrap();
+
+		assert_that(written).is_equal_to("---\nrecommendations: []\nslug: a\n---\nContent\n");
+	}
+
+	#[test]
+	fn clear__without_key__unchanged() {
+		let content = "---\nslug: a\n---\nContent\n";
+
+		let written = FrontMatterRecommendations::clear(content.into()).unwrap();
+
+		assert_that(written).is_equal_to(content);
+	}
+}
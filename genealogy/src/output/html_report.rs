@@ -0,0 +1,156 @@
+use crate::genealogy::relation::Relation;
+use crate::output::output_utils::OutputUtils;
+use crate::post::Post;
+use crate::recommendation::Recommendation;
+use genealogy_java_apis::exception::Exception;
+use genealogy_java_apis::stream::Stream;
+use genealogy_java_apis::string::JString;
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, HashMap};
+
+/// Renders the whole recommendation graph as one self-contained HTML page for editorial reviews.
+///
+/// The posts are grouped by kind and year, newest first. Every post lists its reading time if it has content,
+/// its tags, its recommendations with the score of their relation and the posts that recommend it.
+/// Posts link to each other via anchors named after their slugs, the styles are inlined, so no server or external assets are needed.
+///
+/// The empty enum has the same effect as a private constructor, preventing instantiation.
+pub enum HtmlReport {}
+
+impl HtmlReport {
+	const STYLE: &'static str = "\
+body { font-family: sans-serif; max-width: 60em; margin: 0 auto; padding: 1em; }
+article { border-top: 1px solid #ccc; padding: 0.5em 0; }
+article:target { background: #ffd; }
+.meta { color: #666; }
+.tag { background: #eee; border-radius: 0.3em; padding: 0 0.3em; margin-right: 0.3em; }
+";
+
+	pub fn to_html(relations: Stream<Relation>, recommendations: Stream<Recommendation>) -> Result<JString, Exception> {
+		let mut posts = BTreeMap::new();
+		let mut scores = HashMap::new();
+		for relation in relations.into_iterator() {
+			let relation = relation?;
+			let (post1, post2) = (relation.post1(), relation.post2());
+			scores.insert((post1.slug(), post2.slug()), relation.score());
+			posts.insert(post1.slug(), post1);
+			posts.insert(post2.slug(), post2);
+		}
+
+		let mut recommended_posts = HashMap::new();
+		let mut backlinks = HashMap::<_, Vec<Post>>::new();
+		for recommendation in recommendations.into_iterator() {
+			let recommendation = recommendation?;
+			let post = recommendation.post();
+			for recommended_post in recommendation.recommended_posts().into_iter() {
+				backlinks.entry(recommended_post.slug()).or_default().push(post.clone());
+			}
+			recommended_posts.insert(post.slug(), recommendation.recommended_posts());
+			posts.insert(post.slug(), post);
+		}
+
+		let mut posts_by_kind = BTreeMap::<_, Vec<Post>>::new();
+		for post in posts.into_values() {
+			posts_by_kind.entry(post.kind().to_string()).or_default().push(post);
+		}
+
+		let mut html = String::new();
+		html += "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n";
+		html += "<meta charset=\"utf-8\">\n<title>Recommendations</title>\n";
+		html += &format!("<style>\n{}</style>\n", Self::STYLE);
+		html += "</head>\n<body>\n<h1>Recommendations</h1>\n";
+		for (kind, mut posts) in posts_by_kind {
+			posts.sort_by_key(|post| (Reverse(post.date()), post.slug()));
+			html += &format!("<section>\n<h2>{}s</h2>\n", OutputUtils::escape(&kind));
+			let mut year = None;
+			for post in posts {
+				let post_year = post.date().format("%Y").to_string();
+				if year.as_ref() != Some(&post_year) {
+					html += &format!("<h3>{}</h3>\n", post_year);
+					year = Some(post_year);
+				}
+
+				let slug = post.slug();
+				html += &format!("<article id=\"{}\">\n", OutputUtils::escape(slug.value().as_ref()));
+				html += &format!("<h4>{}</h4>\n", OutputUtils::escape(post.title().text().as_ref()));
+				let reading_time = post
+					.content()
+					.map(|content| Ok(content.document()?.reading_time_minutes()))?
+					.into_iter()
+					.map(|minutes| format!(" &middot; {} min read", minutes))
+					.collect::<String>();
+				html += &format!(
+					"<p class=\"meta\">{} &middot; {}{}</p>\n",
+					post.date(),
+					OutputUtils::escape(slug.value().as_ref()),
+					reading_time
+				);
+
+				let mut tags = post
+					.tags()
+					.into_iter()
+					.map(|tag| tag.text().to_string())
+					.collect::<Vec<_>>();
+				tags.sort();
+				html += "<p>";
+				for tag in tags {
+					html += &format!("<span class=\"tag\">{}</span>", OutputUtils::escape(&tag));
+				}
+				html += "</p>\n";
+
+				html += "<h5>Recommendations</h5>\n<ol>\n";
+				for recommended_post in recommended_posts.remove(&slug).into_iter().flatten() {
+					let score = scores.get(&(slug.clone(), recommended_post.slug()));
+					let score = score.map_or_else(|| "?".to_string(), i64::to_string);
+					html += &format!("<li>{} (score {})</li>\n", Self::link(&recommended_post), score);
+				}
+				html += "</ol>\n";
+
+				html += "<h5>Recommended by</h5>\n<ul>\n";
+				let mut recommending_posts = backlinks.remove(&slug).unwrap_or_default();
+				recommending_posts.sort_by_key(Post::slug);
+				for recommending_post in recommending_posts {
+					html += &format!("<li>{}</li>\n", Self::link(&recommending_post));
+				}
+				html += "</ul>\n</article>\n";
+			}
+			html += "</section>\n";
+		}
+		html += "</body>\n</html>";
+		Ok(html.into())
+	}
+
+	fn link(post: &Post) -> String {
+		format!(
+			"<a href=\"#{}\">{}</a>",
+			OutputUtils::escape(post.slug().value().as_ref()),
+			OutputUtils::escape(post.title().text().as_ref())
+		)
+	}
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::post::test::PostTestHelper;
+	use genealogy_java_apis::list::List;
+
+	#[test]
+	fn recommendation__listed_with_score_and_as_backlink() {
+		let post_a = PostTestHelper::create_with_slug("a".into()).unwrap();
+		let post_b = PostTestHelper::create_with_slug("b".into()).unwrap();
+		let relations = Stream::of([
+			Relation::new(post_a.clone(), post_b.clone(), 42).unwrap(),
+			Relation::new(post_b.clone(), post_a.clone(), 42).unwrap(),
+		]);
+		let recommendations = Stream::of([Recommendation::new(post_a, List::of([post_b]))]);
+
+		let html = HtmlReport::to_html(relations, recommendations).unwrap().to_string();
+
+		let article_a = &html[html.find("<article id=\"a\">").unwrap()..html.find("<article id=\"b\">").unwrap()];
+		let article_b = &html[html.find("<article id=\"b\">").unwrap()..];
+		assert!(article_a.contains("<li><a href=\"#b\">Title</a> (score 42)</li>"));
+		assert!(article_b.contains("<h5>Recommended by</h5>\n<ul>\n<li><a href=\"#a\">Title</a></li>"));
+	}
+}
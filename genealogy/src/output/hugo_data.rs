@@ -0,0 +1,61 @@
+use crate::recommendation::Recommendation;
+use genealogy_java_apis::exception::Exception;
+use genealogy_java_apis::exception::Exception::RuntimeException;
+use genealogy_java_apis::stream::Stream;
+use genealogy_java_apis::string::JString;
+use std::collections::BTreeMap;
+
+/// Writes [`Recommendation`]s as a Hugo data file, usually `data/recommendations.yaml`,
+/// that maps the slug of every post to the slugs of the recommended posts:
+/// ```yaml
+/// some-slug:
+/// - other-slug
+/// - third-slug
+/// ```
+/// Templates can then look the posts up with `site.Data.recommendations` and `site.GetPage`.
+///
+/// The empty enum has the same effect as a private constructor, preventing instantiation.
+pub enum HugoData {}
+
+impl HugoData {
+	pub fn to_yaml(recommendations: Stream<Recommendation>) -> Result<JString, Exception> {
+		let mut slugs_by_slug = BTreeMap::new();
+		for recommendation in recommendations.into_iterator() {
+			let recommendation = recommendation?;
+			let recommended_slugs = recommendation
+				.recommended_posts()
+				.into_iter()
+				.map(|post| post.slug().value().to_string())
+				.collect::<Vec<_>>();
+			slugs_by_slug.insert(recommendation.post().slug().value().to_string(), recommended_slugs);
+		}
+
+		serde_yaml::to_string(&slugs_by_slug)
+			.map(JString::from)
+			.map_err(|error| RuntimeException("Writing Hugo data failed.".into(), error.into()))
+	}
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::post::test::PostTestHelper;
+	use genealogy_java_apis::list::List;
+	use genealogy_java_apis::test::assert_that;
+
+	#[test]
+	fn recommendations__slugs_keyed_by_slug() {
+		let post_a = PostTestHelper::create_with_slug("a".into()).unwrap();
+		let post_b = PostTestHelper::create_with_slug("b".into()).unwrap();
+		let post_c = PostTestHelper::create_with_slug("c".into()).unwrap();
+		let recommendations = Stream::of([
+			Recommendation::new(post_b.clone(), List::of([post_a.clone()])),
+			Recommendation::new(post_a, List::of([post_c, post_b])),
+		]);
+
+		let yaml = HugoData::to_yaml(recommendations).unwrap();
+
+		assert_that(yaml).is_equal_to("a:\n- c\n- b\nb:\n- a\n");
+	}
+}
@@ -0,0 +1,67 @@
+use crate::recommendation::Recommendation;
+use genealogy_java_apis::exception::Exception;
+use genealogy_java_apis::exception::Exception::RuntimeException;
+use genealogy_java_apis::stream::Stream;
+use genealogy_java_apis::string::JString;
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+/// Writes [`Recommendation`]s as a Jekyll data file, usually `_data/related.yml`,
+/// that maps the slug of every post to the slugs and titles of the recommended posts:
+/// ```yaml
+/// some-slug:
+/// - slug: other-slug
+///   title: Other Title
+/// ```
+/// The titles are included because looking posts up by slug is cumbersome in Liquid templates.
+///
+/// The empty enum has the same effect as a private constructor, preventing instantiation.
+pub enum JekyllData {}
+
+impl JekyllData {
+	pub fn to_yaml(recommendations: Stream<Recommendation>) -> Result<JString, Exception> {
+		let mut related_by_slug = BTreeMap::new();
+		for recommendation in recommendations.into_iterator() {
+			let recommendation = recommendation?;
+			let related = recommendation
+				.recommended_posts()
+				.into_iter()
+				.map(|post| RelatedPost {
+					slug: post.slug().value().to_string(),
+					title: post.title().text().to_string(),
+				})
+				.collect::<Vec<_>>();
+			related_by_slug.insert(recommendation.post().slug().value().to_string(), related);
+		}
+
+		serde_yaml::to_string(&related_by_slug)
+			.map(JString::from)
+			.map_err(|error| RuntimeException("Writing Jekyll data failed.".into(), error.into()))
+	}
+}
+
+#[derive(Serialize)]
+struct RelatedPost {
+	slug: String,
+	title: String,
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::post::test::PostTestHelper;
+	use genealogy_java_apis::list::List;
+	use genealogy_java_apis::test::assert_that;
+
+	#[test]
+	fn recommendations__slugs_and_titles_keyed_by_slug() {
+		let post_a = PostTestHelper::create_with_slug("a".into()).unwrap();
+		let post_b = PostTestHelper::create_with_slug("b".into()).unwrap();
+		let recommendations = Stream::of([Recommendation::new(post_a, List::of([post_b]))]);
+
+		let yaml = JekyllData::to_yaml(recommendations).unwrap();
+
+		assert_that(yaml).is_equal_to("a:\n- slug: b\n  title: Title\n");
+	}
+}
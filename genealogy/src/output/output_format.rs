@@ -0,0 +1,108 @@
+use genealogy_java_apis::exception::Exception;
+use genealogy_java_apis::exception::Exception::IllegalArgumentException;
+use genealogy_java_apis::optional::Optional;
+use genealogy_java_apis::path::Path;
+use genealogy_java_apis::string::JString;
+use genealogy_java_apis::throw;
+use std::fmt::{Display, Formatter};
+
+/// The formats the output can be written in.
+///
+/// `Hugo`, `Jekyll` and `Zola` are data files for static site generators and only exist for recommendations,
+/// `Dot`, `GraphMl` and `Gexf` are graph formats and only exist for relations,
+/// `Csv` and `JsonLines` only exist for the relation matrix.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OutputFormat {
+	Json,
+	Text,
+	Hugo,
+	Jekyll,
+	Zola,
+	Dot,
+	GraphMl,
+	Gexf,
+	Csv,
+	JsonLines,
+}
+
+impl OutputFormat {
+	pub const ALL: [OutputFormat; 10] = [
+		OutputFormat::Json,
+		OutputFormat::Text,
+		OutputFormat::Hugo,
+		OutputFormat::Jekyll,
+		OutputFormat::Zola,
+		OutputFormat::Dot,
+		OutputFormat::GraphMl,
+		OutputFormat::Gexf,
+		OutputFormat::Csv,
+		OutputFormat::JsonLines,
+	];
+
+	/// Parses the name of a format, ignoring case.
+	pub fn from_name(name: JString) -> Result<OutputFormat, Exception> {
+		let name = name.strip().to_lower_case();
+		match Self::ALL.into_iter().find(|format| format.name() == name) {
+			Some(format) => Ok(format),
+			None => throw!(IllegalArgumentException("Unknown output format: " + name)),
+		}
+	}
+
+	pub fn name(&self) -> JString {
+		match self {
+			OutputFormat::Json => "json",
+			OutputFormat::Text => "text",
+			OutputFormat::Hugo => "hugo",
+			OutputFormat::Jekyll => "jekyll",
+			OutputFormat::Zola => "zola",
+			OutputFormat::Dot => "dot",
+			OutputFormat::GraphMl => "graphml",
+			OutputFormat::Gexf => "gexf",
+			OutputFormat::Csv => "csv",
+			OutputFormat::JsonLines => "jsonl",
+		}
+		.into()
+	}
+
+	/// Where the static site generator expects the data file, relative to the root of the site.
+	pub fn data_file(&self) -> Optional<Path> {
+		match self {
+			OutputFormat::Json
+			| OutputFormat::Text
+			| OutputFormat::Dot
+			| OutputFormat::GraphMl
+			| OutputFormat::Gexf
+			| OutputFormat::Csv
+			| OutputFormat::JsonLines => Optional::empty(),
+			OutputFormat::Hugo => Optional::of(Path::of("data/recommendations.yaml")),
+			OutputFormat::Jekyll => Optional::of(Path::of("_data/related.yml")),
+			OutputFormat::Zola => Optional::of(Path::of("data/recommendations.toml")),
+		}
+	}
+}
+
+impl Display for OutputFormat {
+	fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+		write!(formatter, "{}", self.name())
+	}
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod test {
+	use super::*;
+	use genealogy_java_apis::test::assert_that;
+
+	#[test]
+	fn from_name__any_case__parsed() {
+		assert_that(OutputFormat::from_name(" Hugo ".into()).unwrap()).is_equal_to(OutputFormat::Hugo);
+	}
+
+	#[test]
+	fn from_name__unknown__error() {
+		assert!(matches!(
+			OutputFormat::from_name("xml".into()),
+			Err(IllegalArgumentException(_))
+		));
+	}
+}
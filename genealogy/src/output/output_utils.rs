@@ -0,0 +1,49 @@
+use genealogy_java_apis::exception::Exception;
+use genealogy_java_apis::string::JString;
+use serde::Serialize;
+use serde_json::ser::PrettyFormatter;
+use serde_json::Serializer;
+
+/// Helpers shared by the output formats.
+///
+/// The empty enum has the same effect as a private constructor, preventing instantiation.
+pub(crate) enum OutputUtils {}
+
+impl OutputUtils {
+	/// Serializes the document to pretty printed JSON, indented with tabs like the templates of the original Java code.
+	pub(crate) fn to_json(document: &impl Serialize) -> Result<JString, Exception> {
+		let mut json = Vec::new();
+		let mut serializer = Serializer::with_formatter(&mut json, PrettyFormatter::with_indent(b"\t"));
+		document.serialize(&mut serializer).map_err(std::io::Error::from)?;
+		Ok(String::from_utf8_lossy(&json).as_ref().into())
+	}
+
+	/// Escapes the characters that have a special meaning in HTML and XML.
+	pub(crate) fn escape(text: &str) -> String {
+		let mut escaped = String::with_capacity(text.len());
+		for character in text.chars() {
+			match character {
+				'&' => escaped += "&amp;",
+				'<' => escaped += "&lt;",
+				'>' => escaped += "&gt;",
+				'"' => escaped += "&quot;",
+				'\'' => escaped += "&#39;",
+				character => escaped.push(character),
+			}
+		}
+		escaped
+	}
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod test {
+	use super::*;
+	use genealogy_java_apis::test::assert_that;
+
+	#[test]
+	fn escape__html_special_characters() {
+		assert_that(OutputUtils::escape("<a href=\"x\">&'"))
+			.is_equal_to("&lt;a href=&quot;x&quot;&gt;&amp;&#39;".to_string());
+	}
+}
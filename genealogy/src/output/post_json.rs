@@ -0,0 +1,117 @@
+use crate::output::output_utils::OutputUtils;
+use crate::post::metadata::Metadata;
+use crate::post::Post;
+use genealogy_java_apis::exception::Exception;
+use genealogy_java_apis::stream::Stream;
+use genealogy_java_apis::string::JString;
+use serde::Serialize;
+
+/// Serializes [`Post`]s to JSON.
+///
+/// ```json
+/// {
+/// 	"schemaVersion": 1,
+/// 	"posts": [
+/// 		{
+/// 			"slug": "…", "kind": "article", "title": "…", "date": "2020-01-23", "description": "…",
+/// 			"metadata": { "featuredImage": "…", "searchKeywords": "…" },
+/// 			"readingTimeMinutes": 4
+/// 		},
+/// 		{ "slug": "…", "kind": "talk", …, "recording": "…" },
+/// 		{ "slug": "…", "kind": "video", …, "talk": "…" }
+/// 	]
+/// }
+/// ```
+/// `metadata` holds the front matter keys that aren't read into the other fields, see [`Metadata`].
+/// It is left out if there are none.
+/// `readingTimeMinutes` is estimated from the content, see [`Document::reading_time_minutes`](crate::post::content::document::Document::reading_time_minutes),
+/// and left out for posts without content.
+/// `recording` is the slug of the video of a talk's recording and `talk` the slug of the talk a video is
+/// a recording of, see [`Post::linked_post`]. Both are left out if the post isn't linked.
+/// The empty enum has the same effect as a private constructor, preventing instantiation.
+pub enum PostJson {}
+
+impl PostJson {
+	/// Version of the output schema, see [`RecommendationJson::SCHEMA_VERSION`](crate::output::recommendation_json::RecommendationJson::SCHEMA_VERSION).
+	pub const SCHEMA_VERSION: u32 = 1;
+
+	pub fn to_json(posts: Stream<Post>) -> Result<JString, Exception> {
+		let document = PostsDocument {
+			schema_version: Self::SCHEMA_VERSION,
+			posts: posts
+				.map(|post| {
+					Ok(PostWithDetails {
+						reading_time_minutes: post
+							.content()
+							.map(|content| Ok(content.document()?.reading_time_minutes()))?
+							.into_iter()
+							.next(),
+						post: PostEntry::from(&post),
+					})
+				})
+				.into_iterator()
+				.collect::<Result<_, _>>()?,
+		};
+		OutputUtils::to_json(&document)
+	}
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PostsDocument {
+	schema_version: u32,
+	posts: Vec<PostWithDetails>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PostWithDetails {
+	#[serde(flatten)]
+	post: PostEntry,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	reading_time_minutes: Option<usize>,
+}
+
+/// A post as it appears in all JSON outputs.
+#[derive(Serialize)]
+pub(crate) struct PostEntry {
+	slug: String,
+	kind: String,
+	title: String,
+	date: String,
+	description: String,
+	#[serde(skip_serializing_if = "Metadata::is_empty")]
+	metadata: Metadata,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	recording: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	talk: Option<String>,
+}
+
+impl From<&Post> for PostEntry {
+	fn from(post: &Post) -> Self {
+		Self {
+			slug: post.slug().value().to_string(),
+			kind: post.kind().to_string(),
+			title: post.title().text().to_string(),
+			date: post.date().to_string(),
+			description: post.description().text().to_string(),
+			metadata: post.metadata(),
+			recording: Self::linked_slug(post, "talk"),
+			talk: Self::linked_slug(post, "video"),
+		}
+	}
+}
+
+impl PostEntry {
+	/// The slug of the linked post if `post` is of the given kind.
+	fn linked_slug(post: &Post, kind: &str) -> Option<String> {
+		if post.kind() != kind {
+			return None;
+		}
+		post.linked_post()
+			.into_iter()
+			.next()
+			.map(|linked_post| linked_post.slug().value().to_string())
+	}
+}
@@ -0,0 +1,174 @@
+use crate::output::output_utils::OutputUtils;
+use crate::output::post_json::PostEntry;
+use crate::recommendation::Recommendation;
+use genealogy_java_apis::exception::Exception;
+use genealogy_java_apis::stream::Stream;
+use genealogy_java_apis::string::JString;
+use serde::Serialize;
+
+/// Serializes [`Recommendation`]s to JSON.
+///
+/// Replaces the string templates of the original `Main.recommendationsToJson`, which neither escaped
+/// the titles nor contained anything but the titles.
+///
+/// The output follows a versioned schema, see [`RecommendationJson::SCHEMA_VERSION`]:
+/// ```json
+/// {
+/// 	"schemaVersion": 1,
+/// 	"recommendations": [
+/// 		{
+/// 			"post": { "slug": "…", "kind": "article", "title": "…", "date": "2020-01-23", "description": "…" },
+/// 			"recommendedPosts": [
+/// 				{ "rank": 1, "slug": "…", "kind": "video", "title": "…", "date": "…", "description": "…" }
+/// 			]
+/// 		}
+/// 	]
+/// }
+/// ```
+/// The posts have the `metadata` and the `recording` or `talk` links of [`PostJson`](crate::output::post_json::PostJson) as well.
+///
+/// The empty enum has the same effect as a private constructor, preventing instantiation.
+pub enum RecommendationJson {}
+
+impl RecommendationJson {
+	/// Version of the output schema. Must be incremented on every change that isn't backwards compatible,
+	/// so that consumers of the output can rely on the format.
+	pub const SCHEMA_VERSION: u32 = 1;
+
+	pub fn to_json(recommendations: Stream<Recommendation>) -> Result<JString, Exception> {
+		let document = RecommendationsDocument {
+			schema_version: Self::SCHEMA_VERSION,
+			recommendations: recommendations
+				.map(|recommendation| Ok(RecommendationEntry::from(&recommendation)))
+				.into_iterator()
+				.collect::<Result<_, _>>()?,
+		};
+		OutputUtils::to_json(&document)
+	}
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct RecommendationsDocument {
+	schema_version: u32,
+	recommendations: Vec<RecommendationEntry>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct RecommendationEntry {
+	post: PostEntry,
+	recommended_posts: Vec<RecommendedPostEntry>,
+}
+
+impl From<&Recommendation> for RecommendationEntry {
+	fn from(recommendation: &Recommendation) -> Self {
+		Self {
+			post: PostEntry::from(&recommendation.post()),
+			recommended_posts: recommendation
+				.recommended_posts()
+				.into_iter()
+				.enumerate()
+				.map(|(index, post)| RecommendedPostEntry {
+					rank: index + 1,
+					post: PostEntry::from(&post),
+				})
+				.collect(),
+		}
+	}
+}
+
+#[derive(Serialize)]
+struct RecommendedPostEntry {
+	rank: usize,
+	#[serde(flatten)]
+	post: PostEntry,
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::post::article::Article;
+	use crate::post::corpus::Corpus;
+	use crate::post::description::Description;
+	use crate::post::metadata::Metadata;
+	use crate::post::post_type::PostType;
+	use crate::post::slug::Slug;
+	use crate::post::tag::Tag;
+	use crate::post::test::PostTestHelper;
+	use crate::post::title::Title;
+	use crate::post::Post;
+	use genealogy_java_apis::list::List;
+	use genealogy_java_apis::optional::Optional;
+	use genealogy_java_apis::path::Path;
+	use genealogy_java_apis::test::assert_that;
+	use genealogy_java_apis::time::{LocalDate, LocalDateExtension};
+	use serde_json::{json, Value};
+
+	#[test]
+	fn recommendations__serialized_with_schema_version_and_ranks() {
+		let post_a = PostTestHelper::create_with_slug("a".into()).unwrap();
+		let post_b = PostTestHelper::create_with_slug("b".into()).unwrap();
+		let post_c = PostTestHelper::create_with_slug("c".into()).unwrap();
+		let recommendation = Recommendation::new(post_a, List::of([post_c, post_b]));
+
+		let json = RecommendationJson::to_json(Stream::of([recommendation])).unwrap();
+
+		let value: Value = serde_json::from_str(json.as_ref()).unwrap();
+		assert_that(value["schemaVersion"].clone()).is_equal_to(json!(RecommendationJson::SCHEMA_VERSION));
+		let entry = &value["recommendations"][0];
+		assert_that(entry["post"]["slug"].clone()).is_equal_to(json!("a"));
+		assert_that(entry["post"]["kind"].clone()).is_equal_to(json!("article"));
+		assert_that(entry["post"]["description"].clone()).is_equal_to(json!("description"));
+		assert_that(entry["recommendedPosts"][0]["rank"].clone()).is_equal_to(json!(1));
+		assert_that(entry["recommendedPosts"][0]["slug"].clone()).is_equal_to(json!("c"));
+		assert_that(entry["recommendedPosts"][1]["rank"].clone()).is_equal_to(json!(2));
+		assert_that(entry["recommendedPosts"][1]["slug"].clone()).is_equal_to(json!("b"));
+	}
+
+	#[test]
+	fn title_with_quotation_marks_and_backslashes__escaped() {
+		let title = r#"Impulse: \"Workflows\" of "Refactoring" at C:\"#;
+		let post: Post = Article::new(
+			Title::new(title.into()).unwrap(),
+			Tag::from("[Tag]".into()).unwrap(),
+			LocalDate::of(2020, 1, 23),
+			Description::new("description".into()).unwrap(),
+			Slug::new("slug".into()).unwrap(),
+			Optional::empty(),
+			(|| Stream::of(["".into()])).into(),
+			Metadata::empty(),
+		)
+		.into();
+		let recommendation = Recommendation::new(post.clone(), List::of([post]));
+
+		let json = RecommendationJson::to_json(Stream::of([recommendation])).unwrap();
+
+		let value: Value = serde_json::from_str(json.as_ref()).unwrap();
+		assert_that(value["recommendations"][0]["post"]["title"].clone()).is_equal_to(json!(title));
+		assert_that(value["recommendations"][0]["post"]["date"].clone()).is_equal_to(json!("2020-01-23"));
+	}
+
+	#[test]
+	fn linked_talk_and_video__links_serialized() {
+		let posts = [(PostType::Talk, "talk"), (PostType::Video, "video")].map(|(post_type, slug)| {
+			(
+				Path::of(slug),
+				PostTestHelper::create_of_type(post_type, slug, "video").unwrap(),
+			)
+		});
+		let (posts, _) = Corpus::link_talks_and_videos(posts.into());
+		let talk = posts[0].1.clone();
+		let video = posts[1].1.clone();
+		let recommendation = Recommendation::new(talk, List::of([video]));
+
+		let json = RecommendationJson::to_json(Stream::of([recommendation])).unwrap();
+
+		let value: Value = serde_json::from_str(json.as_ref()).unwrap();
+		let entry = &value["recommendations"][0];
+		assert_that(entry["post"]["recording"].clone()).is_equal_to(json!("video"));
+		assert_that(entry["post"].get("talk").cloned()).is_equal_to(None);
+		assert_that(entry["recommendedPosts"][0]["talk"].clone()).is_equal_to(json!("talk"));
+	}
+}
@@ -0,0 +1,53 @@
+use crate::post::Post;
+use crate::recommendation::Recommendation;
+use genealogy_java_apis::collector::Collectors;
+use genealogy_java_apis::exception::Exception;
+use genealogy_java_apis::stream::Stream;
+use genealogy_java_apis::string::JString;
+
+/// Formats [`Recommendation`]s as human readable text, one block per post:
+/// ```text
+/// Some Title [article, some-slug]
+/// 	1. Other Title [video, other-slug]
+/// ```
+/// The empty enum has the same effect as a private constructor, preventing instantiation.
+pub enum RecommendationText {}
+
+impl RecommendationText {
+	pub fn to_text(recommendations: Stream<Recommendation>) -> Result<JString, Exception> {
+		recommendations
+			.map(|recommendation| {
+				let mut text = Self::describe(&recommendation.post()).to_string();
+				for (index, post) in recommendation.recommended_posts().into_iter().enumerate() {
+					text += &format!("\n\t{}. {}", index + 1, Self::describe(&post));
+				}
+				Ok(JString::from(text))
+			})
+			.collect(Collectors::joining("\n"))
+	}
+
+	pub(crate) fn describe(post: &Post) -> JString {
+		format!("{} [{}, {}]", post.title().text(), post.kind(), post.slug().value()).into()
+	}
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::post::test::PostTestHelper;
+	use genealogy_java_apis::list::List;
+	use genealogy_java_apis::test::assert_that;
+
+	#[test]
+	fn recommendation__post_followed_by_ranked_recommendations() {
+		let post_a = PostTestHelper::create_with_slug("a".into()).unwrap();
+		let post_b = PostTestHelper::create_with_slug("b".into()).unwrap();
+		let post_c = PostTestHelper::create_with_slug("c".into()).unwrap();
+		let recommendation = Recommendation::new(post_a, List::of([post_c, post_b]));
+
+		let text = RecommendationText::to_text(Stream::of([recommendation])).unwrap();
+
+		assert_that(text).is_equal_to("Title [article, a]\n\t1. Title [article, c]\n\t2. Title [article, b]");
+	}
+}
@@ -0,0 +1,70 @@
+use crate::output::relation_graph::RelationGraph;
+use crate::post::Post;
+use genealogy_java_apis::string::JString;
+
+/// Writes a [`RelationGraph`] in the DOT language of Graphviz:
+/// ```dot
+/// digraph relations {
+/// 	"some-slug" [label="Some Title", title="Some Title", type="article", date="2020-01-23", tags="java, rust"];
+/// 	"some-slug" -> "other-slug" [weight=42, label="42"];
+/// }
+/// ```
+/// The empty enum has the same effect as a private constructor, preventing instantiation.
+pub enum RelationDot {}
+
+impl RelationDot {
+	pub fn to_dot(graph: &RelationGraph) -> JString {
+		let mut dot = String::from("digraph relations {\n");
+		for post in graph.nodes().into_iter() {
+			let title = Self::quote(post.title().text().as_ref());
+			dot += &format!(
+				"\t{} [label={}, title={}, type={}, date={}, tags={}];\n",
+				Self::quote_slug(&post),
+				title,
+				title,
+				Self::quote(post.kind().as_ref()),
+				Self::quote(&post.date().to_string()),
+				Self::quote(&RelationGraph::tags_of(&post))
+			);
+		}
+		for relation in graph.edges().into_iter() {
+			dot += &format!(
+				"\t{} -> {} [weight={}, label=\"{}\"];\n",
+				Self::quote_slug(&relation.post1()),
+				Self::quote_slug(&relation.post2()),
+				relation.score(),
+				relation.score()
+			);
+		}
+		dot += "}";
+		dot.into()
+	}
+
+	fn quote_slug(post: &Post) -> String {
+		Self::quote(post.slug().value().as_ref())
+	}
+
+	fn quote(text: &str) -> String {
+		format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\""))
+	}
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::output::relation_graph::test::create_graph;
+	use genealogy_java_apis::optional::Optional;
+
+	#[test]
+	fn graph__nodes_with_attributes_and_weighted_edges() {
+		let graph = create_graph(Optional::empty(), Optional::of(1));
+
+		let dot = RelationDot::to_dot(&graph).to_string();
+
+		assert!(dot.starts_with("digraph relations {\n"));
+		assert!(dot.contains("\t\"a\" [label=\"Title\", title=\"Title\", type=\"article\", date=\""));
+		assert!(dot.contains("\t\"a\" -> \"c\" [weight=60, label=\"60\"];\n"));
+		assert!(!dot.contains("\"a\" -> \"b\""));
+	}
+}
@@ -0,0 +1,83 @@
+use crate::output::output_utils::OutputUtils;
+use crate::output::relation_graph::RelationGraph;
+use genealogy_java_apis::string::JString;
+
+/// Writes a [`RelationGraph`] as [GEXF 1.3](https://gexf.net/), the native format of Gephi.
+/// Nodes are identified by their slug, labeled with their title and carry type, date and tags as attributes,
+/// edges carry their score as weight.
+///
+/// The empty enum has the same effect as a private constructor, preventing instantiation.
+pub enum RelationGexf {}
+
+impl RelationGexf {
+	pub fn to_gexf(graph: &RelationGraph) -> JString {
+		let mut xml = String::new();
+		xml += "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n";
+		xml += "<gexf xmlns=\"http://gexf.net/1.3\" version=\"1.3\">\n";
+		xml += "\t<graph defaultedgetype=\"directed\">\n";
+		xml += "\t\t<attributes class=\"node\">\n";
+		for attribute in ["type", "date", "tags"] {
+			xml += &format!(
+				"\t\t\t<attribute id=\"{}\" title=\"{}\" type=\"string\"/>\n",
+				attribute, attribute
+			);
+		}
+		xml += "\t\t</attributes>\n";
+
+		xml += "\t\t<nodes>\n";
+		for post in graph.nodes().into_iter() {
+			xml += &format!(
+				"\t\t\t<node id=\"{}\" label=\"{}\">\n",
+				OutputUtils::escape(post.slug().value().as_ref()),
+				OutputUtils::escape(post.title().text().as_ref())
+			);
+			xml += "\t\t\t\t<attvalues>\n";
+			let attributes = [
+				("type", post.kind().to_string()),
+				("date", post.date().to_string()),
+				("tags", RelationGraph::tags_of(&post)),
+			];
+			for (attribute, value) in attributes {
+				xml += &format!(
+					"\t\t\t\t\t<attvalue for=\"{}\" value=\"{}\"/>\n",
+					attribute,
+					OutputUtils::escape(&value)
+				);
+			}
+			xml += "\t\t\t\t</attvalues>\n\t\t\t</node>\n";
+		}
+		xml += "\t\t</nodes>\n";
+
+		xml += "\t\t<edges>\n";
+		for (index, relation) in graph.edges().into_iter().enumerate() {
+			xml += &format!(
+				"\t\t\t<edge id=\"{}\" source=\"{}\" target=\"{}\" weight=\"{}\"/>\n",
+				index,
+				OutputUtils::escape(relation.post1().slug().value().as_ref()),
+				OutputUtils::escape(relation.post2().slug().value().as_ref()),
+				relation.score()
+			);
+		}
+		xml += "\t\t</edges>\n\t</graph>\n</gexf>";
+		xml.into()
+	}
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::output::relation_graph::test::create_graph;
+	use genealogy_java_apis::optional::Optional;
+
+	#[test]
+	fn graph__nodes_with_attributes_and_weighted_edges() {
+		let graph = create_graph(Optional::empty(), Optional::empty());
+
+		let xml = RelationGexf::to_gexf(&graph).to_string();
+
+		assert!(xml.contains("\t\t\t<node id=\"a\" label=\"Title\">\n\t\t\t\t<attvalues>\n"));
+		assert!(xml.contains("\t\t\t\t\t<attvalue for=\"type\" value=\"article\"/>\n"));
+		assert!(xml.contains("\t\t\t<edge id=\"0\" source=\"a\" target=\"c\" weight=\"60\"/>\n"));
+	}
+}
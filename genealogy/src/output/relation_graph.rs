@@ -0,0 +1,139 @@
+use crate::genealogy::relation::Relation;
+use crate::post::Post;
+use genealogy_java_apis::exception::Exception;
+use genealogy_java_apis::list::List;
+use genealogy_java_apis::optional::Optional;
+use genealogy_java_apis::stream::Stream;
+use std::cmp::Reverse;
+use std::collections::BTreeMap;
+
+/// The relations between posts as a directed graph, the input of the graph exporters
+/// [`RelationDot`](crate::output::relation_dot::RelationDot),
+/// [`RelationGraphMl`](crate::output::relation_graph_ml::RelationGraphMl) and
+/// [`RelationGexf`](crate::output::relation_gexf::RelationGexf).
+///
+/// Every post is a node, even if all of its relations were filtered out. Nodes are ordered by slug,
+/// edges by the slug of their source and then by descending score.
+pub struct RelationGraph {
+	nodes: List<Post>,
+	edges: List<Relation>,
+}
+
+impl RelationGraph {
+	/// Keeps only the relations with at least `min_score` and, of those, the `top_k` with the highest score
+	/// per post they start from.
+	pub fn new(
+		relations: Stream<Relation>,
+		min_score: Optional<i64>,
+		top_k: Optional<usize>,
+	) -> Result<RelationGraph, Exception> {
+		let mut nodes = BTreeMap::new();
+		let mut edges_by_source = BTreeMap::<_, Vec<Relation>>::new();
+		for relation in relations.into_iterator() {
+			let relation = relation?;
+			nodes.insert(relation.post1().slug(), relation.post1());
+			nodes.insert(relation.post2().slug(), relation.post2());
+			if min_score
+				.clone()
+				.into_iter()
+				.all(|min_score| relation.score() >= min_score)
+			{
+				edges_by_source
+					.entry(relation.post1().slug())
+					.or_default()
+					.push(relation);
+			}
+		}
+
+		let mut edges = Vec::new();
+		for (_, mut relations) in edges_by_source {
+			relations.sort_by_key(|relation| (Reverse(relation.score()), relation.post2().slug()));
+			if let Some(top_k) = top_k.clone().into_iter().next() {
+				relations.truncate(top_k);
+			}
+			edges.extend(relations);
+		}
+
+		Ok(RelationGraph {
+			nodes: List::of(nodes.into_values()),
+			edges: List::of(edges),
+		})
+	}
+
+	pub fn nodes(&self) -> List<Post> {
+		self.nodes.clone()
+	}
+
+	pub fn edges(&self) -> List<Relation> {
+		self.edges.clone()
+	}
+
+	/// The tags of a post as one sorted, comma separated attribute value.
+	pub(crate) fn tags_of(post: &Post) -> String {
+		let mut tags = post
+			.tags()
+			.into_iter()
+			.map(|tag| tag.text().to_string())
+			.collect::<Vec<_>>();
+		tags.sort();
+		tags.join(", ")
+	}
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+pub(crate) mod test {
+	use super::*;
+	use crate::post::test::PostTestHelper;
+	use genealogy_java_apis::test::assert_that;
+
+	/// Posts `a`, `b` and `c` with relations `a -> b: 10`, `a -> c: 60` and `b -> a: 30`.
+	pub(crate) fn create_graph(min_score: Optional<i64>, top_k: Optional<usize>) -> RelationGraph {
+		let post_a = PostTestHelper::create_with_slug("a".into()).unwrap();
+		let post_b = PostTestHelper::create_with_slug("b".into()).unwrap();
+		let post_c = PostTestHelper::create_with_slug("c".into()).unwrap();
+		let relations = Stream::of([
+			Relation::new(post_a.clone(), post_b.clone(), 10).unwrap(),
+			Relation::new(post_a.clone(), post_c, 60).unwrap(),
+			Relation::new(post_b, post_a, 30).unwrap(),
+		]);
+		RelationGraph::new(relations, min_score, top_k).unwrap()
+	}
+
+	fn edges_of(graph: &RelationGraph) -> Vec<String> {
+		graph
+			.edges()
+			.into_iter()
+			.map(|relation| {
+				format!(
+					"{}->{}",
+					relation.post1().slug().value(),
+					relation.post2().slug().value()
+				)
+			})
+			.collect()
+	}
+
+	#[test]
+	fn no_filters__all_edges_ordered_by_source_and_score() {
+		let graph = create_graph(Optional::empty(), Optional::empty());
+
+		assert_that(graph.nodes().length()).is_equal_to(3);
+		assert_that(edges_of(&graph)).is_equal_to(vec!["a->c".to_string(), "a->b".into(), "b->a".into()]);
+	}
+
+	#[test]
+	fn min_score__edges_below_removed_but_nodes_kept() {
+		let graph = create_graph(Optional::of(30), Optional::empty());
+
+		assert_that(graph.nodes().length()).is_equal_to(3);
+		assert_that(edges_of(&graph)).is_equal_to(vec!["a->c".to_string(), "b->a".into()]);
+	}
+
+	#[test]
+	fn top_k__only_best_edges_per_node_kept() {
+		let graph = create_graph(Optional::empty(), Optional::of(1));
+
+		assert_that(edges_of(&graph)).is_equal_to(vec!["a->c".to_string(), "b->a".into()]);
+	}
+}
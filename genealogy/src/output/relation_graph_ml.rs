@@ -0,0 +1,73 @@
+use crate::output::output_utils::OutputUtils;
+use crate::output::relation_graph::RelationGraph;
+use genealogy_java_apis::string::JString;
+
+/// Writes a [`RelationGraph`] as [GraphML](http://graphml.graphdrawing.org/), which e.g. Gephi and yEd can read.
+/// Nodes are identified by their slug and carry title, type, date and tags as data, edges carry their score as weight.
+///
+/// The empty enum has the same effect as a private constructor, preventing instantiation.
+pub enum RelationGraphMl {}
+
+impl RelationGraphMl {
+	pub fn to_graph_ml(graph: &RelationGraph) -> JString {
+		let mut xml = String::new();
+		xml += "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n";
+		xml += "<graphml xmlns=\"http://graphml.graphdrawing.org/xmlns\">\n";
+		for key in ["title", "type", "date", "tags"] {
+			xml += &format!(
+				"\t<key id=\"{}\" for=\"node\" attr.name=\"{}\" attr.type=\"string\"/>\n",
+				key, key
+			);
+		}
+		xml += "\t<key id=\"weight\" for=\"edge\" attr.name=\"weight\" attr.type=\"long\"/>\n";
+		xml += "\t<graph id=\"relations\" edgedefault=\"directed\">\n";
+		for post in graph.nodes().into_iter() {
+			xml += &format!(
+				"\t\t<node id=\"{}\">\n",
+				OutputUtils::escape(post.slug().value().as_ref())
+			);
+			let data = [
+				("title", post.title().text().to_string()),
+				("type", post.kind().to_string()),
+				("date", post.date().to_string()),
+				("tags", RelationGraph::tags_of(&post)),
+			];
+			for (key, value) in data {
+				xml += &format!("\t\t\t<data key=\"{}\">{}</data>\n", key, OutputUtils::escape(&value));
+			}
+			xml += "\t\t</node>\n";
+		}
+		for relation in graph.edges().into_iter() {
+			xml += &format!(
+				"\t\t<edge source=\"{}\" target=\"{}\">\n",
+				OutputUtils::escape(relation.post1().slug().value().as_ref()),
+				OutputUtils::escape(relation.post2().slug().value().as_ref())
+			);
+			xml += &format!("\t\t\t<data key=\"weight\">{}</data>\n", relation.score());
+			xml += "\t\t</edge>\n";
+		}
+		xml += "\t</graph>\n</graphml>";
+		xml.into()
+	}
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::output::relation_graph::test::create_graph;
+	use genealogy_java_apis::optional::Optional;
+
+	#[test]
+	fn graph__nodes_with_data_and_weighted_edges() {
+		let graph = create_graph(Optional::of(30), Optional::empty());
+
+		let xml = RelationGraphMl::to_graph_ml(&graph).to_string();
+
+		assert!(xml.contains(
+			"\t\t<node id=\"b\">\n\t\t\t<data key=\"title\">Title</data>\n\t\t\t<data key=\"type\">article</data>\n"
+		));
+		assert!(xml.contains("\t\t<edge source=\"a\" target=\"c\">\n\t\t\t<data key=\"weight\">60</data>\n"));
+		assert!(!xml.contains("target=\"b\""));
+	}
+}
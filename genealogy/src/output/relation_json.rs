@@ -0,0 +1,84 @@
+use crate::genealogy::relation::Relation;
+use crate::output::output_utils::OutputUtils;
+use genealogy_java_apis::exception::Exception;
+use genealogy_java_apis::stream::Stream;
+use genealogy_java_apis::string::JString;
+use serde::Serialize;
+
+/// Serializes [`Relation`]s to JSON.
+///
+/// ```json
+/// {
+/// 	"schemaVersion": 1,
+/// 	"relations": [
+/// 		{ "post1": "some-slug", "post2": "other-slug", "score": 42 }
+/// 	]
+/// }
+/// ```
+/// The empty enum has the same effect as a private constructor, preventing instantiation.
+pub enum RelationJson {}
+
+impl RelationJson {
+	/// Version of the output schema, see [`RecommendationJson::SCHEMA_VERSION`](crate::output::recommendation_json::RecommendationJson::SCHEMA_VERSION).
+	pub const SCHEMA_VERSION: u32 = 1;
+
+	pub fn to_json(relations: Stream<Relation>) -> Result<JString, Exception> {
+		let document = RelationsDocument {
+			schema_version: Self::SCHEMA_VERSION,
+			relations: relations
+				.map(|relation| Ok(RelationEntry::from(&relation)))
+				.into_iterator()
+				.collect::<Result<_, _>>()?,
+		};
+		OutputUtils::to_json(&document)
+	}
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct RelationsDocument {
+	schema_version: u32,
+	relations: Vec<RelationEntry>,
+}
+
+#[derive(Serialize)]
+struct RelationEntry {
+	post1: String,
+	post2: String,
+	score: i64,
+}
+
+impl From<&Relation> for RelationEntry {
+	fn from(relation: &Relation) -> Self {
+		Self {
+			post1: relation.post1().slug().value().to_string(),
+			post2: relation.post2().slug().value().to_string(),
+			score: relation.score(),
+		}
+	}
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::genealogy::relation_test_helper::RelationTestHelper;
+	use crate::post::test::PostTestHelper;
+	use genealogy_java_apis::test::assert_that;
+	use serde_json::{json, Value};
+
+	#[test]
+	fn relations__serialized_as_slugs_with_score() {
+		let post_a = PostTestHelper::create_with_slug("a".into()).unwrap();
+		let post_b = PostTestHelper::create_with_slug("b".into()).unwrap();
+		let relation = RelationTestHelper::create(post_a, post_b, 60).unwrap();
+
+		let json = RelationJson::to_json(Stream::of([relation])).unwrap();
+
+		let value: Value = serde_json::from_str(json.as_ref()).unwrap();
+		assert_that(value).is_equal_to(json!({
+			"schemaVersion": RelationJson::SCHEMA_VERSION,
+			"relations": [{ "post1": "a", "post2": "b", "score": 60 }],
+		}));
+	}
+}
@@ -0,0 +1,160 @@
+use crate::genealogy::relation_scores::RelationScores;
+use genealogy_java_apis::exception::Exception;
+use genealogy_java_apis::exception::Exception::RuntimeException;
+use genealogy_java_apis::stream::Stream;
+use serde_json::{Map, Value};
+use std::io::Write;
+
+/// Writes the scores of every ordered pair of posts, one row per pair, for analysis in e.g. pandas.
+/// Every row has the slugs of both posts, one column per relation type with the raw score of its genealogist
+/// and the aggregated score of the relation:
+/// ```csv
+/// post1,post2,tag,link,score
+/// some-slug,other-slug,40,60,50
+/// ```
+/// As JSON Lines, every row is one object with the same keys.
+///
+/// The relation types are taken from the first row. Rows are written as soon as they are inferred,
+/// so the whole matrix never has to be held in memory.
+///
+/// The empty enum has the same effect as a private constructor, preventing instantiation.
+pub enum RelationMatrix {}
+
+impl RelationMatrix {
+	pub fn write_csv(relation_scores: Stream<RelationScores>, writer: impl Write) -> Result<(), Exception> {
+		let mut writer = csv::Writer::from_writer(writer);
+		let mut rows = relation_scores.into_iterator().peekable();
+		let relation_types = match rows.peek() {
+			Some(Ok(first)) => Self::relation_types_of(first),
+			_ => Vec::new(),
+		};
+
+		let mut header = vec!["post1".to_string(), "post2".to_string()];
+		header.extend(relation_types.iter().cloned());
+		header.push("score".to_string());
+		writer.write_record(&header).map_err(Self::writing_failed)?;
+
+		for row in rows {
+			let row = row?;
+			let relation = row.relation();
+			let mut record = vec![
+				relation.post1().slug().value().to_string(),
+				relation.post2().slug().value().to_string(),
+			];
+			record.extend(
+				Self::scores_of(&row, &relation_types)
+					.into_iter()
+					.map(|score| score.map(|score| score.to_string()).unwrap_or_default()),
+			);
+			record.push(relation.score().to_string());
+			writer.write_record(&record).map_err(Self::writing_failed)?;
+		}
+		writer.flush()?;
+		Ok(())
+	}
+
+	pub fn write_json_lines(relation_scores: Stream<RelationScores>, mut writer: impl Write) -> Result<(), Exception> {
+		let mut rows = relation_scores.into_iterator().peekable();
+		let relation_types = match rows.peek() {
+			Some(Ok(first)) => Self::relation_types_of(first),
+			_ => Vec::new(),
+		};
+
+		for row in rows {
+			let row = row?;
+			let relation = row.relation();
+			let mut object = Map::new();
+			object.insert("post1".into(), relation.post1().slug().value().to_string().into());
+			object.insert("post2".into(), relation.post2().slug().value().to_string().into());
+			for (relation_type, score) in relation_types.iter().zip(Self::scores_of(&row, &relation_types)) {
+				object.insert(relation_type.clone(), score.into());
+			}
+			object.insert("score".into(), relation.score().into());
+			serde_json::to_writer(&mut writer, &Value::Object(object)).map_err(std::io::Error::from)?;
+			writer.write_all(b"\n")?;
+		}
+		writer.flush()?;
+		Ok(())
+	}
+
+	fn relation_types_of(row: &RelationScores) -> Vec<String> {
+		row.typed_relations()
+			.into_iter()
+			.map(|typed_relation| typed_relation.r#type().value().to_string())
+			.collect()
+	}
+
+	/// The raw score for every relation type, `None` if no genealogist of that type inferred one.
+	fn scores_of(row: &RelationScores, relation_types: &[String]) -> Vec<Option<i64>> {
+		let typed_relations = row.typed_relations();
+		relation_types
+			.iter()
+			.map(|relation_type| {
+				typed_relations
+					.clone()
+					.into_iter()
+					.find(|typed_relation| typed_relation.r#type().value().to_string() == *relation_type)
+					.map(|typed_relation| typed_relation.score())
+			})
+			.collect()
+	}
+
+	fn writing_failed(error: csv::Error) -> Exception {
+		RuntimeException("Writing the relation matrix failed.".into(), error.into())
+	}
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::genealogist::relation_type::RelationType;
+	use crate::genealogist::typed_relation::TypedRelation;
+	use crate::genealogy::relation::Relation;
+	use crate::post::test::PostTestHelper;
+	use genealogy_java_apis::list::List;
+	use genealogy_java_apis::test::assert_that;
+
+	fn relation_scores() -> Stream<RelationScores> {
+		let post_a = PostTestHelper::create_with_slug("a".into()).unwrap();
+		let post_b = PostTestHelper::create_with_slug("b".into()).unwrap();
+		let tag = RelationType::new("tag".into()).unwrap();
+		let link = RelationType::new("link".into()).unwrap();
+		Stream::of([
+			RelationScores::new(
+				Relation::new(post_a.clone(), post_b.clone(), 50).unwrap(),
+				List::of([
+					TypedRelation::new(post_a.clone(), post_b.clone(), tag.clone(), 40).unwrap(),
+					TypedRelation::new(post_a.clone(), post_b.clone(), link.clone(), 60).unwrap(),
+				]),
+			),
+			RelationScores::new(
+				Relation::new(post_b.clone(), post_a.clone(), 20).unwrap(),
+				List::of([TypedRelation::new(post_b, post_a, tag, 20).unwrap()]),
+			),
+		])
+	}
+
+	#[test]
+	fn csv__one_column_per_relation_type() {
+		let mut csv = Vec::new();
+
+		RelationMatrix::write_csv(relation_scores(), &mut csv).unwrap();
+
+		assert_that(String::from_utf8(csv).unwrap())
+			.is_equal_to("post1,post2,tag,link,score\na,b,40,60,50\nb,a,20,,20\n".to_string());
+	}
+
+	#[test]
+	fn json_lines__one_object_per_row() {
+		let mut json_lines = Vec::new();
+
+		RelationMatrix::write_json_lines(relation_scores(), &mut json_lines).unwrap();
+
+		assert_that(String::from_utf8(json_lines).unwrap()).is_equal_to(
+			"{\"link\":60,\"post1\":\"a\",\"post2\":\"b\",\"score\":50,\"tag\":40}\n\
+			 {\"link\":null,\"post1\":\"b\",\"post2\":\"a\",\"score\":20,\"tag\":20}\n"
+				.to_string(),
+		);
+	}
+}
@@ -0,0 +1,28 @@
+use crate::genealogy::relation::Relation;
+use genealogy_java_apis::collector::Collectors;
+use genealogy_java_apis::exception::Exception;
+use genealogy_java_apis::stream::Stream;
+use genealogy_java_apis::string::JString;
+
+/// Formats [`Relation`]s as human readable text, one line per relation:
+/// ```text
+/// some-slug -> other-slug: 42
+/// ```
+/// The empty enum has the same effect as a private constructor, preventing instantiation.
+pub enum RelationText {}
+
+impl RelationText {
+	pub fn to_text(relations: Stream<Relation>) -> Result<JString, Exception> {
+		relations
+			.map(|relation| {
+				Ok(format!(
+					"{} -> {}: {}",
+					relation.post1().slug().value(),
+					relation.post2().slug().value(),
+					relation.score()
+				)
+				.into())
+			})
+			.collect(Collectors::joining("\n"))
+	}
+}
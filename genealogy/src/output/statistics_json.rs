@@ -0,0 +1,103 @@
+use crate::output::output_utils::OutputUtils;
+use crate::post::Post;
+use crate::statistics::score_distribution::ScoreDistribution;
+use crate::statistics::Statistics;
+use genealogy_java_apis::exception::Exception;
+use genealogy_java_apis::string::JString;
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+/// Serializes [`Statistics`] to JSON.
+///
+/// ```json
+/// {
+/// 	"schemaVersion": 1,
+/// 	"postsPerKindAndYear": { "article": { "2020": 12 } },
+/// 	"tagFrequencies": [ { "tag": "java", "posts": 42 } ],
+/// 	"postsWithoutRepository": [ "some-slug" ],
+/// 	"talksWithVideo": [ "some-talk" ],
+/// 	"talksWithoutVideo": [ "other-talk" ],
+/// 	"scoreDistributions": [
+/// 		{ "relationType": "tag", "count": 30450, "min": 0, "max": 100, "mean": 12.3, "p25": 0, "median": 0, "p75": 20, "p90": 40, "p99": 80 }
+/// 	]
+/// }
+/// ```
+/// The empty enum has the same effect as a private constructor, preventing instantiation.
+pub enum StatisticsJson {}
+
+impl StatisticsJson {
+	/// Version of the output schema, see [`RecommendationJson::SCHEMA_VERSION`](crate::output::recommendation_json::RecommendationJson::SCHEMA_VERSION).
+	pub const SCHEMA_VERSION: u32 = 1;
+
+	pub fn to_json(statistics: &Statistics) -> Result<JString, Exception> {
+		let slugs = |posts: Vec<Post>| posts.iter().map(|post| post.slug().value().to_string()).collect();
+		let document = StatisticsDocument {
+			schema_version: Self::SCHEMA_VERSION,
+			posts_per_kind_and_year: statistics.posts_per_kind_and_year(),
+			tag_frequencies: statistics
+				.tag_frequencies()
+				.into_iter()
+				.map(|(tag, posts)| TagEntry { tag, posts })
+				.collect(),
+			posts_without_repository: slugs(statistics.posts_without_repository()),
+			talks_with_video: slugs(statistics.talks_with_video()),
+			talks_without_video: slugs(statistics.talks_without_video()),
+			score_distributions: statistics
+				.score_distributions()
+				.iter()
+				.map(DistributionEntry::from)
+				.collect(),
+		};
+		OutputUtils::to_json(&document)
+	}
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct StatisticsDocument {
+	schema_version: u32,
+	posts_per_kind_and_year: BTreeMap<String, BTreeMap<String, usize>>,
+	tag_frequencies: Vec<TagEntry>,
+	posts_without_repository: Vec<String>,
+	talks_with_video: Vec<String>,
+	talks_without_video: Vec<String>,
+	score_distributions: Vec<DistributionEntry>,
+}
+
+#[derive(Serialize)]
+struct TagEntry {
+	tag: String,
+	posts: usize,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct DistributionEntry {
+	relation_type: String,
+	count: usize,
+	min: i64,
+	max: i64,
+	mean: f64,
+	p25: i64,
+	median: i64,
+	p75: i64,
+	p90: i64,
+	p99: i64,
+}
+
+impl From<&ScoreDistribution> for DistributionEntry {
+	fn from(distribution: &ScoreDistribution) -> Self {
+		Self {
+			relation_type: distribution.relation_type().to_string(),
+			count: distribution.count(),
+			min: distribution.min(),
+			max: distribution.max(),
+			mean: distribution.mean(),
+			p25: distribution.p25(),
+			median: distribution.median(),
+			p75: distribution.p75(),
+			p90: distribution.p90(),
+			p99: distribution.p99(),
+		}
+	}
+}
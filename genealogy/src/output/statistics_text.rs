@@ -0,0 +1,72 @@
+use crate::post::Post;
+use crate::statistics::Statistics;
+use genealogy_java_apis::string::JString;
+
+/// Formats [`Statistics`] as human readable text:
+/// ```text
+/// Posts per year:
+/// 	article: 2019: 12, 2020: 30 (42 total)
+/// Tags:
+/// 	java: 42
+/// Posts without repository (1):
+/// 	some-slug
+/// Talks with video (1):
+/// 	some-talk
+/// Talks without video (0):
+/// Scores:
+/// 	tag: count 30450, min 0, max 100, mean 12.30, p25 0, median 0, p75 20, p90 40, p99 80
+/// ```
+/// The empty enum has the same effect as a private constructor, preventing instantiation.
+pub enum StatisticsText {}
+
+impl StatisticsText {
+	pub fn to_text(statistics: &Statistics) -> JString {
+		let mut text = String::from("Posts per year:\n");
+		for (kind, posts_per_year) in statistics.posts_per_kind_and_year() {
+			let total = posts_per_year.values().sum::<usize>();
+			let years = posts_per_year
+				.into_iter()
+				.map(|(year, posts)| format!("{}: {}", year, posts))
+				.collect::<Vec<_>>();
+			text += &format!("\t{}: {} ({} total)\n", kind, years.join(", "), total);
+		}
+
+		text += "Tags:\n";
+		for (tag, posts) in statistics.tag_frequencies() {
+			text += &format!("\t{}: {}\n", tag, posts);
+		}
+
+		Self::write_posts(
+			&mut text,
+			"Posts without repository",
+			statistics.posts_without_repository(),
+		);
+		Self::write_posts(&mut text, "Talks with video", statistics.talks_with_video());
+		Self::write_posts(&mut text, "Talks without video", statistics.talks_without_video());
+
+		text += "Scores:";
+		for distribution in statistics.score_distributions() {
+			text += &format!(
+				"\n\t{}: count {}, min {}, max {}, mean {:.2}, p25 {}, median {}, p75 {}, p90 {}, p99 {}",
+				distribution.relation_type(),
+				distribution.count(),
+				distribution.min(),
+				distribution.max(),
+				distribution.mean(),
+				distribution.p25(),
+				distribution.median(),
+				distribution.p75(),
+				distribution.p90(),
+				distribution.p99()
+			);
+		}
+		text.into()
+	}
+
+	fn write_posts(text: &mut String, heading: &str, posts: Vec<Post>) {
+		*text += &format!("{} ({}):\n", heading, posts.len());
+		for post in posts {
+			*text += &format!("\t{}\n", post.slug().value());
+		}
+	}
+}
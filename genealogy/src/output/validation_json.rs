@@ -0,0 +1,60 @@
+use crate::output::output_utils::OutputUtils;
+use crate::validation::ValidationError;
+use genealogy_java_apis::exception::Exception;
+use genealogy_java_apis::list::List;
+use genealogy_java_apis::string::JString;
+use serde::Serialize;
+
+/// Serializes [`ValidationError`]s to JSON.
+///
+/// ```json
+/// {
+/// 	"schemaVersion": 1,
+/// 	"errors": [
+/// 		{ "file": "content/articles/some-article.md", "line": 4, "key": "date", "message": "…" }
+/// 	]
+/// }
+/// ```
+/// `line` and `key` are `null` if the error isn't about a specific line or key.
+///
+/// The empty enum has the same effect as a private constructor, preventing instantiation.
+pub enum ValidationJson {}
+
+impl ValidationJson {
+	/// Version of the output schema, see [`RecommendationJson::SCHEMA_VERSION`](crate::output::recommendation_json::RecommendationJson::SCHEMA_VERSION).
+	pub const SCHEMA_VERSION: u32 = 1;
+
+	pub fn to_json(errors: List<ValidationError>) -> Result<JString, Exception> {
+		let document = ValidationDocument {
+			schema_version: Self::SCHEMA_VERSION,
+			errors: errors.into_iter().map(|error| ErrorEntry::from(&error)).collect(),
+		};
+		OutputUtils::to_json(&document)
+	}
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ValidationDocument {
+	schema_version: u32,
+	errors: Vec<ErrorEntry>,
+}
+
+#[derive(Serialize)]
+struct ErrorEntry {
+	file: String,
+	line: Option<usize>,
+	key: Option<String>,
+	message: String,
+}
+
+impl From<&ValidationError> for ErrorEntry {
+	fn from(error: &ValidationError) -> Self {
+		Self {
+			file: error.file().to_string(),
+			line: error.line().get().ok(),
+			key: error.key().get().ok().map(|key| key.to_string()),
+			message: error.message().to_string(),
+		}
+	}
+}
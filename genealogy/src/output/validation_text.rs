@@ -0,0 +1,27 @@
+use crate::validation::ValidationError;
+use genealogy_java_apis::list::List;
+use genealogy_java_apis::string::JString;
+
+/// Formats [`ValidationError`]s as human readable text, one line per error followed by a summary:
+/// ```text
+/// content/articles/some-article.md:4: date: DateTimeException: 'input contains invalid characters'
+/// 1 error found.
+/// ```
+/// The empty enum has the same effect as a private constructor, preventing instantiation.
+pub enum ValidationText {}
+
+impl ValidationText {
+	pub fn to_text(errors: List<ValidationError>) -> JString {
+		let count = errors.length();
+		let mut text = String::new();
+		for error in errors {
+			text += &format!("{}\n", error.describe());
+		}
+		text += &match count {
+			0 => "No errors found.".to_string(),
+			1 => "1 error found.".to_string(),
+			count => format!("{} errors found.", count),
+		};
+		text.into()
+	}
+}
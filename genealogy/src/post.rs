@@ -1,22 +1,35 @@
 use crate::post::article::Article;
+use crate::post::content::Content;
 use crate::post::description::Description;
+use crate::post::metadata::Metadata;
+use crate::post::publication_status::PublicationStatus;
 use crate::post::slug::Slug;
 use crate::post::tag::Tag;
 use crate::post::talk::Talk;
 use crate::post::title::Title;
 use crate::post::video::Video;
+use genealogy_java_apis::optional::Optional;
 use genealogy_java_apis::set::Set;
+use genealogy_java_apis::string::JString;
 use genealogy_java_apis::time::LocalDate;
-use std::fmt::{Display, Formatter};
+use std::fmt::{Debug, Display, Formatter};
+use std::hash::{Hash, Hasher};
 use std::rc::Rc;
 
 pub mod article;
 pub mod content;
+pub mod corpus;
+pub mod dangling_reference;
 pub mod description;
 pub mod factories;
+pub mod metadata;
+pub mod post_type;
+pub mod publication_status;
 pub mod repository;
 pub mod slug;
+pub mod slug_rules;
 pub mod tag;
+pub mod tag_taxonomy;
 pub mod talk;
 pub mod title;
 pub mod video;
@@ -28,89 +41,196 @@ pub mod video_slug;
 ///
 /// enum instead of sealed interface. The semantics are roughly equivalent
 /// since both are sum types.
-#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+///
+/// Note: Unlike the sealed interface, it is open to other kinds of posts with [`Post::Other`],
+/// see [`PostFactoryService`](crate::post::factories::post_factory_service::PostFactoryService).
+/// Posts are equal if they are of the same kind and have the same slug.
+#[derive(Debug, Clone)]
 pub enum Post {
 	Article(Rc<Article>),
 	Talk(Rc<Talk>),
 	Video(Rc<Video>),
+	Other(Rc<dyn PostTrait>),
 }
 
 use Post::*;
 
 impl Post {
+	fn post(&self) -> &dyn PostTrait {
+		match self {
+			Article(article) => article.as_ref(),
+			Talk(talk) => talk.as_ref(),
+			Video(video) => video.as_ref(),
+			Other(post) => post.as_ref(),
+		}
+	}
+
 	/// ```java
 	/// Title title();
 	/// ```
 	pub fn title(&self) -> Title {
-		match self {
-			Article(article) => article.title(),
-			Talk(talk) => talk.title(),
-			Video(video) => video.title(),
-		}
+		self.post().title()
 	}
 
 	/// ```java
 	/// Set<Tag> tags();
 	/// ```
 	pub fn tags(&self) -> Set<Tag> {
-		match self {
-			Article(article) => article.tags(),
-			Talk(talk) => talk.tags(),
-			Video(video) => video.tags(),
-		}
+		self.post().tags()
 	}
 
 	/// ```java
 	/// LocalDate date();
 	/// ```
 	pub fn date(&self) -> LocalDate {
-		match self {
-			Article(article) => article.date(),
-			Talk(talk) => talk.date(),
-			Video(video) => video.date(),
-		}
+		self.post().date()
 	}
 
 	/// ```java
 	/// Description description();
 	/// ```
 	pub fn description(&self) -> Description {
-		match self {
-			Article(article) => article.description(),
-			Talk(talk) => talk.description(),
-			Video(video) => video.description(),
-		}
+		self.post().description()
 	}
 
 	/// ```java
 	/// Slug slug();
 	/// ```
 	pub fn slug(&self) -> Slug {
+		self.post().slug()
+	}
+
+	/// The front matter keys that aren't read into the other fields.
+	pub fn metadata(&self) -> Metadata {
+		self.post().metadata()
+	}
+
+	/// Name of the kind of post, as used in the config and the output formats.
+	pub fn kind(&self) -> JString {
+		self.post().kind()
+	}
+
+	/// The Markdown after the front matter, only articles have one.
+	pub fn content(&self) -> Optional<Content> {
+		self.post().content()
+	}
+
+	/// The video of a talk's recording or the talk a video is a recording of, once the posts are linked,
+	/// see [`Corpus::link_talks_and_videos`](corpus::Corpus::link_talks_and_videos).
+	pub fn linked_post(&self) -> Optional<Post> {
+		match self {
+			Talk(talk) => Optional::of_nullable(talk.recording().into_iter().next().map(Video)),
+			Video(video) => Optional::of_nullable(video.talk().into_iter().next().map(Talk)),
+			Article(_) | Other(_) => Optional::empty(),
+		}
+	}
+
+	/// Whether the post is published as of the given date.
+	pub fn publication_status(&self, as_of: LocalDate) -> PublicationStatus {
+		PublicationStatus::of(self, as_of)
+	}
+
+	/// The same post with other tags, e.g. normalized with a [`TagTaxonomy`](tag_taxonomy::TagTaxonomy).
+	/// Posts of other kinds can't be changed, they keep the tags their factory created.
+	pub fn with_tags(&self, tags: Set<Tag>) -> Post {
 		match self {
-			Article(article) => article.slug(),
-			Talk(talk) => talk.slug(),
-			Video(video) => video.slug(),
+			Article(article) => Article(Rc::new(article.with_tags(tags))),
+			Talk(talk) => Talk(Rc::new(talk.with_tags(tags))),
+			Video(video) => Video(Rc::new(video.with_tags(tags))),
+			Other(_) => self.clone(),
+		}
+	}
+}
+
+/// ```java
+/// public sealed interface Post permits Article, Talk, Video {
+///
+/// 	Title title();
+///
+/// 	Set<Tag> tags();
+///
+/// 	LocalDate date();
+///
+/// 	Description description();
+///
+/// 	Slug slug();
+///
+/// }
+/// ```
+/// Note: Implemented by every kind of post, so that [`Post`] can be extended with [`Post::Other`].
+pub trait PostTrait: Display + Debug {
+	/// ```java
+	/// Title title();
+	/// ```
+	fn title(&self) -> Title;
+
+	/// ```java
+	/// Set<Tag> tags();
+	/// ```
+	fn tags(&self) -> Set<Tag>;
+
+	/// ```java
+	/// LocalDate date();
+	/// ```
+	fn date(&self) -> LocalDate;
+
+	/// ```java
+	/// Description description();
+	/// ```
+	fn description(&self) -> Description;
+
+	/// ```java
+	/// Slug slug();
+	/// ```
+	fn slug(&self) -> Slug;
+
+	/// The front matter keys that aren't read into the other fields, see [`Metadata`].
+	fn metadata(&self) -> Metadata;
+
+	/// Name of the kind of post, as used in the config and the output formats.
+	fn kind(&self) -> JString;
+
+	/// The Markdown after the front matter, see [`Content::document`]. Posts without content,
+	/// like talks and videos, have none.
+	fn content(&self) -> Optional<Content> {
+		Optional::empty()
+	}
+}
+
+impl PartialEq for Post {
+	fn eq(&self, other: &Self) -> bool {
+		match (self, other) {
+			(Article(article), Article(other)) => article == other,
+			(Talk(talk), Talk(other)) => talk == other,
+			(Video(video), Video(other)) => video == other,
+			(Other(post), Other(other)) => post.kind() == other.kind() && post.slug() == other.slug(),
+			_ => false,
 		}
 	}
 }
 
+impl Eq for Post {}
+
+impl Hash for Post {
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		self.kind().hash(state);
+		self.slug().hash(state);
+	}
+}
+
 impl Display for Post {
 	fn fmt(&self, formatter: &mut Formatter) -> std::fmt::Result {
-		match self {
-			Article(article) => article.fmt(formatter),
-			Talk(talk) => talk.fmt(formatter),
-			Video(video) => video.fmt(formatter),
-		}
+		Display::fmt(self.post(), formatter)
 	}
 }
 
 #[cfg(test)]
 pub mod test {
 	use super::*;
+	use crate::post::post_type::PostType;
 	use genealogy_java_apis::exception::Exception;
-	use genealogy_java_apis::optional::Optional;
+	use genealogy_java_apis::list::List;
 	use genealogy_java_apis::stream::Stream;
-	use genealogy_java_apis::string::JString;
 	use genealogy_java_apis::time::LocalDateExtension;
 
 	/// ```java
@@ -140,8 +260,26 @@ pub mod test {
 				Slug::new(slug)?,
 				Optional::empty(),
 				(|| Stream::of(["".into()])).into(),
+				Metadata::empty(),
 			)
 			.into())
 		}
+
+		/// A post of the given type with the given `videoSlug`, which talks and videos can be linked by,
+		/// see [`Corpus::link_talks_and_videos`](corpus::Corpus::link_talks_and_videos).
+		pub fn create_of_type(post_type: PostType, slug: &str, video_slug: &str) -> Result<Post, Exception> {
+			let lines = [
+				"---".to_string(),
+				"title: Title".to_string(),
+				"tags: [Tag]".to_string(),
+				"date: 2020-01-23".to_string(),
+				"description: \"description\"".to_string(),
+				format!("slug: {}", slug),
+				format!("videoSlug: {}", video_slug),
+				"slides: https://slides.codefx.org".to_string(),
+				"---".to_string(),
+			];
+			post_type.create_post_from_lines(List::of(lines.iter().map(|line| JString::from(line.as_str()))))
+		}
 	}
 }
@@ -1,13 +1,15 @@
 use crate::post::content::Content;
 use crate::post::description::Description;
+use crate::post::metadata::Metadata;
 use crate::post::repository::Repository;
 use crate::post::slug::Slug;
 use crate::post::tag::Tag;
 use crate::post::title::Title;
-use crate::post::Post;
+use crate::post::{Post, PostTrait};
 use genealogy_java_apis::optional::Optional;
 use genealogy_java_apis::record;
 use genealogy_java_apis::set::Set;
+use genealogy_java_apis::string::JString;
 use genealogy_java_apis::time::LocalDate;
 use std::hash::{Hash, Hasher};
 use std::rc::Rc;
@@ -36,6 +38,8 @@ use std::rc::Rc;
 /// The `implements Post` can't be emulated directly since there is no
 /// inheritance in rust and traits cannot be `sealed`. Therefore [`Post`]
 /// is an enum instead and the `implements` is emulated by a [`From`] implementation.
+///
+/// Note: `metadata` holds the front matter keys that aren't read into the other fields, see [`Metadata`].
 #[record(equals = false, hash = false)]
 pub struct Article {
 	title: Title,
@@ -46,6 +50,7 @@ pub struct Article {
 	slug: Slug,
 	repository: Optional<Repository>,
 	content: Content,
+	metadata: Metadata,
 }
 
 impl Article {
@@ -58,6 +63,48 @@ impl Article {
 	pub fn tags(&self) -> Set<Tag> {
 		Set::copy_of(self.tags.clone())
 	}
+
+	/// The same article with other tags, see [`Post::with_tags`].
+	pub(crate) fn with_tags(&self, tags: Set<Tag>) -> Article {
+		Article { tags, ..self.clone() }
+	}
+}
+
+/// ```java
+/// public record Article(...) implements Post
+/// ```
+impl PostTrait for Article {
+	fn title(&self) -> Title {
+		Article::title(self)
+	}
+
+	fn tags(&self) -> Set<Tag> {
+		Article::tags(self)
+	}
+
+	fn date(&self) -> LocalDate {
+		Article::date(self)
+	}
+
+	fn description(&self) -> Description {
+		Article::description(self)
+	}
+
+	fn slug(&self) -> Slug {
+		Article::slug(self)
+	}
+
+	fn metadata(&self) -> Metadata {
+		Article::metadata(self)
+	}
+
+	fn kind(&self) -> JString {
+		"article".into()
+	}
+
+	fn content(&self) -> Optional<Content> {
+		Optional::of(Article::content(self))
+	}
 }
 
 /// ```java
@@ -1,9 +1,59 @@
+use crate::post::content::document::Document;
+use genealogy_java_apis::exception::Exception;
 use genealogy_java_apis::function::supplier::Supplier;
 use genealogy_java_apis::stream::Stream;
 use genealogy_java_apis::string::JString;
+use std::fmt::{Display, Formatter};
+
+pub mod block;
+pub mod code_block;
+pub mod document;
+pub mod heading;
+pub mod image;
+pub mod link;
+pub mod paragraph;
+pub mod section;
 
 /// ```java
 /// @FunctionalInterface
 /// public interface Content extends Supplier<Stream<String>> {}
 /// ```
-pub type Content = Supplier<Stream<JString>>;
+/// Note: A struct around the [`Supplier`] instead of a type alias, so that the lines
+/// can also be parsed into a [`Document`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Content {
+	lines: Supplier<Stream<JString>>,
+}
+
+impl Content {
+	/// The lines of Markdown after the front matter, see [`Supplier::get`].
+	pub fn get(&self) -> Stream<JString> {
+		self.lines.get()
+	}
+
+	/// The lines parsed as Markdown. Parsing doesn't fail, every text is valid Markdown,
+	/// but reading the lines can.
+	pub fn document(&self) -> Result<Document, Exception> {
+		let lines = self
+			.get()
+			.into_iterator()
+			.map(|line| line.map(|line| line.to_string()))
+			.collect::<Result<Vec<_>, _>>()?;
+		Ok(Document::parse(&lines.join("\n")))
+	}
+}
+
+impl<Function> From<Function> for Content
+where
+	Function: Fn() -> Stream<JString> + 'static,
+{
+	fn from(function: Function) -> Self {
+		Self { lines: function.into() }
+	}
+}
+
+impl Display for Content {
+	fn fmt(&self, formatter: &mut Formatter) -> std::fmt::Result {
+		Display::fmt(&self.lines, formatter)
+	}
+}
@@ -0,0 +1,18 @@
+use crate::post::content::code_block::CodeBlock;
+use crate::post::content::paragraph::Paragraph;
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Block {
+	Paragraph(Paragraph),
+	Code(CodeBlock),
+}
+
+impl Display for Block {
+	fn fmt(&self, formatter: &mut Formatter) -> std::fmt::Result {
+		match self {
+			Block::Paragraph(paragraph) => Display::fmt(paragraph, formatter),
+			Block::Code(code_block) => Display::fmt(code_block, formatter),
+		}
+	}
+}
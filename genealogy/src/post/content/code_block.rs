@@ -0,0 +1,11 @@
+use genealogy_java_apis::optional::Optional;
+use genealogy_java_apis::record;
+use genealogy_java_apis::string::JString;
+
+/// Fenced or indented code, only fenced code has a language.
+#[record]
+pub struct CodeBlock {
+	/// The first word of the info string of the fence, e.g. `java` for ```` ```java ````.
+	language: Optional<JString>,
+	code: JString,
+}
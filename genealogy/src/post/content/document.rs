@@ -0,0 +1,355 @@
+use crate::post::content::block::Block;
+use crate::post::content::code_block::CodeBlock;
+use crate::post::content::heading::Heading;
+use crate::post::content::image::Image;
+use crate::post::content::link::{Link, LinkKind};
+use crate::post::content::paragraph::Paragraph;
+use crate::post::content::section::Section;
+use genealogy_java_apis::list::List;
+use genealogy_java_apis::optional::Optional;
+use genealogy_java_apis::record;
+use genealogy_java_apis::string::JString;
+use pulldown_cmark::{CodeBlockKind, Event, Parser, Tag, TagEnd};
+
+/// The Markdown of a [`Content`](crate::post::content::Content), parsed into sections.
+///
+/// Every heading starts a new section, whatever its level, so the sections are flat.
+/// Text before the first heading is in a section without a heading.
+#[record]
+pub struct Document {
+	sections: List<Section>,
+}
+
+impl Document {
+	/// Average reading speed that [`Document::reading_time_minutes`] is estimated with.
+	pub const WORDS_PER_MINUTE: usize = 200;
+
+	pub fn parse(markdown: &str) -> Document {
+		let mut parser = DocumentParser::default();
+		for event in Parser::new(markdown) {
+			parser.handle(event);
+		}
+		parser.finish()
+	}
+
+	pub fn headings(&self) -> List<Heading> {
+		List::of(self.sections.clone().into_iter().flat_map(|section| section.heading()))
+	}
+
+	pub fn paragraphs(&self) -> List<Paragraph> {
+		List::of(self.blocks().filter_map(|block| match block {
+			Block::Paragraph(paragraph) => Some(paragraph),
+			Block::Code(_) => None,
+		}))
+	}
+
+	pub fn code_blocks(&self) -> List<CodeBlock> {
+		List::of(self.blocks().filter_map(|block| match block {
+			Block::Code(code_block) => Some(code_block),
+			Block::Paragraph(_) => None,
+		}))
+	}
+
+	/// The links of all paragraphs, in order.
+	pub fn links(&self) -> List<Link> {
+		List::of(self.paragraphs().into_iter().flat_map(|paragraph| paragraph.links()))
+	}
+
+	/// The images of all paragraphs, in order.
+	pub fn images(&self) -> List<Image> {
+		List::of(self.paragraphs().into_iter().flat_map(|paragraph| paragraph.images()))
+	}
+
+	/// The words of the headings and paragraphs. Code blocks and the alternative text of images aren't read,
+	/// so they don't count.
+	pub fn word_count(&self) -> usize {
+		let headings = self.headings().into_iter().map(|heading| heading.text());
+		let paragraphs = self.paragraphs().into_iter().map(|paragraph| paragraph.text());
+		headings.chain(paragraphs).map(|text| Self::words(text.as_ref())).sum()
+	}
+
+	/// Estimated from the [`Document::word_count`], rounded up, so a document with any words takes at least a minute.
+	pub fn reading_time_minutes(&self) -> usize {
+		self.word_count().div_ceil(Self::WORDS_PER_MINUTE)
+	}
+
+	fn blocks(&self) -> impl Iterator<Item = Block> {
+		self.sections.clone().into_iter().flat_map(|section| section.blocks())
+	}
+
+	/// Punctuation on its own, like a dash between words, isn't a word.
+	fn words(text: &str) -> usize {
+		text.split_whitespace()
+			.filter(|word| word.chars().any(char::is_alphanumeric))
+			.count()
+	}
+}
+
+/// The block that text is currently added to.
+enum OpenBlock {
+	Heading {
+		level: u8,
+		text: String,
+	},
+	Paragraph {
+		text: String,
+		links: Vec<Link>,
+		images: Vec<Image>,
+	},
+	Code {
+		language: Optional<JString>,
+		code: String,
+	},
+}
+
+/// Builds a [`Document`] from the events of the Markdown parser.
+#[derive(Default)]
+struct DocumentParser {
+	sections: Vec<Section>,
+	heading: Option<Heading>,
+	blocks: Vec<Block>,
+	open_block: Option<OpenBlock>,
+	/// Kind, destination and text of the link that is currently read.
+	link: Option<(LinkKind, JString, String)>,
+	/// Source and alternative text of the image that is currently read.
+	image: Option<(JString, String)>,
+}
+
+impl DocumentParser {
+	fn handle(&mut self, event: Event) {
+		match event {
+			Event::Start(Tag::Heading { level, .. }) => {
+				self.close_block();
+				self.open_block = Some(OpenBlock::Heading {
+					level: level as u8,
+					text: String::new(),
+				});
+			}
+			Event::Start(Tag::CodeBlock(kind)) => {
+				self.close_block();
+				let language = match kind {
+					CodeBlockKind::Fenced(info) => info.split_whitespace().next().map(JString::from),
+					CodeBlockKind::Indented => None,
+				};
+				self.open_block = Some(OpenBlock::Code {
+					language: Optional::of_nullable(language),
+					code: String::new(),
+				});
+			}
+			Event::Start(Tag::Link {
+				link_type, dest_url, ..
+			}) => {
+				self.open_paragraph();
+				self.link = Some((link_type.into(), dest_url.as_ref().into(), String::new()));
+			}
+			Event::End(TagEnd::Link) => {
+				if let Some((kind, destination, text)) = self.link.take() {
+					self.paragraph_links_and_images(|links, _| {
+						links.push(Link::new(text.trim().into(), destination, kind))
+					});
+				}
+			}
+			Event::Start(Tag::Image { dest_url, .. }) => {
+				self.open_paragraph();
+				self.image = Some((dest_url.as_ref().into(), String::new()));
+			}
+			Event::End(TagEnd::Image) => {
+				if let Some((source, alt_text)) = self.image.take() {
+					self.paragraph_links_and_images(|_, images| {
+						images.push(Image::new(alt_text.trim().into(), source))
+					});
+				}
+			}
+			Event::Start(Tag::Emphasis | Tag::Strong | Tag::Strikethrough | Tag::Superscript | Tag::Subscript)
+			| Event::End(
+				TagEnd::Emphasis | TagEnd::Strong | TagEnd::Strikethrough | TagEnd::Superscript | TagEnd::Subscript,
+			) => {}
+			// every other tag is a block, which ends the text that came before it
+			Event::Start(_) | Event::End(_) => self.close_block(),
+			Event::Text(text) | Event::Code(text) => self.add_text(&text),
+			Event::SoftBreak | Event::HardBreak => self.add_text(" "),
+			_ => {}
+		}
+	}
+
+	fn add_text(&mut self, text: &str) {
+		if let Some((_, alt_text)) = &mut self.image {
+			alt_text.push_str(text);
+			return;
+		}
+		if let Some((_, _, link_text)) = &mut self.link {
+			link_text.push_str(text);
+		}
+
+		match self.open_paragraph() {
+			OpenBlock::Heading { text: heading, .. } | OpenBlock::Paragraph { text: heading, .. } => {
+				heading.push_str(text)
+			}
+			OpenBlock::Code { code, .. } => code.push_str(text),
+		}
+	}
+
+	/// Text outside of any block, e.g. in a tight list, starts a paragraph.
+	fn open_paragraph(&mut self) -> &mut OpenBlock {
+		self.open_block.get_or_insert_with(|| OpenBlock::Paragraph {
+			text: String::new(),
+			links: Vec::new(),
+			images: Vec::new(),
+		})
+	}
+
+	/// Links and images in headings are dropped, their text is kept.
+	fn paragraph_links_and_images(&mut self, add: impl FnOnce(&mut Vec<Link>, &mut Vec<Image>)) {
+		if let Some(OpenBlock::Paragraph { links, images, .. }) = &mut self.open_block {
+			add(links, images);
+		}
+	}
+
+	fn close_block(&mut self) {
+		match self.open_block.take() {
+			None => {}
+			Some(OpenBlock::Heading { level, text }) => {
+				self.close_section();
+				self.heading = Some(Heading::new(level, Self::normalize(&text)));
+			}
+			Some(OpenBlock::Paragraph { text, links, images }) => {
+				let text = Self::normalize(&text);
+				if !text.is_blank() || !links.is_empty() || !images.is_empty() {
+					self.blocks.push(Block::Paragraph(Paragraph::new(
+						text,
+						List::of(links),
+						List::of(images),
+					)));
+				}
+			}
+			Some(OpenBlock::Code { language, code }) => {
+				self.blocks.push(Block::Code(CodeBlock::new(language, code.into())));
+			}
+		}
+	}
+
+	fn close_section(&mut self) {
+		let heading = self.heading.take();
+		let blocks = std::mem::take(&mut self.blocks);
+		if heading.is_some() || !blocks.is_empty() {
+			self.sections
+				.push(Section::new(Optional::of_nullable(heading), List::of(blocks)));
+		}
+	}
+
+	fn finish(mut self) -> Document {
+		self.close_block();
+		self.close_section();
+		Document::new(List::of(self.sections))
+	}
+
+	/// Line breaks and the spaces around formatting are collapsed into single spaces.
+	fn normalize(text: &str) -> JString {
+		text.split_whitespace().collect::<Vec<_>>().join(" ").into()
+	}
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod test {
+	use super::*;
+	use genealogy_java_apis::test::assert_that;
+
+	const MARKDOWN: &str = "\
+Intro with a [link](https://example.org) and <https://nipafx.dev>.
+
+## Code
+
+```java
+var list = List.of(1);
+```
+
+    indented
+
+### Images
+
+![A *diagram*](diagram.png) of [the reference][ref].
+
+- first item
+- second item
+
+[ref]: https://example.org/reference
+";
+
+	#[test]
+	fn parse__sections_with_headings() {
+		let document = Document::parse(MARKDOWN);
+
+		assert_that(document.sections().length()).is_equal_to(3);
+		assert!(document.sections().get(0).unwrap().heading().is_empty());
+		assert_that(document.headings()).is_equal_to(List::of([
+			Heading::new(2, "Code".into()),
+			Heading::new(3, "Images".into()),
+		]));
+	}
+
+	#[test]
+	fn parse__paragraphs_including_list_items() {
+		let texts = Document::parse(MARKDOWN)
+			.paragraphs()
+			.into_iter()
+			.map(|paragraph| paragraph.text())
+			.collect::<Vec<_>>();
+
+		assert_that(texts).is_equal_to(vec![
+			JString::from("Intro with a link and https://nipafx.dev."),
+			"of the reference.".into(),
+			"first item".into(),
+			"second item".into(),
+		]);
+	}
+
+	#[test]
+	fn parse__inline_reference_and_auto_links() {
+		assert_that(Document::parse(MARKDOWN).links()).is_equal_to(List::of([
+			Link::new("link".into(), "https://example.org".into(), LinkKind::Inline),
+			Link::new(
+				"https://nipafx.dev".into(),
+				"https://nipafx.dev".into(),
+				LinkKind::Autolink,
+			),
+			Link::new(
+				"the reference".into(),
+				"https://example.org/reference".into(),
+				LinkKind::Reference,
+			),
+		]));
+	}
+
+	#[test]
+	fn parse__code_blocks_with_language() {
+		assert_that(Document::parse(MARKDOWN).code_blocks()).is_equal_to(List::of([
+			CodeBlock::new(Optional::of("java".into()), "var list = List.of(1);\n".into()),
+			CodeBlock::new(Optional::empty(), "indented\n".into()),
+		]));
+	}
+
+	#[test]
+	fn parse__images() {
+		assert_that(Document::parse(MARKDOWN).images())
+			.is_equal_to(List::of([Image::new("A diagram".into(), "diagram.png".into())]));
+	}
+
+	#[test]
+	fn word_count__prose_without_code() {
+		let document = Document::parse(MARKDOWN);
+
+		// "Code", "Images", 6 in the intro, 3 after the image and 2 per item
+		assert_that(document.word_count()).is_equal_to(15);
+	}
+
+	#[test]
+	fn reading_time__rounded_up_minutes() {
+		let words = |count: usize| Document::parse(&vec!["word"; count].join(" "));
+
+		assert_that(words(0).reading_time_minutes()).is_equal_to(0);
+		assert_that(words(1).reading_time_minutes()).is_equal_to(1);
+		assert_that(words(200).reading_time_minutes()).is_equal_to(1);
+		assert_that(words(201).reading_time_minutes()).is_equal_to(2);
+	}
+}
@@ -0,0 +1,10 @@
+use genealogy_java_apis::record;
+use genealogy_java_apis::string::JString;
+
+/// The text of a heading, without formatting. Links in headings are only kept as text.
+#[record]
+pub struct Heading {
+	/// 1 for `#` up to 6 for `######`.
+	level: u8,
+	text: JString,
+}
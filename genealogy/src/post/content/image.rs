@@ -0,0 +1,8 @@
+use genealogy_java_apis::record;
+use genealogy_java_apis::string::JString;
+
+#[record]
+pub struct Image {
+	alt_text: JString,
+	source: JString,
+}
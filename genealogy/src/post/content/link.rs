@@ -0,0 +1,47 @@
+use genealogy_java_apis::record;
+use genealogy_java_apis::string::JString;
+use pulldown_cmark::LinkType;
+use std::fmt::{Display, Formatter};
+
+#[record]
+pub struct Link {
+	text: JString,
+	destination: JString,
+	kind: LinkKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LinkKind {
+	/// `[text](destination)`
+	Inline,
+	/// `[text][label]`, `[text][]` or `[text]` with the destination defined elsewhere as `[label]: destination`.
+	Reference,
+	/// `<destination>`
+	Autolink,
+}
+
+impl Display for LinkKind {
+	fn fmt(&self, formatter: &mut Formatter) -> std::fmt::Result {
+		let name = match self {
+			LinkKind::Inline => "inline",
+			LinkKind::Reference => "reference",
+			LinkKind::Autolink => "autolink",
+		};
+		formatter.write_str(name)
+	}
+}
+
+impl From<LinkType> for LinkKind {
+	fn from(link_type: LinkType) -> Self {
+		match link_type {
+			LinkType::Reference
+			| LinkType::ReferenceUnknown
+			| LinkType::Collapsed
+			| LinkType::CollapsedUnknown
+			| LinkType::Shortcut
+			| LinkType::ShortcutUnknown => LinkKind::Reference,
+			LinkType::Autolink | LinkType::Email => LinkKind::Autolink,
+			LinkType::Inline | LinkType::WikiLink { .. } => LinkKind::Inline,
+		}
+	}
+}
@@ -0,0 +1,15 @@
+use crate::post::content::image::Image;
+use crate::post::content::link::Link;
+use genealogy_java_apis::list::List;
+use genealogy_java_apis::record;
+use genealogy_java_apis::string::JString;
+
+/// A paragraph of text without formatting, with its links and images.
+///
+/// Text that isn't in a paragraph in Markdown, like the items of a tight list or table cells, is a paragraph of its own.
+#[record]
+pub struct Paragraph {
+	text: JString,
+	links: List<Link>,
+	images: List<Image>,
+}
@@ -0,0 +1,12 @@
+use crate::post::content::block::Block;
+use crate::post::content::heading::Heading;
+use genealogy_java_apis::list::List;
+use genealogy_java_apis::optional::Optional;
+use genealogy_java_apis::record;
+
+/// A heading and the blocks up to the next heading.
+#[record]
+pub struct Section {
+	heading: Optional<Heading>,
+	blocks: List<Block>,
+}
@@ -0,0 +1,183 @@
+use crate::post::dangling_reference::DanglingReference;
+use crate::post::talk::Talk;
+use crate::post::video::Video;
+use crate::post::video_slug::VideoSlug;
+use crate::post::Post;
+use genealogy_java_apis::exception::Exception;
+use genealogy_java_apis::exception::Exception::IllegalArgumentException;
+use genealogy_java_apis::list::List;
+use genealogy_java_apis::path::Path;
+use genealogy_java_apis::throw;
+use std::collections::BTreeMap;
+use std::rc::Rc;
+
+/// Checks and links all posts together, after every post was created from its file.
+///
+/// The empty enum has the same effect as a private constructor, preventing instantiation.
+pub enum Corpus {}
+
+impl Corpus {
+	/// Posts are equal if their slugs are, see e.g. [`Article`](crate::post::article::Article), so two posts
+	/// with the same slug, even of different kinds, would silently be merged into one.
+	/// The error names every slug that is used more than once with the files that use it.
+	pub fn check_unique_slugs<'a>(posts_by_file: impl IntoIterator<Item = &'a (Path, Post)>) -> Result<(), Exception> {
+		let mut files_by_slug = BTreeMap::<String, Vec<String>>::new();
+		for (file, post) in posts_by_file {
+			files_by_slug
+				.entry(post.slug().value().to_string())
+				.or_default()
+				.push(format!("{} {}", post.kind(), file));
+		}
+
+		let duplicates = files_by_slug
+			.into_iter()
+			.filter(|(_, files)| files.len() > 1)
+			.map(|(slug, files)| format!("'{}' is used by {}", slug, files.join(", ")))
+			.collect::<Vec<_>>();
+		if !duplicates.is_empty() {
+			throw!(IllegalArgumentException(
+				format!("Slugs must be unique: \n\t{}", duplicates.join("\n\t")).into()
+			));
+		}
+		Ok(())
+	}
+
+	/// Links every talk to the video of its recording, which is the video with the talk's `video` slug,
+	/// and that video back to the talk, see [`Talk::recording`] and [`Video::talk`]. If several talks
+	/// have the same recording, the video is linked to the first of them in the given order.
+	///
+	/// Returns the linked posts in the same order, with the talks whose `video` slug matches no video.
+	pub fn link_talks_and_videos(posts_by_file: Vec<(Path, Post)>) -> (Vec<(Path, Post)>, List<DanglingReference>) {
+		let mut videos = BTreeMap::<VideoSlug, Rc<Video>>::new();
+		let mut talks = BTreeMap::<VideoSlug, Rc<Talk>>::new();
+		for (_, post) in &posts_by_file {
+			match post {
+				Post::Video(video) => {
+					videos.entry(video.video()).or_insert(video.clone());
+				}
+				Post::Talk(talk) => {
+					if let Ok(video) = talk.video().get() {
+						talks.entry(video).or_insert(talk.clone());
+					}
+				}
+				_ => {}
+			}
+		}
+
+		let mut dangling_references = Vec::new();
+		let posts_by_file = posts_by_file
+			.into_iter()
+			.map(|(file, post)| {
+				let post = match &post {
+					Post::Talk(talk) => match talk.video().get() {
+						Ok(video) => match videos.get(&video) {
+							Some(recording) => Post::Talk(Rc::new(talk.with_recording(recording.clone()))),
+							None => {
+								dangling_references.push(DanglingReference::new(file.clone(), talk.slug(), video));
+								post
+							}
+						},
+						Err(_) => post,
+					},
+					Post::Video(video) => match talks.get(&video.video()) {
+						Some(talk) => Post::Video(Rc::new(video.with_talk(talk.clone()))),
+						None => post,
+					},
+					_ => post,
+				};
+				(file, post)
+			})
+			.collect();
+		(posts_by_file, List::of(dangling_references))
+	}
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::post::post_type::PostType;
+	use genealogy_java_apis::string::JString;
+	use genealogy_java_apis::test::assert_that;
+
+	fn post(file: &str, post_type: PostType, slug: &str) -> (Path, Post) {
+		let lines = [
+			"---".to_string(),
+			"title: Title".to_string(),
+			"tags: [Tag]".to_string(),
+			"date: 2020-01-23".to_string(),
+			"description: \"Description\"".to_string(),
+			format!("slug: {}", slug),
+			"videoSlug: video".to_string(),
+			"slides: https://slides.codefx.org".to_string(),
+			"---".to_string(),
+		];
+		let post = post_type
+			.create_post_from_lines(List::of(lines.iter().map(|line| JString::from(line.as_str()))))
+			.unwrap();
+		(Path::of(file), post)
+	}
+
+	#[test]
+	fn link_talks_and_videos__linked_both_ways() {
+		let posts = vec![
+			post("talk.md", PostType::Talk, "talk"),
+			post("video.md", PostType::Video, "video"),
+			post("article.md", PostType::Article, "article"),
+		];
+
+		let (posts, dangling_references) = Corpus::link_talks_and_videos(posts);
+
+		assert!(dangling_references.is_empty());
+		let Post::Talk(talk) = &posts[0].1 else {
+			panic!("Not a talk: {}", posts[0].1);
+		};
+		let Post::Video(video) = &posts[1].1 else {
+			panic!("Not a video: {}", posts[1].1);
+		};
+		assert_that(talk.recording().get().unwrap().slug()).is_equal_to(video.slug());
+		assert_that(video.talk().get().unwrap().slug()).is_equal_to(talk.slug());
+		assert_that(posts[2].1.slug().value()).is_equal_to(JString::from("article"));
+	}
+
+	#[test]
+	fn link_talks_and_videos__missing_video__dangling_reference() {
+		let posts = vec![post("talk.md", PostType::Talk, "talk")];
+
+		let (posts, dangling_references) = Corpus::link_talks_and_videos(posts);
+
+		let Post::Talk(talk) = &posts[0].1 else {
+			panic!("Not a talk: {}", posts[0].1);
+		};
+		assert!(!talk.recording().is_present());
+		assert_that(dangling_references.length()).is_equal_to(1);
+		assert_that(dangling_references.get(0).unwrap().describe())
+			.is_equal_to(JString::from("talk.md: No video has the videoSlug 'video'."));
+	}
+
+	#[test]
+	fn check_unique_slugs__unique__ok() {
+		let posts = [
+			post("article.md", PostType::Article, "article"),
+			post("video.md", PostType::Video, "video"),
+		];
+
+		assert!(Corpus::check_unique_slugs(&posts).is_ok());
+	}
+
+	#[test]
+	fn check_unique_slugs__same_slug_across_kinds__error_with_files() {
+		let posts = [
+			post("article.md", PostType::Article, "java-17"),
+			post("talk.md", PostType::Talk, "java-17"),
+		];
+
+		let result = Corpus::check_unique_slugs(&posts);
+
+		assert!(matches!(
+			result,
+			Err(IllegalArgumentException(message))
+				if message.to_string().contains("'java-17' is used by article article.md, talk talk.md")
+		));
+	}
+}
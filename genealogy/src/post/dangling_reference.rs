@@ -0,0 +1,21 @@
+use crate::post::slug::Slug;
+use crate::post::video_slug::VideoSlug;
+use genealogy_java_apis::path::Path;
+use genealogy_java_apis::record;
+use genealogy_java_apis::string::JString;
+
+/// A talk whose `video` slug matches no video, found when the posts are linked,
+/// see [`Corpus::link_talks_and_videos`](crate::post::corpus::Corpus::link_talks_and_videos).
+#[record]
+pub struct DanglingReference {
+	file: Path,
+	talk: Slug,
+	video: VideoSlug,
+}
+
+impl DanglingReference {
+	/// The message of the reference, e.g. for standard error.
+	pub fn describe(&self) -> JString {
+		format!("{}: No video has the videoSlug '{}'.", self.file, self.video.value()).into()
+	}
+}
@@ -1,6 +1,11 @@
 pub mod article_factory;
+pub mod factory_error;
+pub mod front_matter_format;
+mod front_matter_reader;
+pub mod front_matter_value;
 pub mod post_factory;
-mod raw_front_matter;
+pub mod post_factory_service;
+pub mod raw_front_matter;
 pub mod raw_post;
 pub mod talk_factory;
 pub mod video_factory;
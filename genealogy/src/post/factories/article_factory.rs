@@ -1,14 +1,16 @@
 use crate::post::article::Article;
 use crate::post::description::Description;
+use crate::post::factories::factory_error::FactoryError;
+use crate::post::factories::front_matter_reader::FrontMatterReader;
 use crate::post::factories::post_factory::PostFactory;
 use crate::post::factories::raw_post::RawPost;
 use crate::post::repository::Repository;
 use crate::post::slug::Slug;
-use crate::post::tag::Tag;
 use crate::post::title::Title;
 use genealogy_java_apis::exception::Exception;
 use genealogy_java_apis::exception::Exception::RuntimeException;
 use genealogy_java_apis::list::List;
+use genealogy_java_apis::optional::Optional;
 use genealogy_java_apis::path::Path;
 use genealogy_java_apis::string::JString;
 use genealogy_java_apis::time::{LocalDate, LocalDateExtension};
@@ -69,18 +71,52 @@ impl ArticleFactory {
 	///	}
 	/// ```
 	/// Note: The method has been renamed because rust doesn't have any overloading.
-	fn create_article_from_raw_post(post: RawPost) -> Result<Article, Exception> {
-		let front_matter = post.front_matter();
+	/// Fails with the first error of [`ArticleFactory::read_article`].
+	pub(crate) fn create_article_from_raw_post(post: RawPost) -> Result<Article, Exception> {
+		Self::read_article(post).map_err(FactoryError::first)
+	}
+
+	/// Reads every key, so that the errors of all of them are returned, see [`FrontMatterReader`].
+	/// The tags are read with [`PostFactory::tags_from`] to support YAML lists.
+	pub(crate) fn read_article(post: RawPost) -> Result<Article, Vec<FactoryError>> {
+		let mut reader = FrontMatterReader::new(post.front_matter());
+		let metadata = reader.read(Optional::empty(), |front_matter| {
+			PostFactory::metadata_from(front_matter, &Self::read_keys())
+		});
+		let title = reader.required(PostFactory::TITLE(), Title::new);
+		let tags = reader.read(Optional::of(PostFactory::TAGS()), PostFactory::tags_from);
+		let date = reader.required(PostFactory::DATE(), LocalDate::parse);
+		let description = reader.required(PostFactory::DESCRIPTION(), Description::new);
+		let slug = reader.required(PostFactory::SLUG(), Slug::new);
+		let repository = reader.optional(PostFactory::REPOSITORY(), Repository::new);
+		let (Some(title), Some(tags), Some(date), Some(description), Some(slug), Some(repository), Some(metadata)) =
+			(title, tags, date, description, slug, repository, metadata)
+		else {
+			return Err(reader.into_errors());
+		};
 		Ok(Article::new(
-			Title::new(front_matter.required_value_of(PostFactory::TITLE())?)?,
-			Tag::from(front_matter.required_value_of(PostFactory::TAGS())?)?,
-			LocalDate::parse(front_matter.required_value_of(PostFactory::DATE())?)?,
-			Description::new(front_matter.required_value_of(PostFactory::DESCRIPTION())?)?,
-			Slug::new(front_matter.required_value_of(PostFactory::SLUG())?)?,
-			front_matter.value_of(PostFactory::REPOSITORY()).map(Repository::new)?,
+			title,
+			tags,
+			date,
+			description,
+			slug,
+			repository,
 			post.content(),
+			metadata,
 		))
 	}
+
+	/// The front matter keys the article is created from, every other key goes into its [`Metadata`](crate::post::metadata::Metadata).
+	pub(crate) fn read_keys() -> [JString; 6] {
+		[
+			PostFactory::TITLE(),
+			PostFactory::TAGS(),
+			PostFactory::DATE(),
+			PostFactory::DESCRIPTION(),
+			PostFactory::SLUG(),
+			PostFactory::REPOSITORY(),
+		]
+	}
 }
 
 /// ```java
@@ -90,6 +126,8 @@ impl ArticleFactory {
 #[cfg(test)]
 mod test {
 	use super::*;
+	use crate::post::factories::front_matter_value::FrontMatterValue;
+	use crate::post::tag::Tag;
 	use genealogy_java_apis::test::assert_that;
 	use genealogy_java_apis::time::LocalDate;
 	use genealogy_java_apis::time::LocalDateExtension;
@@ -254,4 +292,127 @@ mod test {
 			"Excepteur sint occaecat cupidatat non proident.",
 		]);
 	}
+
+	#[test]
+	fn create_from_front_matter__yaml_block_list_and_escaped_quotes__get_valid_article() {
+		let file = List::of([
+			"---".into(),
+			r#"title: "A \"cool\": blog post""#.into(),
+			"tags:".into(),
+			"  - $TAG".into(),
+			"  - $TIG, $TOG".into(),
+			"date: 2020-01-23".into(),
+			"description: |-".into(),
+			"  Very blog,".into(),
+			"  much post".into(),
+			"slug: cool-blog-post".into(),
+			"---".into(),
+		]);
+
+		let article = ArticleFactory::create_article_from_lines(file).unwrap();
+
+		assert_that(article.title().text()).is_equal_to(r#"A "cool": blog post"#);
+		assert_that(article.tags())
+			.extracting(Tag::text)
+			.contains_exactly_in_any_order(["$TAG", "$TIG, $TOG"]);
+		assert_that(article.description().text()).is_equal_to("Very blog,\nmuch post");
+	}
+
+	#[test]
+	fn create_from_file__toml_front_matter__get_valid_article() {
+		let file = List::of([
+			"+++".into(),
+			r#"title = "A cool blog post""#.into(),
+			r#"tags = ["$TAG", "$TOG"]"#.into(),
+			"date = 2020-01-23".into(),
+			r#"description = "Very blog, much post, so wow""#.into(),
+			r#"slug = "cool-blog-post""#.into(),
+			"+++".into(),
+			"Lorem ipsum dolor sit amet.".into(),
+		]);
+
+		let article = ArticleFactory::create_article_from_lines(file).unwrap();
+
+		assert_that(article.title().text()).is_equal_to("A cool blog post");
+		assert_that(article.tags())
+			.extracting(Tag::text)
+			.contains_exactly_in_any_order(["$TAG", "$TOG"]);
+		assert_that(article.date()).is_equal_to(LocalDate::of(2020, 1, 23));
+		assert_that(article.slug().value()).is_equal_to("cool-blog-post");
+		assert_that(article.content().get().to_list().unwrap()).contains_exactly(["Lorem ipsum dolor sit amet."]);
+	}
+
+	#[test]
+	fn create_from_file__json_front_matter__get_valid_article() {
+		let file = List::of([
+			"{".into(),
+			r#"  "title": "A cool blog post","#.into(),
+			r#"  "tags": ["$TAG", "$TOG"],"#.into(),
+			r#"  "date": "2020-01-23","#.into(),
+			r#"  "description": "Very blog, much post, so wow","#.into(),
+			r#"  "slug": "cool-blog-post""#.into(),
+			"}".into(),
+			"".into(),
+			"Lorem ipsum dolor sit amet.".into(),
+		]);
+
+		let article = ArticleFactory::create_article_from_lines(file).unwrap();
+
+		assert_that(article.title().text()).is_equal_to("A cool blog post");
+		assert_that(article.tags())
+			.extracting(Tag::text)
+			.contains_exactly_in_any_order(["$TAG", "$TOG"]);
+		assert_that(article.date()).is_equal_to(LocalDate::of(2020, 1, 23));
+		assert_that(article.description().text()).is_equal_to("Very blog, much post, so wow");
+		assert_that(article.content().get().to_list().unwrap()).contains_exactly(["", "Lorem ipsum dolor sit amet."]);
+	}
+
+	#[test]
+	fn create_from_front_matter__unknown_keys__kept_as_metadata() {
+		let file = List::of([
+			"---".into(),
+			"title: A cool blog post".into(),
+			"tags: [$TAG, $TOG]".into(),
+			"date: 2020-01-23".into(),
+			r#"description: "Very blog, much post, so wow""#.into(),
+			"slug: cool-blog-post".into(),
+			r#"searchKeywords: "blog post""#.into(),
+			"featuredImage: cool-image".into(),
+			"intro: true".into(),
+			"---".into(),
+		]);
+
+		let article = ArticleFactory::create_article_from_lines(file).unwrap();
+
+		let metadata = article.metadata();
+		assert_that(metadata.keys()).contains_exactly(["featuredImage", "intro", "searchKeywords"]);
+		assert_that(metadata.search_keywords()).contains_exactly(["blog post"]);
+		assert_that(metadata.featured_image().get().unwrap()).is_equal_to("cool-image");
+		assert_that(metadata.get("intro".into()).get().unwrap()).is_equal_to(FrontMatterValue::Bool(true));
+	}
+
+	#[test]
+	fn read_article__invalid_and_missing_keys__errors_of_all_keys_in_order() {
+		let file = List::of([
+			"---".into(),
+			"title: A cool blog post".into(),
+			"tags: [$TAG, $TOG]".into(),
+			"date: 2020-13-45".into(),
+			"slug: cool-blog-post".into(),
+			"repo: \" \"".into(),
+			"---".into(),
+		]);
+
+		let errors = ArticleFactory::read_article(PostFactory::read_post(file.clone()).unwrap()).unwrap_err();
+
+		let keys = errors
+			.iter()
+			.map(|error| error.key().get().unwrap())
+			.collect::<Vec<_>>();
+		assert_that(keys).contains_exactly(["date", "description", "repo"]);
+		assert!(matches!(
+			ArticleFactory::create_article_from_lines(file),
+			Err(Exception::DateTimeException(_))
+		));
+	}
 }
@@ -0,0 +1,39 @@
+use genealogy_java_apis::exception::Exception;
+use genealogy_java_apis::optional::Optional;
+use genealogy_java_apis::string::JString;
+
+/// An error of a factory, with the front matter key whose value caused it if the error is about a single key.
+#[derive(Debug)]
+pub struct FactoryError {
+	key: Optional<JString>,
+	exception: Exception,
+}
+
+impl FactoryError {
+	pub fn new(key: Optional<JString>, exception: Exception) -> Self {
+		Self { key, exception }
+	}
+
+	pub fn key(&self) -> Optional<JString> {
+		self.key.clone()
+	}
+
+	pub fn exception(&self) -> &Exception {
+		&self.exception
+	}
+
+	/// The exception of the first error, which is the one a factory that fails on the first error would throw.
+	pub(crate) fn first(errors: Vec<FactoryError>) -> Exception {
+		errors
+			.into_iter()
+			.next()
+			.map(|error| error.exception)
+			.expect("Reading a post only fails with at least one error.")
+	}
+}
+
+impl From<Exception> for FactoryError {
+	fn from(exception: Exception) -> Self {
+		Self::new(Optional::empty(), exception)
+	}
+}
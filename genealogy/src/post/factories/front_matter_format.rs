@@ -0,0 +1,44 @@
+use crate::post::factories::post_factory::PostFactory;
+use genealogy_java_apis::string::JString;
+use std::fmt::{Display, Formatter};
+
+/// The formats the front matter of a post can be written in, detected on the first line of its file:
+/// * YAML between lines containing only `---`
+/// * TOML between lines containing only `+++`
+/// * JSON as one object at the start of the file
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FrontMatterFormat {
+	Yaml,
+	Toml,
+	Json,
+}
+
+impl FrontMatterFormat {
+	/// Files whose first line fits no format are read as YAML, which like the Java version
+	/// skips every line before the first `---`.
+	pub fn detect(first_line: &str) -> FrontMatterFormat {
+		let first_line = first_line.trim();
+		if first_line == PostFactory::TOML_FRONT_MATTER_SEPARATOR() {
+			FrontMatterFormat::Toml
+		} else if first_line.starts_with('{') {
+			FrontMatterFormat::Json
+		} else {
+			FrontMatterFormat::Yaml
+		}
+	}
+
+	pub fn name(&self) -> JString {
+		match self {
+			FrontMatterFormat::Yaml => "YAML",
+			FrontMatterFormat::Toml => "TOML",
+			FrontMatterFormat::Json => "JSON",
+		}
+		.into()
+	}
+}
+
+impl Display for FrontMatterFormat {
+	fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+		write!(formatter, "{}", self.name())
+	}
+}
@@ -0,0 +1,64 @@
+use crate::post::factories::factory_error::FactoryError;
+use crate::post::factories::raw_front_matter::RawFrontMatter;
+use genealogy_java_apis::exception::Exception;
+use genealogy_java_apis::optional::Optional;
+use genealogy_java_apis::string::JString;
+
+/// Reads the values of the front matter for a factory. Instead of failing on the first key that is missing
+/// or has an invalid value, it reads every key and collects the errors, so that all of them can be reported,
+/// see [`Validator`](crate::validation::validator::Validator).
+pub(super) struct FrontMatterReader<'a> {
+	front_matter: &'a RawFrontMatter,
+	errors: Vec<FactoryError>,
+}
+
+impl<'a> FrontMatterReader<'a> {
+	pub(super) fn new(front_matter: &'a RawFrontMatter) -> Self {
+		Self {
+			front_matter,
+			errors: Vec::new(),
+		}
+	}
+
+	/// The value of `key` created with `create`, see [`RawFrontMatter::required_value_of`].
+	pub(super) fn required<T>(
+		&mut self,
+		key: JString,
+		create: impl FnOnce(JString) -> Result<T, Exception>,
+	) -> Option<T> {
+		self.read(Optional::of(key.clone()), |front_matter| {
+			create(front_matter.required_value_of(key)?)
+		})
+	}
+
+	/// The value of `key` created with `create` if the front matter has one, see [`RawFrontMatter::value_of`].
+	pub(super) fn optional<T>(
+		&mut self,
+		key: JString,
+		create: impl FnOnce(JString) -> Result<T, Exception>,
+	) -> Option<Optional<T>> {
+		self.read(Optional::of(key.clone()), |front_matter| {
+			front_matter.value_of(key).map(create)
+		})
+	}
+
+	/// The result of `read`, or `None` if it failed, in which case its error is collected for `key`.
+	pub(super) fn read<T>(
+		&mut self,
+		key: Optional<JString>,
+		read: impl FnOnce(&RawFrontMatter) -> Result<T, Exception>,
+	) -> Option<T> {
+		match read(self.front_matter) {
+			Ok(value) => Some(value),
+			Err(exception) => {
+				self.errors.push(FactoryError::new(key, exception));
+				None
+			}
+		}
+	}
+
+	/// The errors in the order the keys were read.
+	pub(super) fn into_errors(self) -> Vec<FactoryError> {
+		self.errors
+	}
+}
@@ -0,0 +1,186 @@
+use genealogy_java_apis::exception::Exception;
+use genealogy_java_apis::exception::Exception::IllegalArgumentException;
+use genealogy_java_apis::list::List;
+use genealogy_java_apis::string::JString;
+use genealogy_java_apis::throw;
+use genealogy_java_apis::time::{LocalDate, LocalDateExtension};
+use serde::{Serialize, Serializer};
+use serde_yaml::Value;
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+
+/// A value in the front matter of a post, see [`FrontMatterFormat`](super::front_matter_format::FrontMatterFormat).
+///
+/// Numbers are kept as strings, `null` values are left out. Strings of the form `yyyy-MM-dd` are dates,
+/// like TOML dates without a time.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FrontMatterValue {
+	String(JString),
+	List(List<FrontMatterValue>),
+	Date(LocalDate),
+	Bool(bool),
+	Map(BTreeMap<JString, FrontMatterValue>),
+}
+
+impl FrontMatterValue {
+	/// `None` for `null`.
+	pub(super) fn from_yaml(value: Value) -> Result<Option<FrontMatterValue>, Exception> {
+		let value = match value {
+			Value::Null => return Ok(None),
+			Value::Bool(bool) => FrontMatterValue::Bool(bool),
+			Value::Number(number) => FrontMatterValue::String(number.to_string().into()),
+			Value::String(string) => Self::from_text(string.into()),
+			Value::Sequence(values) => {
+				let mut list = Vec::new();
+				for value in values {
+					list.extend(Self::from_yaml(value)?);
+				}
+				FrontMatterValue::List(List::of(list))
+			}
+			Value::Mapping(mapping) => {
+				let mut map = BTreeMap::new();
+				for (key, value) in mapping {
+					if let Some(value) = Self::from_yaml(value)? {
+						map.insert(Self::key_from_yaml(key)?, value);
+					}
+				}
+				FrontMatterValue::Map(map)
+			}
+			Value::Tagged(tagged) => return Self::from_yaml(tagged.value),
+		};
+		Ok(Some(value))
+	}
+
+	/// `None` for `null`.
+	pub(super) fn from_json(value: serde_json::Value) -> Option<FrontMatterValue> {
+		use serde_json::Value;
+		let value = match value {
+			Value::Null => return None,
+			Value::Bool(bool) => FrontMatterValue::Bool(bool),
+			Value::Number(number) => FrontMatterValue::String(number.to_string().into()),
+			Value::String(string) => Self::from_text(string.into()),
+			Value::Array(values) => FrontMatterValue::List(List::of(values.into_iter().filter_map(Self::from_json))),
+			Value::Object(object) => FrontMatterValue::Map(
+				object
+					.into_iter()
+					.filter_map(|(key, value)| Some((key.into(), Self::from_json(value)?)))
+					.collect(),
+			),
+		};
+		Some(value)
+	}
+
+	/// Date-times with a time are kept as strings in TOML's format.
+	pub(super) fn from_toml(value: toml::Value) -> FrontMatterValue {
+		use toml::Value;
+		match value {
+			Value::String(string) => Self::from_text(string.into()),
+			Value::Integer(integer) => FrontMatterValue::String(integer.to_string().into()),
+			Value::Float(float) => FrontMatterValue::String(float.to_string().into()),
+			Value::Boolean(bool) => FrontMatterValue::Bool(bool),
+			Value::Datetime(datetime) => Self::from_text(datetime.to_string().into()),
+			Value::Array(values) => FrontMatterValue::List(List::of(values.into_iter().map(Self::from_toml))),
+			Value::Table(table) => FrontMatterValue::Map(
+				table
+					.into_iter()
+					.map(|(key, value)| (key.into(), Self::from_toml(value)))
+					.collect(),
+			),
+		}
+	}
+
+	/// A date if the text is of the form `yyyy-MM-dd`, otherwise a string.
+	pub(super) fn from_text(text: JString) -> FrontMatterValue {
+		match LocalDate::parse(text.clone()) {
+			Ok(date) => FrontMatterValue::Date(date),
+			Err(_) => FrontMatterValue::String(text),
+		}
+	}
+
+	pub(super) fn key_from_yaml(key: Value) -> Result<JString, Exception> {
+		match key {
+			Value::String(key) => Ok(key.into()),
+			Value::Number(key) => Ok(key.to_string().into()),
+			Value::Bool(key) => Ok(key.to_string().into()),
+			key => throw!(IllegalArgumentException(
+				format!("Keys must be strings, numbers or booleans: {:?}", key).into()
+			)),
+		}
+	}
+
+	/// The value as text like it was written before the front matter was parsed: lists as `[a, b]`,
+	/// maps as `{key: value}` and dates as `yyyy-MM-dd`.
+	pub fn text(&self) -> JString {
+		self.to_string().into()
+	}
+
+	pub fn as_list(&self) -> Result<List<FrontMatterValue>, Exception> {
+		match self {
+			FrontMatterValue::List(list) => Ok(list.clone()),
+			value => throw!(IllegalArgumentException(format!("Expected a list: {}", value).into())),
+		}
+	}
+
+	pub fn as_date(&self) -> Result<LocalDate, Exception> {
+		match self {
+			FrontMatterValue::Date(date) => Ok(*date),
+			value => throw!(IllegalArgumentException(format!("Expected a date: {}", value).into())),
+		}
+	}
+
+	pub fn as_bool(&self) -> Result<bool, Exception> {
+		match self {
+			FrontMatterValue::Bool(bool) => Ok(*bool),
+			value => throw!(IllegalArgumentException(
+				format!("Expected true or false: {}", value).into()
+			)),
+		}
+	}
+
+	pub fn as_map(&self) -> Result<BTreeMap<JString, FrontMatterValue>, Exception> {
+		match self {
+			FrontMatterValue::Map(map) => Ok(map.clone()),
+			value => throw!(IllegalArgumentException(format!("Expected a map: {}", value).into())),
+		}
+	}
+}
+
+impl Display for FrontMatterValue {
+	fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+		match self {
+			FrontMatterValue::String(string) => write!(formatter, "{}", string),
+			FrontMatterValue::Date(date) => write!(formatter, "{}", date.format("%Y-%m-%d")),
+			FrontMatterValue::Bool(bool) => write!(formatter, "{}", bool),
+			FrontMatterValue::List(list) => {
+				let values = list
+					.clone()
+					.into_iter()
+					.map(|value| value.to_string())
+					.collect::<Vec<_>>();
+				write!(formatter, "[{}]", values.join(", "))
+			}
+			FrontMatterValue::Map(map) => {
+				let entries = map
+					.iter()
+					.map(|(key, value)| format!("{}: {}", key, value))
+					.collect::<Vec<_>>();
+				write!(formatter, "{{{}}}", entries.join(", "))
+			}
+		}
+	}
+}
+
+/// Dates as `yyyy-MM-dd`.
+impl Serialize for FrontMatterValue {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		match self {
+			FrontMatterValue::String(string) => serializer.serialize_str(string.as_ref()),
+			FrontMatterValue::Date(_) => serializer.serialize_str(self.text().as_ref()),
+			FrontMatterValue::Bool(bool) => serializer.serialize_bool(*bool),
+			FrontMatterValue::List(list) => serializer.collect_seq(list.clone()),
+			FrontMatterValue::Map(map) => {
+				serializer.collect_map(map.iter().map(|(key, value)| (key.to_string(), value)))
+			}
+		}
+	}
+}
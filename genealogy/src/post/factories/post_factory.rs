@@ -1,11 +1,17 @@
+use crate::post::factories::front_matter_format::FrontMatterFormat;
+use crate::post::factories::front_matter_value::FrontMatterValue;
 use crate::post::factories::raw_front_matter::RawFrontMatter;
 use crate::post::factories::raw_post::RawPost;
+use crate::post::metadata::Metadata;
+use crate::post::slug::Slug;
+use crate::post::tag::Tag;
 use crate::utils::Utils;
 use genealogy_java_apis::collector::Collectors;
 use genealogy_java_apis::exception::Exception;
 use genealogy_java_apis::exception::Exception::{IllegalArgumentException, RuntimeException};
 use genealogy_java_apis::list::List;
 use genealogy_java_apis::path::Path;
+use genealogy_java_apis::set::Set;
 use genealogy_java_apis::stream::Stream;
 use genealogy_java_apis::string::JString;
 use genealogy_java_apis::{r#static, record, throw};
@@ -17,7 +23,7 @@ use genealogy_java_apis::{r#static, record, throw};
 ///		}
 /// ```
 /// The empty enum has the same effect as a private constructor, preventing instantiation.
-pub(super) enum PostFactory {}
+pub(crate) enum PostFactory {}
 
 impl PostFactory {
 	// ```java
@@ -63,7 +69,9 @@ impl PostFactory {
 	// ```java
 	//	private static final String FRONT_MATTER_SEPARATOR = "---";
 	// ```
-	r#static!(FRONT_MATTER_SEPARATOR: JString = "---".into());
+	r#static!(pub(crate) FRONT_MATTER_SEPARATOR: JString = "---".into());
+
+	r#static!(pub(crate) TOML_FRONT_MATTER_SEPARATOR: JString = "+++".into());
 
 	/// ```java
 	/// public static RawPost readPost(Path file) {
@@ -75,12 +83,13 @@ impl PostFactory {
 	///		}
 	///	}
 	/// ````
-	/// Note: Different name in rust because overloading isn't possible
-	pub fn read_post_from_path(file: Path) -> Result<RawPost, Exception> {
+	/// Note: Different name in rust because overloading isn't possible.
+	/// Posts without a slug get the one derived from the name of their file, see [`PostFactory::read_post_of_file`].
+	pub(crate) fn read_post_from_path(file: Path) -> Result<RawPost, Exception> {
 		// simulated try-catch block
 		(|| {
 			let eager_lines = Utils::unchecked_files_read_all_lines(file.clone())?;
-			Self::read_post(eager_lines)
+			Self::read_post_of_file(&file, eager_lines)
 		})()
 		.map_err(|exception| RuntimeException("Creating article failed: " + file, exception.into()))
 	}
@@ -92,12 +101,65 @@ impl PostFactory {
 	///		return new RawPost(frontMatter, content);
 	///	}
 	/// ```
-	pub fn read_post(file_lines: List<JString>) -> Result<RawPost, Exception> {
+	pub(crate) fn read_post(file_lines: List<JString>) -> Result<RawPost, Exception> {
 		let front_matter = Self::extract_front_matter(file_lines.clone())?;
 		let content = move || Self::extract_content(file_lines.clone());
 		Ok(RawPost::new(front_matter, content.into()))
 	}
 
+	/// Like [`PostFactory::read_post`], but if the front matter has no slug, it gets the one derived
+	/// from the name of the file, see [`Slug::from_file_name`].
+	pub(crate) fn read_post_of_file(file: &Path, file_lines: List<JString>) -> Result<RawPost, Exception> {
+		let post = Self::read_post(file_lines)?;
+		if post.front_matter().typed_value_of(Self::SLUG()).is_present() {
+			return Ok(post);
+		}
+		let slug = Slug::from_file_name(file)?;
+		Ok(post.with_default_value(Self::SLUG(), FrontMatterValue::String(slug.value())))
+	}
+
+	/// Note: Split into reading the front matter and parsing it, see [`PostFactory::parse_front_matter`].
+	fn extract_front_matter(file_lines: List<JString>) -> Result<RawFrontMatter, Exception> {
+		let format = Self::front_matter_format(&file_lines);
+		Self::parse_front_matter(format, Self::read_front_matter(file_lines).to_list()?)
+	}
+
+	/// The format of the front matter, detected on the first line of the file.
+	pub(crate) fn front_matter_format(markdown_file: &List<JString>) -> FrontMatterFormat {
+		markdown_file
+			.clone()
+			.into_iter()
+			.next()
+			.map(|line| FrontMatterFormat::detect(line.as_ref()))
+			.unwrap_or(FrontMatterFormat::Yaml)
+	}
+
+	/// Parses the lines of the front matter in the given format, so the factories work the same on every format.
+	///
+	/// If parsing YAML fails, the lines are parsed line by line like in the Java version, so that e.g.
+	/// unquoted values with colons keep working. If that fails as well, the error of the YAML parser is reported.
+	pub(crate) fn parse_front_matter(
+		format: FrontMatterFormat,
+		front_matter_lines: List<JString>,
+	) -> Result<RawFrontMatter, Exception> {
+		let text = Self::text_of(&front_matter_lines);
+		match format {
+			FrontMatterFormat::Yaml => RawFrontMatter::parse(&text)
+				.or_else(|exception| Self::parse_front_matter_line_by_line(front_matter_lines).map_err(|_| exception)),
+			FrontMatterFormat::Toml => RawFrontMatter::parse_toml(&text),
+			FrontMatterFormat::Json => RawFrontMatter::parse_json(&text),
+		}
+	}
+
+	fn text_of(lines: &List<JString>) -> String {
+		lines
+			.clone()
+			.into_iter()
+			.map(|line| line.to_string())
+			.collect::<Vec<_>>()
+			.join("\n")
+	}
+
 	/// ```java
 	/// private static RawFrontMatter extractFrontMatter(List<String> fileLines) {
 	///		Map<String, String> frontMatter = readFrontMatter(fileLines)
@@ -107,11 +169,16 @@ impl PostFactory {
 	///		return new RawFrontMatter(frontMatter);
 	///	}
 	/// ```
-	fn extract_front_matter(file_lines: List<JString>) -> Result<RawFrontMatter, Exception> {
-		let front_matter = Self::read_front_matter(file_lines)
+	/// Note: Takes the lines of the front matter instead of the file, the values are strings or dates.
+	fn parse_front_matter_line_by_line(front_matter_lines: List<JString>) -> Result<RawFrontMatter, Exception> {
+		let front_matter = front_matter_lines
+			.stream()
+			.map(|line| Ok(line.strip()))
 			.filter(|line| !line.starts_with("#"))
 			.map(PostFactory::key_value_pair_from)
-			.collect(Collectors::to_map(FrontMatterLine::key, FrontMatterLine::value))?;
+			.collect(Collectors::to_map(FrontMatterLine::key, |line: &FrontMatterLine| {
+				FrontMatterValue::from_text(line.value())
+			}))?;
 		Ok(RawFrontMatter::new(front_matter))
 	}
 
@@ -124,13 +191,55 @@ impl PostFactory {
 	///				.takeWhile(not(FRONT_MATTER_SEPARATOR::equals));
 	///	}
 	/// ```
-	fn read_front_matter(markdown_file: List<JString>) -> Stream<JString> {
-		markdown_file
-			.stream()
-			.map(|string| Ok(string.strip()))
-			.drop_while(|string| string != Self::FRONT_MATTER_SEPARATOR())
-			.skip(1)
-			.take_while(|string| string != Self::FRONT_MATTER_SEPARATOR())
+	/// Note: Only the separators are compared stripped, the lines are kept as they are
+	/// because indentation is significant in YAML. TOML front matter is read from the first line on
+	/// and JSON front matter consists of the lines of the JSON object, see [`FrontMatterFormat`].
+	pub(crate) fn read_front_matter(markdown_file: List<JString>) -> Stream<JString> {
+		match Self::front_matter_format(&markdown_file) {
+			FrontMatterFormat::Yaml => markdown_file
+				.stream()
+				.drop_while(|line| line.strip() != Self::FRONT_MATTER_SEPARATOR())
+				.skip(1)
+				.take_while(|line| line.strip() != Self::FRONT_MATTER_SEPARATOR()),
+			FrontMatterFormat::Toml => markdown_file
+				.stream()
+				.skip(1)
+				.take_while(|line| line.strip() != Self::TOML_FRONT_MATTER_SEPARATOR()),
+			FrontMatterFormat::Json => {
+				let length = Self::json_front_matter_length(&markdown_file);
+				Stream::of(markdown_file.into_iter().take(length))
+			}
+		}
+	}
+
+	/// The number of lines up to and including the one that closes the JSON object at the start of the file.
+	/// If the JSON is invalid, that's every line, so the parser reports the error.
+	fn json_front_matter_length(markdown_file: &List<JString>) -> usize {
+		let text = Self::text_of(markdown_file);
+		let mut values = serde_json::Deserializer::from_str(&text).into_iter::<serde::de::IgnoredAny>();
+		match values.next() {
+			Some(Ok(_)) => text[..values.byte_offset()].matches('\n').count() + 1,
+			_ => markdown_file.length(),
+		}
+	}
+
+	/// Tags given as a YAML list are taken as they are, so they can contain commas,
+	/// everything else is parsed with [`Tag::from`] like in the Java version.
+	pub(super) fn tags_from(front_matter: &RawFrontMatter) -> Result<Set<Tag>, Exception> {
+		let tags_text = front_matter.required_value_of(Self::TAGS())?;
+		match front_matter.typed_value_of(Self::TAGS()).get()? {
+			FrontMatterValue::List(values) => Stream::of(values)
+				.map(|value| Ok(value.text().strip()))
+				.filter(|text| !text.is_blank())
+				.map(|text| Ok(Tag::new(text)))
+				.collect(Collectors::to_unmodifiable_set()),
+			_ => Tag::from(tags_text),
+		}
+	}
+
+	/// The keys the factory didn't read, see [`Metadata`].
+	pub(super) fn metadata_from(front_matter: &RawFrontMatter, read_keys: &[JString]) -> Result<Metadata, Exception> {
+		Metadata::new(front_matter.values_except(read_keys)?)
 	}
 
 	/// ```java
@@ -171,13 +280,25 @@ impl PostFactory {
 	///				.skip(1);
 	///	}
 	/// ```
+	/// Note: The content of TOML and JSON front matter starts after the front matter as well.
 	fn extract_content(markdown_file: List<JString>) -> Stream<JString> {
-		markdown_file
-			.stream()
-			.drop_while(|line| line.strip() != Self::FRONT_MATTER_SEPARATOR())
-			.skip(1)
-			.drop_while(|line| line.strip() != Self::FRONT_MATTER_SEPARATOR())
-			.skip(1)
+		match Self::front_matter_format(&markdown_file) {
+			FrontMatterFormat::Yaml => markdown_file
+				.stream()
+				.drop_while(|line| line.strip() != Self::FRONT_MATTER_SEPARATOR())
+				.skip(1)
+				.drop_while(|line| line.strip() != Self::FRONT_MATTER_SEPARATOR())
+				.skip(1),
+			FrontMatterFormat::Toml => markdown_file
+				.stream()
+				.skip(1)
+				.drop_while(|line| line.strip() != Self::TOML_FRONT_MATTER_SEPARATOR())
+				.skip(1),
+			FrontMatterFormat::Json => {
+				let length = Self::json_front_matter_length(&markdown_file);
+				markdown_file.stream().skip(length)
+			}
+		}
 	}
 }
 
@@ -0,0 +1,228 @@
+use crate::post::factories::post_factory::PostFactory;
+use crate::post::factories::raw_post::RawPost;
+use crate::post::Post;
+use genealogy_java_apis::exception::Exception;
+use genealogy_java_apis::exception::Exception::RuntimeException;
+use genealogy_java_apis::list::List;
+use genealogy_java_apis::path::Path;
+use genealogy_java_apis::string::JString;
+use std::cmp::Ordering;
+use std::fmt::{Debug, Display, Formatter};
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+
+/// Type erased wrapper of [`PostFactoryServiceTrait`], see [`GenealogistService`](crate::genealogist::genealogist_service::GenealogistService).
+///
+/// Two services are equal if they create the same kind of post.
+#[derive(Clone)]
+pub struct PostFactoryService {
+	post_factory_service: Arc<dyn PostFactoryServiceTrait + Send + Sync>,
+}
+
+impl PostFactoryService {
+	/// Name of the kind of post, see [`Post::kind`].
+	pub fn kind(&self) -> JString {
+		self.post_factory_service.kind()
+	}
+
+	pub(crate) fn create_post(&self, file: Path) -> Result<Post, Exception> {
+		// simulated try-catch block
+		(|| {
+			let post = PostFactory::read_post_from_path(file.clone())?;
+			self.post_factory_service.create_post(post)
+		})()
+		.map_err(|exception| {
+			RuntimeException(
+				format!("Creating {} failed: {}", self.kind(), file).into(),
+				exception.into(),
+			)
+		})
+	}
+
+	pub(crate) fn create_post_from_lines(&self, file_lines: List<JString>) -> Result<Post, Exception> {
+		self.post_factory_service
+			.create_post(PostFactory::read_post(file_lines)?)
+	}
+
+	pub(crate) fn create_post_from_raw_post(&self, post: RawPost) -> Result<Post, Exception> {
+		self.post_factory_service.create_post(post)
+	}
+}
+
+/// Creates the posts of a kind that isn't built in, e.g. podcasts, from the files of the content folders
+/// whose `type` is [`PostFactoryServiceTrait::kind`].
+///
+/// Services are registered with the [`ServiceLoader`](genealogy_java_apis::service_loader::ServiceLoader),
+/// like [`GenealogistService`](crate::genealogist::genealogist_service::GenealogistService)s.
+/// The posts are usually [`Post::Other`] with a type that implements [`PostTrait`](crate::post::PostTrait).
+pub trait PostFactoryServiceTrait {
+	/// Name of the kind of post, as in the config and [`Post::kind`].
+	fn kind(&self) -> JString;
+
+	/// Creates a post from the front matter and the content of a file, which can be in every format
+	/// of [`FrontMatterFormat`](crate::post::factories::front_matter_format::FrontMatterFormat).
+	fn create_post(&self, post: RawPost) -> Result<Post, Exception>;
+}
+
+/// Helper to create instances of the type erased wrapper.
+impl<PostFactoryServiceType> From<PostFactoryServiceType> for PostFactoryService
+where
+	PostFactoryServiceType: PostFactoryServiceTrait + Send + Sync + 'static,
+{
+	fn from(post_factory_service: PostFactoryServiceType) -> Self {
+		Self {
+			post_factory_service: Arc::new(post_factory_service),
+		}
+	}
+}
+
+impl Debug for PostFactoryService {
+	fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+		formatter
+			.debug_struct("PostFactoryService")
+			.field("kind", &self.kind())
+			.finish()
+	}
+}
+
+impl Display for PostFactoryService {
+	fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+		write!(formatter, "{}", self.kind())
+	}
+}
+
+impl PartialEq for PostFactoryService {
+	fn eq(&self, other: &Self) -> bool {
+		self.kind() == other.kind()
+	}
+}
+
+impl Eq for PostFactoryService {}
+
+impl Hash for PostFactoryService {
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		self.kind().hash(state)
+	}
+}
+
+impl PartialOrd for PostFactoryService {
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+		Some(self.cmp(other))
+	}
+}
+
+impl Ord for PostFactoryService {
+	fn cmp(&self, other: &Self) -> Ordering {
+		self.kind().cmp(&other.kind())
+	}
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::post::description::Description;
+	use crate::post::metadata::Metadata;
+	use crate::post::post_type::PostType;
+	use crate::post::slug::Slug;
+	use crate::post::tag::Tag;
+	use crate::post::title::Title;
+	use crate::post::PostTrait;
+	use genealogy_java_apis::set::Set;
+	use genealogy_java_apis::test::assert_that;
+	use genealogy_java_apis::time::{LocalDate, LocalDateExtension};
+	use std::rc::Rc;
+
+	#[derive(Debug)]
+	struct Podcast {
+		title: Title,
+		tags: Set<Tag>,
+		date: LocalDate,
+		description: Description,
+		slug: Slug,
+		episode: JString,
+	}
+
+	impl PostTrait for Podcast {
+		fn title(&self) -> Title {
+			self.title.clone()
+		}
+
+		fn tags(&self) -> Set<Tag> {
+			self.tags.clone()
+		}
+
+		fn date(&self) -> LocalDate {
+			self.date
+		}
+
+		fn description(&self) -> Description {
+			self.description.clone()
+		}
+
+		fn slug(&self) -> Slug {
+			self.slug.clone()
+		}
+
+		fn metadata(&self) -> Metadata {
+			Metadata::empty()
+		}
+
+		fn kind(&self) -> JString {
+			"podcast".into()
+		}
+	}
+
+	impl Display for Podcast {
+		fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+			write!(
+				formatter,
+				"Podcast[slug={}, episode={}]",
+				self.slug.value(),
+				self.episode
+			)
+		}
+	}
+
+	struct PodcastFactoryService;
+
+	impl PostFactoryServiceTrait for PodcastFactoryService {
+		fn kind(&self) -> JString {
+			"podcast".into()
+		}
+
+		fn create_post(&self, post: RawPost) -> Result<Post, Exception> {
+			let front_matter = post.front_matter();
+			Ok(Post::Other(Rc::new(Podcast {
+				title: Title::new(front_matter.required_value_of(PostFactory::TITLE())?)?,
+				tags: Tag::from(front_matter.required_value_of(PostFactory::TAGS())?)?,
+				date: LocalDate::parse(front_matter.required_value_of(PostFactory::DATE())?)?,
+				description: Description::new(front_matter.required_value_of(PostFactory::DESCRIPTION())?)?,
+				slug: Slug::new(front_matter.required_value_of(PostFactory::SLUG())?)?,
+				episode: front_matter.required_value_of("episode".into())?,
+			})))
+		}
+	}
+
+	#[test]
+	fn other_kind__creates_posts() {
+		let file = List::of([
+			"---".into(),
+			"title: Episode 1".into(),
+			"tags: [java]".into(),
+			"date: 2021-02-11".into(),
+			"description: \"The first episode\"".into(),
+			"slug: episode-1".into(),
+			"episode: 1".into(),
+			"---".into(),
+		]);
+
+		let post_type = PostType::Other(PostFactoryService::from(PodcastFactoryService));
+		let post = post_type.create_post_from_lines(file).unwrap();
+
+		assert_that(post_type.name()).is_equal_to(JString::from("podcast"));
+		assert_that(post.kind()).is_equal_to(JString::from("podcast"));
+		assert_that(post.date()).is_equal_to(LocalDate::of(2021, 2, 11));
+		assert_that(post.to_string()).is_equal_to("Podcast[slug=episode-1, episode=1]".to_string());
+	}
+}
@@ -1,17 +1,26 @@
+use crate::post::factories::front_matter_value::FrontMatterValue;
+use crate::utils::Utils;
 use genealogy_java_apis::exception::Exception;
 use genealogy_java_apis::exception::Exception::IllegalArgumentException;
+use genealogy_java_apis::list::List;
 use genealogy_java_apis::map::Map;
 use genealogy_java_apis::optional::Optional;
 use genealogy_java_apis::string::JString;
+use genealogy_java_apis::throw;
+use serde_yaml::Value;
+use std::collections::BTreeMap;
 
 /// ```java
 /// class RawFrontMatter {
 ///
 /// 	private final Map<String, String> lines;
 /// ```
+/// Note: The front matter is parsed as YAML, TOML or JSON, so the values are typed, see [`FrontMatterValue`].
 #[derive(Debug)]
-pub(super) struct RawFrontMatter {
-	lines: Map<JString, JString>,
+pub struct RawFrontMatter {
+	lines: Map<JString, FrontMatterValue>,
+	/// Whether the values were parsed by a parser like YAML's, which removes the quotation marks of quoted values.
+	is_parsed: bool,
 }
 
 impl RawFrontMatter {
@@ -20,8 +29,99 @@ impl RawFrontMatter {
 	///		this.lines = lines;
 	///	}
 	/// ```
-	pub(super) fn new(lines: Map<JString, JString>) -> Self {
-		Self { lines }
+	pub(super) fn new(lines: Map<JString, FrontMatterValue>) -> Self {
+		Self {
+			lines,
+			is_parsed: false,
+		}
+	}
+
+	/// Parses the YAML between the front matter separators. The error messages contain the line
+	/// within the front matter, starting at 1.
+	pub(crate) fn parse(yaml: &str) -> Result<RawFrontMatter, Exception> {
+		let value = serde_yaml::from_str::<Value>(yaml)
+			.map_err(|error| IllegalArgumentException(format!("Invalid YAML in front matter: {}", error).into()))?;
+		let mapping = match value {
+			Value::Null => Default::default(),
+			Value::Mapping(mapping) => mapping,
+			_ => throw!(IllegalArgumentException(
+				"Front matter must consist of keys with values.".into()
+			)),
+		};
+
+		let mut lines = Vec::new();
+		for (key, value) in mapping {
+			let key = FrontMatterValue::key_from_yaml(key)?;
+			if let Some(value) = FrontMatterValue::from_yaml(value)? {
+				lines.push((key, value));
+			}
+		}
+		Ok(Self::parsed(lines))
+	}
+
+	/// Parses the TOML between the `+++` separators.
+	pub(crate) fn parse_toml(toml: &str) -> Result<RawFrontMatter, Exception> {
+		let table = toml.parse::<toml::Table>().map_err(|error| {
+			IllegalArgumentException(format!("Invalid TOML in front matter: {}", error.to_string().trim_end()).into())
+		})?;
+		Ok(Self::parsed(
+			table
+				.into_iter()
+				.map(|(key, value)| (key.into(), FrontMatterValue::from_toml(value))),
+		))
+	}
+
+	/// Parses the JSON object at the start of the file.
+	pub(crate) fn parse_json(json: &str) -> Result<RawFrontMatter, Exception> {
+		let value = serde_json::from_str::<serde_json::Value>(json)
+			.map_err(|error| IllegalArgumentException(format!("Invalid JSON in front matter: {}", error).into()))?;
+		let serde_json::Value::Object(object) = value else {
+			throw!(IllegalArgumentException(
+				"Front matter must consist of keys with values.".into()
+			));
+		};
+		Ok(Self::parsed(object.into_iter().filter_map(|(key, value)| {
+			Some((key.into(), FrontMatterValue::from_json(value)?))
+		})))
+	}
+
+	fn parsed(lines: impl IntoIterator<Item = (JString, FrontMatterValue)>) -> Self {
+		Self {
+			lines: Map::of(lines),
+			is_parsed: true,
+		}
+	}
+
+	/// The values of all keys but `keys`. Like in [`RawFrontMatter::value_of`], the quotation marks of strings
+	/// are removed, which the Java version left to the types of the values.
+	pub fn values_except(&self, keys: &[JString]) -> Result<BTreeMap<JString, FrontMatterValue>, Exception> {
+		let mut values = BTreeMap::new();
+		for entry in self.lines.clone().entry_set() {
+			if keys.contains(&entry.get_key()) {
+				continue;
+			}
+			let value = match entry.get_value() {
+				FrontMatterValue::String(string) if !self.is_parsed => {
+					FrontMatterValue::String(Utils::remove_outer_quotation_marks(string)?)
+				}
+				value => value,
+			};
+			values.insert(entry.get_key(), value);
+		}
+		Ok(values)
+	}
+
+	/// The keys of the front matter, sorted.
+	pub fn keys(&self) -> List<JString> {
+		let mut keys = self
+			.lines
+			.clone()
+			.entry_set()
+			.into_iter()
+			.map(|entry| entry.get_key())
+			.collect::<Vec<_>>();
+		keys.sort();
+		List::of(keys)
 	}
 
 	/// ```java
@@ -29,8 +129,22 @@ impl RawFrontMatter {
 	///		return Optional.ofNullable(lines.get(key));
 	///	}
 	/// ```
+	/// Note: Returns the value as text, see [`FrontMatterValue::text`]. The Java version returned quoted values
+	/// with their quotation marks, which e.g. [`Title`](crate::post::title::Title) removes with
+	/// [`Utils::remove_outer_quotation_marks`](crate::utils::Utils::remove_outer_quotation_marks).
+	/// If the parser already removed them, strings that start or end with a quotation mark of their own are put in
+	/// quotation marks again to keep them.
 	pub fn value_of(&self, key: JString) -> Optional<JString> {
-		Optional::of_nullable(self.lines.get(key))
+		Optional::of_nullable(self.lines.get(key).map(|value| match value {
+			FrontMatterValue::String(string) if self.is_parsed && Self::has_outer_quotation_mark(string.as_ref()) => {
+				format!("\"{}\"", string).into()
+			}
+			value => value.text(),
+		}))
+	}
+
+	fn has_outer_quotation_mark(string: &str) -> bool {
+		string.starts_with('"') || string.ends_with('"')
 	}
 
 	/// ```java
@@ -43,4 +157,139 @@ impl RawFrontMatter {
 		self.value_of(key.clone())
 			.or_else_throw(|| IllegalArgumentException("Required key '" + key + "' not present in front matter."))
 	}
+
+	/// The same front matter with `value` for `key` if it has no value for it.
+	pub(super) fn with_default_value(mut self, key: JString, value: FrontMatterValue) -> Self {
+		self.lines.compute_if_absent(key, move |_| value);
+		self
+	}
+
+	/// Like [`RawFrontMatter::value_of`], but keeps the type of the value.
+	pub fn typed_value_of(&self, key: JString) -> Optional<FrontMatterValue> {
+		Optional::of_nullable(self.lines.get(key))
+	}
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::post::title::Title;
+	use genealogy_java_apis::test::assert_that;
+	use genealogy_java_apis::time::{LocalDate, LocalDateExtension};
+
+	#[test]
+	fn block_list__typed_and_as_bracket_text() {
+		let front_matter = RawFrontMatter::parse("tags:\n  - java\n  - streams\n").unwrap();
+
+		assert_that(front_matter.typed_value_of("tags".into()).get().unwrap()).is_equal_to(FrontMatterValue::List(
+			List::of([
+				FrontMatterValue::String("java".into()),
+				FrontMatterValue::String("streams".into()),
+			]),
+		));
+		assert_that(front_matter.value_of("tags".into()).get().unwrap()).is_equal_to(JString::from("[java, streams]"));
+	}
+
+	#[test]
+	fn multi_line_and_escaped_strings__parsed() {
+		let front_matter =
+			RawFrontMatter::parse("title: \"Say \\\"Hi\\\": now\"\ndescription: >\n  One\n  line\n").unwrap();
+
+		assert_that(front_matter.value_of("title".into()).get().unwrap()).is_equal_to(JString::from("Say \"Hi\": now"));
+		assert_that(front_matter.value_of("description".into()).get().unwrap())
+			.is_equal_to(JString::from("One line\n"));
+	}
+
+	#[test]
+	fn string_with_outer_quotation_marks__quoted_again() {
+		let front_matter = RawFrontMatter::parse("title: \"Talk: \\\"Design\\\"\"\n").unwrap();
+
+		assert_that(front_matter.value_of("title".into()).get().unwrap())
+			.is_equal_to(JString::from("\"Talk: \"Design\"\""));
+		assert_that(
+			Title::new(front_matter.value_of("title".into()).get().unwrap())
+				.unwrap()
+				.text(),
+		)
+		.is_equal_to(JString::from("Talk: \"Design\""));
+	}
+
+	#[test]
+	fn dates_bools_and_maps__typed() {
+		let front_matter =
+			RawFrontMatter::parse("date: 2018-01-11\ndraft: true\nimage:\n  src: a.png\n  width: 100\n").unwrap();
+
+		assert_that(
+			front_matter
+				.typed_value_of("date".into())
+				.get()
+				.unwrap()
+				.as_date()
+				.unwrap(),
+		)
+		.is_equal_to(LocalDate::of(2018, 1, 11));
+		assert!(front_matter
+			.typed_value_of("draft".into())
+			.get()
+			.unwrap()
+			.as_bool()
+			.unwrap());
+		let image = front_matter
+			.typed_value_of("image".into())
+			.get()
+			.unwrap()
+			.as_map()
+			.unwrap();
+		assert_that(image.get(&JString::from("width")).cloned())
+			.is_equal_to(Some(FrontMatterValue::String("100".into())));
+		assert_that(front_matter.value_of("image".into()).get().unwrap())
+			.is_equal_to(JString::from("{src: a.png, width: 100}"));
+	}
+
+	#[test]
+	fn empty_value__not_present() {
+		let front_matter = RawFrontMatter::parse("repo:\n").unwrap();
+
+		assert!(!front_matter.value_of("repo".into()).is_present());
+	}
+
+	#[test]
+	fn invalid_yaml__error() {
+		let front_matter = RawFrontMatter::parse("title: [unclosed\n");
+
+		assert!(matches!(front_matter, Err(IllegalArgumentException(_))));
+	}
+
+	#[test]
+	fn toml__typed_like_yaml() {
+		let front_matter = RawFrontMatter::parse_toml(
+			"date = 2018-01-11\nupdated = 2018-01-11T10:00:00Z\ndraft = true\nwidth = 100\n",
+		)
+		.unwrap();
+
+		assert_that(front_matter.typed_value_of("date".into()).get().unwrap())
+			.is_equal_to(FrontMatterValue::Date(LocalDate::of(2018, 1, 11)));
+		assert_that(front_matter.value_of("updated".into()).get().unwrap())
+			.is_equal_to(JString::from("2018-01-11T10:00:00Z"));
+		assert_that(front_matter.typed_value_of("draft".into()).get().unwrap())
+			.is_equal_to(FrontMatterValue::Bool(true));
+		assert_that(front_matter.value_of("width".into()).get().unwrap()).is_equal_to(JString::from("100"));
+	}
+
+	#[test]
+	fn json__null_left_out_and_keys_sorted() {
+		let front_matter =
+			RawFrontMatter::parse_json(r#"{"title": "\"Quoted\"", "repo": null, "date": "2018-01-11"}"#).unwrap();
+
+		assert_that(front_matter.keys()).is_equal_to(List::of(["date".into(), "title".into()]));
+		assert_that(front_matter.value_of("title".into()).get().unwrap()).is_equal_to(JString::from("\"\"Quoted\"\""));
+	}
+
+	#[test]
+	fn json_array__error() {
+		let front_matter = RawFrontMatter::parse_json("[1, 2]");
+
+		assert!(matches!(front_matter, Err(IllegalArgumentException(_))));
+	}
 }
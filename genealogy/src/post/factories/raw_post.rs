@@ -1,12 +1,14 @@
 use crate::post::content::Content;
+use crate::post::factories::front_matter_value::FrontMatterValue;
 use crate::post::factories::raw_front_matter::RawFrontMatter;
+use genealogy_java_apis::string::JString;
 
 /// ```java
 /// class RawPost {
 /// 	private final RawFrontMatter frontMatter;
 /// 	private final Content content;
 /// ````
-pub(super) struct RawPost {
+pub struct RawPost {
 	front_matter: RawFrontMatter,
 	content: Content,
 }
@@ -31,6 +33,14 @@ impl RawPost {
 		&self.front_matter
 	}
 
+	/// The same post with `value` for `key` if its front matter has no value for it.
+	pub(super) fn with_default_value(self, key: JString, value: FrontMatterValue) -> Self {
+		Self {
+			front_matter: self.front_matter.with_default_value(key, value),
+			content: self.content,
+		}
+	}
+
 	/// ```java
 	/// public Content content() {
 	///		return content;
@@ -1,14 +1,18 @@
 use crate::post::description::Description;
+use crate::post::factories::factory_error::FactoryError;
+use crate::post::factories::front_matter_reader::FrontMatterReader;
 use crate::post::factories::post_factory::PostFactory;
 use crate::post::factories::raw_post::RawPost;
 use crate::post::slug::Slug;
-use crate::post::tag::Tag;
 use crate::post::talk::Talk;
 use crate::post::title::Title;
 use crate::post::video_slug::VideoSlug;
 use genealogy_java_apis::exception::Exception;
 use genealogy_java_apis::exception::Exception::{IllegalArgumentException, RuntimeException, URISyntaxException};
+use genealogy_java_apis::list::List;
+use genealogy_java_apis::optional::Optional;
 use genealogy_java_apis::path::Path;
+use genealogy_java_apis::string::JString;
 use genealogy_java_apis::time::{LocalDate, LocalDateExtension};
 use genealogy_java_apis::uri::URI;
 
@@ -42,6 +46,16 @@ impl TalkFactory {
 		.map_err(|ex| RuntimeException("Creating talk failed: " + file, ex.into()))
 	}
 
+	/// Creates a talk from the lines of its file, like [`ArticleFactory::create_article_from_lines`].
+	///
+	/// Note: Not part of the original Java code.
+	///
+	/// [`ArticleFactory::create_article_from_lines`]: crate::post::factories::article_factory::ArticleFactory::create_article_from_lines
+	pub fn create_talk_from_lines(file_lines: List<JString>) -> Result<Talk, Exception> {
+		let post = PostFactory::read_post(file_lines)?;
+		Self::create_talk_from_raw_post(post)
+	}
+
 	/// ```java
 	/// private static Talk createTalk(RawPost post) {
 	///		RawFrontMatter frontMatter = post.frontMatter();
@@ -59,23 +73,66 @@ impl TalkFactory {
 	///		}
 	///	}
 	/// ```
-	fn create_talk_from_raw_post(post: RawPost) -> Result<Talk, Exception> {
-		let front_matter = post.front_matter();
-		// simulate try-catch
-		(|| {
-			Ok(Talk::new(
-				Title::new(front_matter.required_value_of(PostFactory::TITLE())?)?,
-				Tag::from(front_matter.required_value_of(PostFactory::TAGS())?)?,
-				LocalDate::parse(front_matter.required_value_of(PostFactory::DATE())?)?,
-				Description::new(front_matter.required_value_of(PostFactory::DESCRIPTION())?)?,
-				Slug::new(front_matter.required_value_of(PostFactory::SLUG())?)?,
-				URI::new(front_matter.required_value_of(PostFactory::SLIDES())?)?,
-				front_matter.value_of(PostFactory::VIDEO()).map(VideoSlug::new)?,
-			))
-		})()
-		.map_err(|error| match error {
-			URISyntaxException(ex) => IllegalArgumentException(ex.to_string().into()),
-			other => other,
-		})
+	/// Note: Fails with the first error of [`TalkFactory::read_talk`].
+	pub(crate) fn create_talk_from_raw_post(post: RawPost) -> Result<Talk, Exception> {
+		Self::read_talk(post).map_err(FactoryError::first)
+	}
+
+	/// Reads every key, so that the errors of all of them are returned, see [`FrontMatterReader`].
+	pub(crate) fn read_talk(post: RawPost) -> Result<Talk, Vec<FactoryError>> {
+		let mut reader = FrontMatterReader::new(post.front_matter());
+		let title = reader.required(PostFactory::TITLE(), Title::new);
+		let tags = reader.read(Optional::of(PostFactory::TAGS()), PostFactory::tags_from);
+		let date = reader.required(PostFactory::DATE(), LocalDate::parse);
+		let description = reader.required(PostFactory::DESCRIPTION(), Description::new);
+		let slug = reader.required(PostFactory::SLUG(), Slug::new);
+		// simulate the catch of the URISyntaxException
+		let slides = reader.required(PostFactory::SLIDES(), |slides| {
+			URI::new(slides).map_err(|error| match error {
+				URISyntaxException(ex) => IllegalArgumentException(ex.to_string().into()),
+				other => other,
+			})
+		});
+		let video = reader.optional(PostFactory::VIDEO(), VideoSlug::new);
+		let metadata = reader.read(Optional::empty(), |front_matter| {
+			PostFactory::metadata_from(front_matter, &Self::read_keys())
+		});
+		let (
+			Some(title),
+			Some(tags),
+			Some(date),
+			Some(description),
+			Some(slug),
+			Some(slides),
+			Some(video),
+			Some(metadata),
+		) = (title, tags, date, description, slug, slides, video, metadata)
+		else {
+			return Err(reader.into_errors());
+		};
+		Ok(Talk::new(
+			title,
+			tags,
+			date,
+			description,
+			slug,
+			slides,
+			video,
+			metadata,
+			Optional::empty(),
+		))
+	}
+
+	/// The front matter keys the talk is created from, every other key goes into its [`Metadata`](crate::post::metadata::Metadata).
+	pub(crate) fn read_keys() -> [JString; 7] {
+		[
+			PostFactory::TITLE(),
+			PostFactory::TAGS(),
+			PostFactory::DATE(),
+			PostFactory::DESCRIPTION(),
+			PostFactory::SLUG(),
+			PostFactory::SLIDES(),
+			PostFactory::VIDEO(),
+		]
 	}
 }
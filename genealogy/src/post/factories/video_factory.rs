@@ -1,15 +1,19 @@
 use crate::post::description::Description;
+use crate::post::factories::factory_error::FactoryError;
+use crate::post::factories::front_matter_reader::FrontMatterReader;
 use crate::post::factories::post_factory::PostFactory;
 use crate::post::factories::raw_post::RawPost;
 use crate::post::repository::Repository;
 use crate::post::slug::Slug;
-use crate::post::tag::Tag;
 use crate::post::title::Title;
 use crate::post::video::Video;
 use crate::post::video_slug::VideoSlug;
 use genealogy_java_apis::exception::Exception;
 use genealogy_java_apis::exception::Exception::RuntimeException;
+use genealogy_java_apis::list::List;
+use genealogy_java_apis::optional::Optional;
 use genealogy_java_apis::path::Path;
+use genealogy_java_apis::string::JString;
 use genealogy_java_apis::time::{LocalDate, LocalDateExtension};
 
 /// ```java
@@ -42,6 +46,16 @@ impl VideoFactory {
 		.map_err(|ex| RuntimeException(r#"Creating video failed: ""# + file, ex.into()))
 	}
 
+	/// Creates a video from the lines of its file, like [`ArticleFactory::create_article_from_lines`].
+	///
+	/// Note: Not part of the original Java code.
+	///
+	/// [`ArticleFactory::create_article_from_lines`]: crate::post::factories::article_factory::ArticleFactory::create_article_from_lines
+	pub fn create_video_from_lines(file_lines: List<JString>) -> Result<Video, Exception> {
+		let post = PostFactory::read_post(file_lines)?;
+		Self::create_video_from_raw_post(post)
+	}
+
 	/// ```java
 	/// private static Video createVideo(RawPost post) {
 	///		RawFrontMatter frontMatter = post.frontMatter();
@@ -55,16 +69,60 @@ impl VideoFactory {
 	///				frontMatter.valueOf(REPOSITORY).map(Repository::new));
 	///	}
 	/// ```
-	fn create_video_from_raw_post(post: RawPost) -> Result<Video, Exception> {
-		let front_matter = post.front_matter();
+	/// Note: Fails with the first error of [`VideoFactory::read_video`].
+	pub(crate) fn create_video_from_raw_post(post: RawPost) -> Result<Video, Exception> {
+		Self::read_video(post).map_err(FactoryError::first)
+	}
+
+	/// Reads every key, so that the errors of all of them are returned, see [`FrontMatterReader`].
+	pub(crate) fn read_video(post: RawPost) -> Result<Video, Vec<FactoryError>> {
+		let mut reader = FrontMatterReader::new(post.front_matter());
+		let title = reader.required(PostFactory::TITLE(), Title::new);
+		let tags = reader.read(Optional::of(PostFactory::TAGS()), PostFactory::tags_from);
+		let date = reader.required(PostFactory::DATE(), LocalDate::parse);
+		let description = reader.required(PostFactory::DESCRIPTION(), Description::new);
+		let slug = reader.required(PostFactory::SLUG(), Slug::new);
+		let video = reader.required(PostFactory::VIDEO(), VideoSlug::new);
+		let repository = reader.optional(PostFactory::REPOSITORY(), Repository::new);
+		let metadata = reader.read(Optional::empty(), |front_matter| {
+			PostFactory::metadata_from(front_matter, &Self::read_keys())
+		});
+		let (
+			Some(title),
+			Some(tags),
+			Some(date),
+			Some(description),
+			Some(slug),
+			Some(video),
+			Some(repository),
+			Some(metadata),
+		) = (title, tags, date, description, slug, video, repository, metadata)
+		else {
+			return Err(reader.into_errors());
+		};
 		Ok(Video::new(
-			Title::new(front_matter.required_value_of(PostFactory::TITLE())?)?,
-			Tag::from(front_matter.required_value_of(PostFactory::TAGS())?)?,
-			LocalDate::parse(front_matter.required_value_of(PostFactory::DATE())?)?,
-			Description::new(front_matter.required_value_of(PostFactory::DESCRIPTION())?)?,
-			Slug::new(front_matter.required_value_of(PostFactory::SLUG())?)?,
-			VideoSlug::new(front_matter.required_value_of(PostFactory::VIDEO())?)?,
-			front_matter.value_of(PostFactory::REPOSITORY()).map(Repository::new)?,
+			title,
+			tags,
+			date,
+			description,
+			slug,
+			video,
+			repository,
+			metadata,
+			Optional::empty(),
 		))
 	}
+
+	/// The front matter keys the video is created from, every other key goes into its [`Metadata`](crate::post::metadata::Metadata).
+	pub(crate) fn read_keys() -> [JString; 7] {
+		[
+			PostFactory::TITLE(),
+			PostFactory::TAGS(),
+			PostFactory::DATE(),
+			PostFactory::DESCRIPTION(),
+			PostFactory::SLUG(),
+			PostFactory::VIDEO(),
+			PostFactory::REPOSITORY(),
+		]
+	}
 }
@@ -0,0 +1,222 @@
+use crate::output::front_matter_recommendations::FrontMatterRecommendations;
+use crate::post::factories::front_matter_value::FrontMatterValue;
+use genealogy_java_apis::exception::Exception;
+use genealogy_java_apis::exception::Exception::IllegalArgumentException;
+use genealogy_java_apis::list::List;
+use genealogy_java_apis::optional::Optional;
+use genealogy_java_apis::string::JString;
+use genealogy_java_apis::{r#static, throw};
+use serde::{Serialize, Serializer};
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::rc::Rc;
+
+/// The front matter keys of a post that its factory doesn't read, with their typed values,
+/// so that genealogists and outputs can use them.
+///
+/// The values of the well-known keys are checked on creation, so their accessors can't fail.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Metadata {
+	values: Rc<BTreeMap<JString, FrontMatterValue>>,
+}
+
+impl Metadata {
+	r#static!(pub SEARCH_KEYWORDS: JString = "searchKeywords".into());
+	r#static!(pub FEATURED_IMAGE: JString = "featuredImage".into());
+	r#static!(pub DRAFT: JString = "draft".into());
+	r#static!(pub UNLISTED: JString = "unlisted".into());
+
+	/// Keys the website uses, but the genealogy doesn't, e.g. the repository of talks, which their factory doesn't read.
+	const WEBSITE_KEYS: [&'static str; 6] = [
+		"canonicalText",
+		"canonicalUrl",
+		"inlineCodeLanguage",
+		"intro",
+		"repo",
+		"source",
+	];
+
+	/// Whether the key is well-known, written by the genealogy, see [`FrontMatterRecommendations`],
+	/// or used by the website. Keys that are neither this nor read by a factory are unknown,
+	/// see [`Validator`](crate::validation::validator::Validator).
+	pub fn is_known_key(key: &JString) -> bool {
+		[
+			Self::SEARCH_KEYWORDS(),
+			Self::FEATURED_IMAGE(),
+			Self::DRAFT(),
+			Self::UNLISTED(),
+			FrontMatterRecommendations::KEY(),
+		]
+		.contains(key)
+			|| Self::WEBSITE_KEYS.contains(&key.as_ref())
+	}
+
+	pub fn new(values: BTreeMap<JString, FrontMatterValue>) -> Result<Metadata, Exception> {
+		if let Some(value) = values.get(&Self::SEARCH_KEYWORDS()) {
+			Self::search_keywords_of(value)?;
+		}
+		if let Some(value) = values.get(&Self::FEATURED_IMAGE()) {
+			Self::text_of(Self::FEATURED_IMAGE(), value)?;
+		}
+		for key in [Self::DRAFT(), Self::UNLISTED()] {
+			if let Some(value) = values.get(&key) {
+				Self::flag_of(key, value)?;
+			}
+		}
+		Ok(Self {
+			values: Rc::new(values),
+		})
+	}
+
+	pub fn empty() -> Metadata {
+		Self::default()
+	}
+
+	pub fn get(&self, key: JString) -> Optional<FrontMatterValue> {
+		Optional::of_nullable(self.values.get(&key).cloned())
+	}
+
+	/// The keys, sorted.
+	pub fn keys(&self) -> List<JString> {
+		List::of(self.values.keys().cloned())
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.values.is_empty()
+	}
+
+	/// The keywords under `searchKeywords`, written either as one string or as a list.
+	pub fn search_keywords(&self) -> List<JString> {
+		self.values
+			.get(&Self::SEARCH_KEYWORDS())
+			.and_then(|value| Self::search_keywords_of(value).ok())
+			.unwrap_or_else(List::new)
+	}
+
+	/// The name of the image under `featuredImage`.
+	pub fn featured_image(&self) -> Optional<JString> {
+		Optional::of_nullable(
+			self.values
+				.get(&Self::FEATURED_IMAGE())
+				.and_then(|value| Self::text_of(Self::FEATURED_IMAGE(), value).ok()),
+		)
+	}
+
+	/// Whether `draft` is `true`, see [`PublicationStatus`](crate::post::publication_status::PublicationStatus).
+	pub fn is_draft(&self) -> bool {
+		self.flag(Self::DRAFT())
+	}
+
+	/// Whether `unlisted` is `true`, see [`PublicationStatus`](crate::post::publication_status::PublicationStatus).
+	pub fn is_unlisted(&self) -> bool {
+		self.flag(Self::UNLISTED())
+	}
+
+	fn flag(&self, key: JString) -> bool {
+		self.values
+			.get(&key)
+			.and_then(|value| Self::flag_of(key, value).ok())
+			.unwrap_or(false)
+	}
+
+	fn search_keywords_of(value: &FrontMatterValue) -> Result<List<JString>, Exception> {
+		match value {
+			FrontMatterValue::List(values) => values
+				.clone()
+				.into_iter()
+				.map(|value| Self::text_of(Self::SEARCH_KEYWORDS(), &value))
+				.collect::<Result<Vec<_>, _>>()
+				.map(List::of),
+			value => Ok(List::of([Self::text_of(Self::SEARCH_KEYWORDS(), value)?])),
+		}
+	}
+
+	/// Booleans, or their text if the front matter couldn't be parsed as YAML.
+	fn flag_of(key: JString, value: &FrontMatterValue) -> Result<bool, Exception> {
+		match value {
+			FrontMatterValue::Bool(flag) => Ok(*flag),
+			FrontMatterValue::String(text) if text == "true" || text == "false" => Ok(text == "true"),
+			value => throw!(IllegalArgumentException(
+				format!("Expected true or false for '{}': {}", key, value).into()
+			)),
+		}
+	}
+
+	/// Strings and dates as text, which YAML can't tell apart.
+	fn text_of(key: JString, value: &FrontMatterValue) -> Result<JString, Exception> {
+		match value {
+			FrontMatterValue::String(_) | FrontMatterValue::Date(_) => Ok(value.text()),
+			value => throw!(IllegalArgumentException(
+				format!("Expected text for '{}': {}", key, value).into()
+			)),
+		}
+	}
+}
+
+/// Like a map of the front matter, see [`FrontMatterValue::text`].
+impl Display for Metadata {
+	fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+		FrontMatterValue::Map((*self.values).clone()).fmt(formatter)
+	}
+}
+
+impl Serialize for Metadata {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		serializer.collect_map(self.values.iter().map(|(key, value)| (key.to_string(), value)))
+	}
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod test {
+	use super::*;
+	use genealogy_java_apis::test::assert_that;
+
+	#[test]
+	fn search_keywords__string_or_list() {
+		let string = Metadata::new(BTreeMap::from([(
+			Metadata::SEARCH_KEYWORDS(),
+			FrontMatterValue::String("java var".into()),
+		)]))
+		.unwrap();
+		let list = Metadata::new(BTreeMap::from([(
+			Metadata::SEARCH_KEYWORDS(),
+			FrontMatterValue::List(List::of([
+				FrontMatterValue::String("java".into()),
+				FrontMatterValue::String("var".into()),
+			])),
+		)]))
+		.unwrap();
+
+		assert_that(string.search_keywords()).is_equal_to(List::of(["java var".into()]));
+		assert_that(list.search_keywords()).is_equal_to(List::of(["java".into(), "var".into()]));
+		assert!(Metadata::empty().search_keywords().is_empty());
+	}
+
+	#[test]
+	fn draft_and_unlisted__flags() {
+		let metadata = Metadata::new(BTreeMap::from([
+			(Metadata::DRAFT(), FrontMatterValue::Bool(true)),
+			(Metadata::UNLISTED(), FrontMatterValue::String("false".into())),
+		]))
+		.unwrap();
+		let not_a_flag = Metadata::new(BTreeMap::from([(
+			Metadata::DRAFT(),
+			FrontMatterValue::String("yes".into()),
+		)]));
+
+		assert!(metadata.is_draft());
+		assert!(!metadata.is_unlisted());
+		assert!(matches!(not_a_flag, Err(IllegalArgumentException(_))));
+	}
+
+	#[test]
+	fn featured_image_not_text__error() {
+		let metadata = Metadata::new(BTreeMap::from([(
+			Metadata::FEATURED_IMAGE(),
+			FrontMatterValue::Bool(true),
+		)]));
+
+		assert!(matches!(metadata, Err(IllegalArgumentException(_))));
+	}
+}
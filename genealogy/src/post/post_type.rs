@@ -0,0 +1,126 @@
+use crate::post::factories::article_factory::ArticleFactory;
+use crate::post::factories::factory_error::FactoryError;
+use crate::post::factories::post_factory::PostFactory;
+use crate::post::factories::post_factory_service::PostFactoryService;
+use crate::post::factories::talk_factory::TalkFactory;
+use crate::post::factories::video_factory::VideoFactory;
+use crate::post::Post;
+use genealogy_java_apis::exception::Exception;
+use genealogy_java_apis::exception::Exception::IllegalArgumentException;
+use genealogy_java_apis::list::List;
+use genealogy_java_apis::optional::Optional;
+use genealogy_java_apis::path::Path;
+use genealogy_java_apis::service_loader::{Class, ServiceLoader};
+use genealogy_java_apis::string::JString;
+use genealogy_java_apis::throw;
+use std::fmt::{Display, Formatter};
+
+/// The types of posts a content folder can contain, they decide which factory creates the posts from its files.
+///
+/// Besides the built-in types, every kind of post with a [`PostFactoryService`] registered
+/// with the [`ServiceLoader`] can be used.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum PostType {
+	Article,
+	Talk,
+	Video,
+	Other(PostFactoryService),
+}
+
+impl PostType {
+	pub const BUILT_IN: [PostType; 3] = [PostType::Article, PostType::Talk, PostType::Video];
+
+	/// Parses the name of a post type, ignoring case.
+	pub fn from_name(name: JString) -> Result<PostType, Exception> {
+		let name = name.strip().to_lower_case();
+		match Self::all()?
+			.into_iter()
+			.find(|post_type| post_type.name().to_lower_case() == name)
+		{
+			Some(post_type) => Ok(post_type),
+			None => throw!(IllegalArgumentException("Unknown post type: " + name)),
+		}
+	}
+
+	/// The built-in types and those of the registered [`PostFactoryService`]s.
+	pub fn all() -> Result<List<PostType>, Exception> {
+		// the service loader fails if no service has been registered at all
+		let services = match ServiceLoader::load(PostFactoryService::class()).stream() {
+			Ok(providers) => providers
+				.map(|provider| Ok(PostType::Other(provider.get())))
+				.to_list()?,
+			Err(_) => List::new(),
+		};
+		Ok(List::of(Self::BUILT_IN.into_iter().chain(services)))
+	}
+
+	/// Same as [`Post::kind`].
+	pub fn name(&self) -> JString {
+		match self {
+			PostType::Article => "article".into(),
+			PostType::Talk => "talk".into(),
+			PostType::Video => "video".into(),
+			PostType::Other(service) => service.kind(),
+		}
+	}
+
+	pub fn create_post(&self, file: Path) -> Result<Post, Exception> {
+		match self {
+			PostType::Article => ArticleFactory::create_article(file).map(Post::from),
+			PostType::Talk => TalkFactory::create_talk(file).map(Post::from),
+			PostType::Video => VideoFactory::create_video(file).map(Post::from),
+			PostType::Other(service) => service.create_post(file),
+		}
+	}
+
+	pub fn create_post_from_lines(&self, file_lines: List<JString>) -> Result<Post, Exception> {
+		match self {
+			PostType::Article => ArticleFactory::create_article_from_lines(file_lines).map(Post::from),
+			PostType::Talk => TalkFactory::create_talk_from_lines(file_lines).map(Post::from),
+			PostType::Video => VideoFactory::create_video_from_lines(file_lines).map(Post::from),
+			PostType::Other(service) => service.create_post_from_lines(file_lines),
+		}
+	}
+
+	/// Like [`PostType::create_post_from_lines`], but a post without a slug gets the one derived
+	/// from the name of the file, like in [`PostType::create_post`].
+	pub fn create_post_from_file_lines(&self, file: &Path, file_lines: List<JString>) -> Result<Post, Exception> {
+		self.read_post_from_file_lines(file, file_lines)
+			.map_err(FactoryError::first)
+	}
+
+	/// Like [`PostType::create_post_from_file_lines`], but the built-in factories return the errors of every
+	/// front matter key instead of failing on the first, see [`ArticleFactory::read_article`].
+	/// Kinds that aren't built-in fail on the first error.
+	pub(crate) fn read_post_from_file_lines(
+		&self,
+		file: &Path,
+		file_lines: List<JString>,
+	) -> Result<Post, Vec<FactoryError>> {
+		let post = PostFactory::read_post_of_file(file, file_lines).map_err(|exception| vec![exception.into()])?;
+		match self {
+			PostType::Article => ArticleFactory::read_article(post).map(Post::from),
+			PostType::Talk => TalkFactory::read_talk(post).map(Post::from),
+			PostType::Video => VideoFactory::read_video(post).map(Post::from),
+			PostType::Other(service) => service
+				.create_post_from_raw_post(post)
+				.map_err(|exception| vec![exception.into()]),
+		}
+	}
+
+	/// The front matter keys the factory reads, empty for kinds that aren't built-in because their services don't tell.
+	pub(crate) fn read_keys(&self) -> Optional<List<JString>> {
+		match self {
+			PostType::Article => Optional::of(List::of(ArticleFactory::read_keys())),
+			PostType::Talk => Optional::of(List::of(TalkFactory::read_keys())),
+			PostType::Video => Optional::of(List::of(VideoFactory::read_keys())),
+			PostType::Other(_) => Optional::empty(),
+		}
+	}
+}
+
+impl Display for PostType {
+	fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+		write!(formatter, "{}", self.name())
+	}
+}
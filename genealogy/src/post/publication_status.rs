@@ -0,0 +1,110 @@
+use crate::post::Post;
+use genealogy_java_apis::time::LocalDate;
+use std::fmt::{Display, Formatter};
+
+/// Whether a post is published as of a date, from the `draft` and `unlisted` keys of its [`Metadata`](crate::post::metadata::Metadata)
+/// and its date.
+///
+/// Only published posts are recommended. Unlisted posts are online, so they get recommendations themselves,
+/// drafts and scheduled posts only in previews.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum PublicationStatus {
+	Published,
+	Unlisted,
+	/// Published after the date, unless it is a draft.
+	Scheduled,
+	Draft,
+}
+
+impl PublicationStatus {
+	/// A draft stays a draft whatever its date, a post scheduled for later isn't listed yet either way.
+	pub fn of(post: &Post, as_of: LocalDate) -> PublicationStatus {
+		let metadata = post.metadata();
+		if metadata.is_draft() {
+			PublicationStatus::Draft
+		} else if post.date() > as_of {
+			PublicationStatus::Scheduled
+		} else if metadata.is_unlisted() {
+			PublicationStatus::Unlisted
+		} else {
+			PublicationStatus::Published
+		}
+	}
+
+	/// Whether other posts can recommend it.
+	pub fn is_recommendable(self) -> bool {
+		self == PublicationStatus::Published
+	}
+
+	/// Whether it gets recommendations itself, which drafts and scheduled posts only get for previews.
+	pub fn receives_recommendations(self, preview: bool) -> bool {
+		match self {
+			PublicationStatus::Published | PublicationStatus::Unlisted => true,
+			PublicationStatus::Scheduled | PublicationStatus::Draft => preview,
+		}
+	}
+
+	pub fn name(self) -> &'static str {
+		match self {
+			PublicationStatus::Published => "published",
+			PublicationStatus::Unlisted => "unlisted",
+			PublicationStatus::Scheduled => "scheduled",
+			PublicationStatus::Draft => "draft",
+		}
+	}
+}
+
+impl Display for PublicationStatus {
+	fn fmt(&self, formatter: &mut Formatter) -> std::fmt::Result {
+		formatter.write_str(self.name())
+	}
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::post::factories::article_factory::ArticleFactory;
+	use genealogy_java_apis::list::List;
+	use genealogy_java_apis::string::JString;
+	use genealogy_java_apis::test::assert_that;
+	use genealogy_java_apis::time::LocalDateExtension;
+
+	fn post(date: &str, extra_line: &str) -> Post {
+		let mut lines = vec![
+			"---".to_string(),
+			"title: Title".to_string(),
+			"tags: [Tag]".to_string(),
+			format!("date: {}", date),
+			"description: \"Description\"".to_string(),
+			"slug: slug".to_string(),
+		];
+		lines.extend((!extra_line.is_empty()).then(|| extra_line.to_string()));
+		lines.push("---".to_string());
+		ArticleFactory::create_article_from_lines(List::of(lines.iter().map(|line| JString::from(line.as_str()))))
+			.unwrap()
+			.into()
+	}
+
+	#[test]
+	fn of__from_front_matter_and_date() {
+		let as_of = LocalDate::of(2021, 1, 1);
+
+		assert_that(PublicationStatus::of(&post("2020-12-31", ""), as_of)).is_equal_to(PublicationStatus::Published);
+		assert_that(PublicationStatus::of(&post("2021-01-01", ""), as_of)).is_equal_to(PublicationStatus::Published);
+		assert_that(PublicationStatus::of(&post("2021-01-02", ""), as_of)).is_equal_to(PublicationStatus::Scheduled);
+		assert_that(PublicationStatus::of(&post("2020-12-31", "unlisted: true"), as_of))
+			.is_equal_to(PublicationStatus::Unlisted);
+		assert_that(PublicationStatus::of(&post("2021-01-02", "draft: true"), as_of))
+			.is_equal_to(PublicationStatus::Draft);
+	}
+
+	#[test]
+	fn receives_recommendations__drafts_and_scheduled_only_in_previews() {
+		assert!(PublicationStatus::Unlisted.receives_recommendations(false));
+		assert!(!PublicationStatus::Unlisted.is_recommendable());
+		assert!(!PublicationStatus::Draft.receives_recommendations(false));
+		assert!(PublicationStatus::Draft.receives_recommendations(true));
+		assert!(!PublicationStatus::Scheduled.receives_recommendations(false));
+	}
+}
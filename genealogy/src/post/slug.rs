@@ -1,5 +1,6 @@
 use genealogy_java_apis::exception::Exception;
 use genealogy_java_apis::exception::Exception::IllegalArgumentException;
+use genealogy_java_apis::path::Path;
 use genealogy_java_apis::string::JString;
 use genealogy_java_apis::{record, throw};
 
@@ -33,6 +34,34 @@ impl Slug {
 
 		Ok(Slug { value })
 	}
+
+	/// The slug of a post without a `slug` in its front matter, from the name of its file without the extension
+	/// and without a leading date, e.g. `2014-09-22-decorator-pattern-saved-my-day.md` has the slug
+	/// `decorator-pattern-saved-my-day`. Files named `index` or `_index` take the name of their folder.
+	pub fn from_file_name(file: &Path) -> Result<Slug, Exception> {
+		let path: &std::path::Path = file.as_ref();
+		let name = match path.file_stem().and_then(|stem| stem.to_str()) {
+			Some("index" | "_index") => path.parent().and_then(|folder| folder.file_name()),
+			_ => path.file_stem(),
+		}
+		.and_then(|name| name.to_str())
+		.unwrap_or_default();
+		Self::new(Self::without_date(name).into())
+	}
+
+	/// Removes a leading `yyyy-mm-dd-`.
+	fn without_date(name: &str) -> &str {
+		let is_date = name.len() > 11
+			&& name.bytes().take(11).enumerate().all(|(index, byte)| match index {
+				4 | 7 | 10 => byte == b'-',
+				_ => byte.is_ascii_digit(),
+			});
+		if is_date {
+			&name[11..]
+		} else {
+			name
+		}
+	}
 }
 
 /// ```java
@@ -56,4 +85,14 @@ mod test {
 			.throws()
 			.and_satisfies(|exception| matches!(exception, IllegalArgumentException(_)));
 	}
+
+	#[test]
+	fn from_file_name__without_extension_and_date() {
+		let slug = |file: &str| Slug::from_file_name(&Path::of(file)).unwrap().value();
+
+		assert_that(slug("articles/2014-09-22-decorator-pattern-saved-my-day.md"))
+			.is_equal_to(JString::from("decorator-pattern-saved-my-day"));
+		assert_that(slug("articles/java-17.md")).is_equal_to(JString::from("java-17"));
+		assert_that(slug("articles/2021-09-14-java-17/index.md")).is_equal_to(JString::from("java-17"));
+	}
 }
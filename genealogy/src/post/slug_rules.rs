@@ -0,0 +1,92 @@
+use crate::post::slug::Slug;
+use genealogy_java_apis::exception::Exception;
+use genealogy_java_apis::exception::Exception::IllegalArgumentException;
+use genealogy_java_apis::optional::Optional;
+use genealogy_java_apis::{record, throw};
+
+/// The rules every [`Slug`] has to follow on top of not being blank, configured in the `slugs` table of the config.
+///
+/// URL-safe slugs only consist of the characters that don't need to be encoded in a URL, which are ASCII letters,
+/// digits, `-`, `.`, `_` and `~`. By default, slugs have to be URL-safe, but can contain upper case letters
+/// and be of any length.
+#[record]
+pub struct SlugRules {
+	url_safe: bool,
+	lowercase: bool,
+	max_length: Optional<usize>,
+}
+
+impl SlugRules {
+	/// Checks the slug against every rule, the error names the first one it breaks.
+	pub fn check(&self, slug: &Slug) -> Result<(), Exception> {
+		let value = slug.value().to_string();
+		if self.url_safe {
+			if let Some(character) = value.chars().find(|character| !Self::is_url_safe(*character)) {
+				throw!(IllegalArgumentException(
+					format!("Slug '{}' contains '{}', which isn't URL-safe.", value, character).into()
+				));
+			}
+		}
+		if self.lowercase && value.chars().any(char::is_uppercase) {
+			throw!(IllegalArgumentException(
+				format!("Slug '{}' has to be lowercase.", value).into()
+			));
+		}
+		if let Ok(max_length) = self.max_length.get() {
+			if value.chars().count() > max_length {
+				throw!(IllegalArgumentException(
+					format!("Slug '{}' is longer than {} characters.", value, max_length).into()
+				));
+			}
+		}
+		Ok(())
+	}
+
+	/// The unreserved characters of RFC 3986.
+	fn is_url_safe(character: char) -> bool {
+		character.is_ascii_alphanumeric() || matches!(character, '-' | '.' | '_' | '~')
+	}
+}
+
+impl Default for SlugRules {
+	fn default() -> Self {
+		Self::new(true, false, Optional::empty())
+	}
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	fn slug(value: &str) -> Slug {
+		Slug::new(value.into()).unwrap()
+	}
+
+	#[test]
+	fn default__url_safe_with_upper_case() {
+		let rules = SlugRules::default();
+
+		assert!(rules.check(&slug("javadoc-tags-apiNote-implSpec-implNote")).is_ok());
+		assert!(matches!(
+			rules.check(&slug("decorator pattern")),
+			Err(IllegalArgumentException(_))
+		));
+		assert!(matches!(rules.check(&slug("café")), Err(IllegalArgumentException(_))));
+	}
+
+	#[test]
+	fn lowercase_and_max_length__enforced() {
+		let rules = SlugRules::new(true, true, Optional::of(10));
+
+		assert!(rules.check(&slug("java-17")).is_ok());
+		assert!(matches!(
+			rules.check(&slug("Java-17")),
+			Err(IllegalArgumentException(_))
+		));
+		assert!(matches!(
+			rules.check(&slug("java-17-sealed-classes")),
+			Err(IllegalArgumentException(_))
+		));
+	}
+}
@@ -1,9 +1,11 @@
 use genealogy_java_apis::collector::Collectors;
 use genealogy_java_apis::exception::Exception;
+use genealogy_java_apis::list::List;
 use genealogy_java_apis::record;
 use genealogy_java_apis::set::Set;
 use genealogy_java_apis::stream::Stream;
 use genealogy_java_apis::string::JString;
+use std::hash::{Hash, Hasher};
 
 /// ```java
 /// public record Tag(String text) {
@@ -11,12 +13,33 @@ use genealogy_java_apis::string::JString;
 /// 		requireNonNull(text);
 /// 	}
 /// ```
-#[record]
+/// Note: Tags of a [`TagTaxonomy`](crate::post::tag_taxonomy::TagTaxonomy) know their `ancestors`,
+/// from the parent up to the root. Tags are equal if they have the same text.
+#[record(constructor = false, equals = false, hash = false)]
 pub struct Tag {
 	text: JString,
+	ancestors: List<JString>,
 }
 
 impl Tag {
+	pub fn new(text: JString) -> Tag {
+		Self::with_ancestors(text, List::new())
+	}
+
+	pub fn with_ancestors(text: JString, ancestors: List<JString>) -> Tag {
+		Tag { text, ancestors }
+	}
+
+	/// Whether two different tags share an ancestor or one is an ancestor of the other,
+	/// e.g. `jpms` and `java-9` if both are below `java`.
+	pub fn is_related_to(&self, other: &Tag) -> bool {
+		if self == other {
+			return false;
+		}
+		let lineage = |tag: &Tag| std::iter::once(tag.text.clone()).chain(tag.ancestors.clone());
+		lineage(self).any(|text| lineage(other).any(|other_text| other_text == text))
+	}
+
 	/// ```java
 	/// public static Set<Tag> from(String tagsText) {
 	///		return Stream.of(tagsText
@@ -39,6 +62,20 @@ impl Tag {
 	}
 }
 
+impl PartialEq for Tag {
+	fn eq(&self, other: &Self) -> bool {
+		self.text == other.text
+	}
+}
+
+impl Eq for Tag {}
+
+impl Hash for Tag {
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		self.text.hash(state)
+	}
+}
+
 /// ```java
 /// class TagTests {
 /// ```
@@ -0,0 +1,204 @@
+use crate::post::tag::Tag;
+use genealogy_java_apis::exception::Exception;
+use genealogy_java_apis::exception::Exception::IllegalArgumentException;
+use genealogy_java_apis::list::List;
+use genealogy_java_apis::path::Path;
+use genealogy_java_apis::set::Set;
+use genealogy_java_apis::string::JString;
+use genealogy_java_apis::throw;
+use serde::Deserialize;
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::fmt::{Display, Formatter};
+use std::rc::Rc;
+
+/// The canonical tags with their aliases and parents, read from the TOML file configured with `tags.taxonomy`:
+/// ```toml
+/// [java]
+///
+/// [java-9]
+/// parent = "java"
+/// aliases = ["java9", "jdk-9"]
+///
+/// [jpms]
+/// parent = "java-9"
+/// aliases = ["modules", "module system"]
+/// ```
+/// Parents are given by their canonical name. Tags are matched ignoring case, against the canonical names
+/// and the aliases, and replaced by the canonical tag with its ancestors. Tags that aren't in the taxonomy
+/// are kept as they are.
+#[derive(Debug, Clone, Default)]
+pub struct TagTaxonomy {
+	/// Canonical names and aliases in lower case.
+	tags_by_name: Rc<BTreeMap<JString, Tag>>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct TaxonomyEntry {
+	parent: Option<String>,
+	#[serde(default)]
+	aliases: Vec<String>,
+}
+
+impl TagTaxonomy {
+	/// No tags, which keeps all tags as they are.
+	pub fn empty() -> TagTaxonomy {
+		Self::default()
+	}
+
+	pub fn from_file(file: Path) -> Result<TagTaxonomy, Exception> {
+		let text = std::fs::read_to_string(&file).map_err(|error| {
+			IllegalArgumentException(format!("Reading the tag taxonomy {} failed: {}", file, error).into())
+		})?;
+		Self::parse(text.as_str().into())
+	}
+
+	pub fn parse(text: JString) -> Result<TagTaxonomy, Exception> {
+		let entries = toml::from_str::<BTreeMap<String, TaxonomyEntry>>(text.to_string().as_str())
+			.map_err(|error| IllegalArgumentException(error.to_string().trim_end().into()))?;
+
+		let mut tags_by_name = BTreeMap::new();
+		for (name, entry) in &entries {
+			if name.trim().is_empty() {
+				throw!(IllegalArgumentException("Tags can't be empty.".into()));
+			}
+			let tag = Tag::with_ancestors(name.as_str().into(), Self::ancestors_of(name, &entries)?);
+			for alias in std::iter::once(name).chain(&entry.aliases) {
+				let key = JString::from(alias.as_str()).strip().to_lower_case();
+				if let Some(other) = tags_by_name.insert(key, tag.clone()) {
+					throw!(IllegalArgumentException(
+						format!(
+							"'{}' stands for both '{}' and '{}', names and aliases must be unique ignoring case.",
+							alias,
+							other.text(),
+							name
+						)
+						.into()
+					));
+				}
+			}
+		}
+		Ok(Self {
+			tags_by_name: Rc::new(tags_by_name),
+		})
+	}
+
+	/// From the parent up to the root.
+	fn ancestors_of(name: &str, entries: &BTreeMap<String, TaxonomyEntry>) -> Result<List<JString>, Exception> {
+		let mut ancestors = Vec::<String>::new();
+		let mut parent = entries.get(name).and_then(|entry| entry.parent.clone());
+		while let Some(current) = parent {
+			if current == name || ancestors.contains(&current) {
+				throw!(IllegalArgumentException(
+					format!("The parents of '{}' form a cycle.", name).into()
+				));
+			}
+			let Some(entry) = entries.get(&current) else {
+				throw!(IllegalArgumentException(
+					format!("The parent of '{}' isn't a tag of the taxonomy: {}", name, current).into()
+				));
+			};
+			parent = entry.parent.clone();
+			ancestors.push(current);
+		}
+		Ok(List::of(ancestors.iter().map(|ancestor| ancestor.as_str().into())))
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.tags_by_name.is_empty()
+	}
+
+	/// The canonical tag with its ancestors, or the tag itself if it isn't in the taxonomy.
+	pub fn normalize(&self, tag: Tag) -> Tag {
+		let name = tag.text().strip().to_lower_case();
+		self.tags_by_name.get(&name).cloned().unwrap_or(tag)
+	}
+
+	/// Tags that stand for the same canonical tag become one.
+	pub fn normalize_all(&self, tags: Set<Tag>) -> Set<Tag> {
+		Set::from(tags.into_iter().map(|tag| self.normalize(tag)).collect::<HashSet<_>>())
+	}
+}
+
+/// Note: The taxonomy only needs to be displayed as part of the [`Config`](crate::config::Config).
+impl Display for TagTaxonomy {
+	fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+		let tags = self
+			.tags_by_name
+			.values()
+			.map(|tag| tag.text().to_string())
+			.collect::<BTreeSet<_>>();
+		write!(
+			formatter,
+			"TagTaxonomy[tags=[{}]]",
+			tags.into_iter().collect::<Vec<_>>().join(", ")
+		)
+	}
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod test {
+	use super::*;
+	use genealogy_java_apis::test::assert_that;
+
+	const TAXONOMY: &str = "\
+[java]
+
+[java-9]
+parent = \"java\"
+aliases = [\"Java9\"]
+
+[jpms]
+parent = \"java-9\"
+aliases = [\"modules\"]
+";
+
+	#[test]
+	fn normalize__aliases_and_case_to_canonical_tag_with_ancestors() {
+		let taxonomy = TagTaxonomy::parse(TAXONOMY.into()).unwrap();
+
+		let jpms = taxonomy.normalize(Tag::new("Modules".into()));
+		let java_9 = taxonomy.normalize(Tag::new("JAVA9".into()));
+
+		assert_that(jpms.text()).is_equal_to(JString::from("jpms"));
+		assert_that(jpms.ancestors()).is_equal_to(List::of(["java-9".into(), "java".into()]));
+		assert_that(java_9.text()).is_equal_to(JString::from("java-9"));
+		assert!(jpms.is_related_to(&java_9));
+	}
+
+	#[test]
+	fn normalize__unknown_tag_kept() {
+		let taxonomy = TagTaxonomy::parse(TAXONOMY.into()).unwrap();
+
+		let tag = taxonomy.normalize(Tag::new("Streams".into()));
+
+		assert_that(tag.text()).is_equal_to(JString::from("Streams"));
+		assert!(tag.ancestors().is_empty());
+	}
+
+	#[test]
+	fn normalize_all__aliases_of_one_tag_merged() {
+		let taxonomy = TagTaxonomy::parse(TAXONOMY.into()).unwrap();
+
+		let tags = taxonomy.normalize_all(Tag::from("[jpms, Modules, java]".into()).unwrap());
+
+		assert_that(tags.size()).is_equal_to(2);
+	}
+
+	#[test]
+	fn parse__cycle_error() {
+		let taxonomy = TagTaxonomy::parse("[a]\nparent = \"b\"\n[b]\nparent = \"a\"\n".into());
+
+		assert!(matches!(taxonomy, Err(IllegalArgumentException(message)) if message.to_string().contains("cycle")));
+	}
+
+	#[test]
+	fn parse__unknown_parent_and_duplicate_alias_errors() {
+		let unknown_parent = TagTaxonomy::parse("[a]\nparent = \"b\"\n".into());
+		let duplicate_alias = TagTaxonomy::parse("[a]\n[b]\naliases = [\"A\"]\n".into());
+
+		assert!(matches!(unknown_parent, Err(IllegalArgumentException(_))));
+		assert!(matches!(duplicate_alias, Err(IllegalArgumentException(_))));
+	}
+}
@@ -1,12 +1,15 @@
 use crate::post::description::Description;
+use crate::post::metadata::Metadata;
 use crate::post::slug::Slug;
 use crate::post::tag::Tag;
 use crate::post::title::Title;
+use crate::post::video::Video;
 use crate::post::video_slug::VideoSlug;
-use crate::post::Post;
+use crate::post::{Post, PostTrait};
 use genealogy_java_apis::optional::Optional;
 use genealogy_java_apis::record;
 use genealogy_java_apis::set::Set;
+use genealogy_java_apis::string::JString;
 use genealogy_java_apis::time::LocalDate;
 use genealogy_java_apis::uri::URI;
 use std::hash::{Hash, Hasher};
@@ -36,6 +39,10 @@ use std::rc::Rc;
 /// The `implements Post` can't be emulated directly since there is no
 /// inheritance in rust and traits cannot be `sealed`. Therefore [`Post`]
 /// is an enum instead and the `implements` is emulated by a [`From`] implementation.
+///
+/// Note: `metadata` holds the front matter keys that aren't read into the other fields, see [`Metadata`].
+/// `recording` is the video with the talk's `video` slug, once the posts are linked,
+/// see [`Corpus::link_talks_and_videos`](crate::post::corpus::Corpus::link_talks_and_videos).
 #[record(equals = false, hash = false)]
 pub struct Talk {
 	title: Title,
@@ -46,6 +53,8 @@ pub struct Talk {
 	slug: Slug,
 	slides: URI,
 	video: Optional<VideoSlug>,
+	metadata: Metadata,
+	recording: Optional<Rc<Video>>,
 }
 
 impl Talk {
@@ -58,6 +67,53 @@ impl Talk {
 	pub fn tags(&self) -> Set<Tag> {
 		Set::copy_of(self.tags.clone())
 	}
+
+	/// The same talk with other tags, see [`Post::with_tags`].
+	pub(crate) fn with_tags(&self, tags: Set<Tag>) -> Talk {
+		Talk { tags, ..self.clone() }
+	}
+
+	/// The same talk, linked to the video of its recording. The video itself isn't linked back,
+	/// so the links don't form a cycle.
+	pub(crate) fn with_recording(&self, recording: Rc<Video>) -> Talk {
+		Talk {
+			recording: Optional::of(recording),
+			..self.clone()
+		}
+	}
+}
+
+/// ```java
+/// public record Talk(...) implements Post
+/// ```
+impl PostTrait for Talk {
+	fn title(&self) -> Title {
+		Talk::title(self)
+	}
+
+	fn tags(&self) -> Set<Tag> {
+		Talk::tags(self)
+	}
+
+	fn date(&self) -> LocalDate {
+		Talk::date(self)
+	}
+
+	fn description(&self) -> Description {
+		Talk::description(self)
+	}
+
+	fn slug(&self) -> Slug {
+		Talk::slug(self)
+	}
+
+	fn metadata(&self) -> Metadata {
+		Talk::metadata(self)
+	}
+
+	fn kind(&self) -> JString {
+		"talk".into()
+	}
 }
 
 /// ```java
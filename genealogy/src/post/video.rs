@@ -1,13 +1,16 @@
 use crate::post::description::Description;
+use crate::post::metadata::Metadata;
 use crate::post::repository::Repository;
 use crate::post::slug::Slug;
 use crate::post::tag::Tag;
+use crate::post::talk::Talk;
 use crate::post::title::Title;
 use crate::post::video_slug::VideoSlug;
-use crate::post::Post;
+use crate::post::{Post, PostTrait};
 use genealogy_java_apis::optional::Optional;
 use genealogy_java_apis::record;
 use genealogy_java_apis::set::Set;
+use genealogy_java_apis::string::JString;
 use genealogy_java_apis::time::LocalDate;
 use std::hash::{Hash, Hasher};
 use std::rc::Rc;
@@ -36,6 +39,10 @@ use std::rc::Rc;
 /// The `implements Post` can't be emulated directly since there is no
 /// inheritance in rust and traits cannot be `sealed`. Therefore [`Post`]
 /// is an enum instead and the `implements` is emulated by a [`From`] implementation.
+///
+/// Note: `metadata` holds the front matter keys that aren't read into the other fields, see [`Metadata`].
+/// `talk` is the talk that has the video's `video` slug, once the posts are linked,
+/// see [`Corpus::link_talks_and_videos`](crate::post::corpus::Corpus::link_talks_and_videos).
 #[record(equals = false, hash = false)]
 pub struct Video {
 	title: Title,
@@ -46,6 +53,8 @@ pub struct Video {
 	slug: Slug,
 	video: VideoSlug,
 	repository: Optional<Repository>,
+	metadata: Metadata,
+	talk: Optional<Rc<Talk>>,
 }
 
 impl Video {
@@ -58,6 +67,53 @@ impl Video {
 	pub fn tags(&self) -> Set<Tag> {
 		Set::copy_of(self.tags.clone())
 	}
+
+	/// The same video with other tags, see [`Post::with_tags`].
+	pub(crate) fn with_tags(&self, tags: Set<Tag>) -> Video {
+		Video { tags, ..self.clone() }
+	}
+
+	/// The same video, linked to the talk it is a recording of. The talk itself isn't linked back,
+	/// so the links don't form a cycle.
+	pub(crate) fn with_talk(&self, talk: Rc<Talk>) -> Video {
+		Video {
+			talk: Optional::of(talk),
+			..self.clone()
+		}
+	}
+}
+
+/// ```java
+/// public record Video(...) implements Post
+/// ```
+impl PostTrait for Video {
+	fn title(&self) -> Title {
+		Video::title(self)
+	}
+
+	fn tags(&self) -> Set<Tag> {
+		Video::tags(self)
+	}
+
+	fn date(&self) -> LocalDate {
+		Video::date(self)
+	}
+
+	fn description(&self) -> Description {
+		Video::description(self)
+	}
+
+	fn slug(&self) -> Slug {
+		Video::slug(self)
+	}
+
+	fn metadata(&self) -> Metadata {
+		Video::metadata(self)
+	}
+
+	fn kind(&self) -> JString {
+		"video".into()
+	}
 }
 
 /// ```java
@@ -1,17 +1,27 @@
 use crate::genealogy::relation::Relation;
+use crate::post::slug::Slug;
+use crate::post::Post;
 use crate::recommendation::Recommendation;
 use genealogy_java_apis::collector::Collectors;
 use genealogy_java_apis::comparator::Comparator;
 use genealogy_java_apis::exception::Exception::{self, IllegalArgumentException};
+use genealogy_java_apis::list::List;
+use genealogy_java_apis::map::Entry;
 use genealogy_java_apis::stream::Stream;
 use genealogy_java_apis::string::JString;
 use genealogy_java_apis::throw;
+use genealogy_java_apis::time::LocalDate;
+use std::cmp::Reverse;
 
 /// ```java
 /// // Don't judge me for the name - recommend a better one (see what I did there?)
 /// public class Recommender {
 /// ```
-pub struct Recommender;
+/// Note: Can leave out posts that aren't published, see [`Recommender::as_of`].
+pub struct Recommender {
+	as_of: Option<LocalDate>,
+	preview: bool,
+}
 
 impl Recommender {
 	/// ```java
@@ -19,7 +29,19 @@ impl Recommender {
 	/// ```
 	#[allow(clippy::new_without_default)]
 	pub fn new() -> Self {
-		Self
+		Self {
+			as_of: None,
+			preview: false,
+		}
+	}
+
+	/// Only recommends posts that are published as of the given date, see [`PublicationStatus`](crate::post::publication_status::PublicationStatus).
+	/// Drafts and scheduled posts only get recommendations themselves with `preview`.
+	pub fn as_of(as_of: LocalDate, preview: bool) -> Self {
+		Self {
+			as_of: Some(as_of),
+			preview,
+		}
 	}
 
 	/// ```java
@@ -44,29 +66,73 @@ impl Recommender {
 	///
 	/// }
 	/// ```
+	/// Note: Unlike the Java code, the output is deterministic. The recommendations are ordered by the slug
+	/// of their post. Recommended posts with equal scores are ordered by date, newest first, then by slug.
+	/// Relations of posts that aren't published are left out first, see [`Recommender::as_of`].
 	pub fn recommend(&self, relations: Stream<Relation>, per_post: i32) -> Result<Stream<Recommendation>, Exception> {
 		if per_post < 1 {
 			throw!(IllegalArgumentException(
 				JString::from("Number of recommendations per post must be greater zero: ") + per_post
 			));
 		}
-
-		let by_post_then_by_decreasing_score = Comparator::comparing(|relation: &Relation| relation.post1().slug())
-			.then_comparing(Relation::score)
-			.reversed();
-		let by_post = relations
-			.sorted(by_post_then_by_decreasing_score)?
+		let by_post_then_in_order_of_recommendation =
+			Comparator::comparing(|relation: &Relation| relation.post1().slug())
+				.then_comparing(Self::order_of_recommendation);
+		// the sort is stable, so grouping keeps the order of the relations of each post
+		let by_post = self
+			.filter(relations)
+			.sorted(by_post_then_in_order_of_recommendation)?
 			.collect(Collectors::grouping_by(Relation::post1))?;
-		Ok(by_post.entry_set().stream().map(move |post_with_relations| {
-			Recommendation::from(
-				post_with_relations.get_key(),
-				post_with_relations
-					.get_value()
-					.stream()
-					.map(|relation| Ok(relation.post2())),
-				per_post,
-			)
-		}))
+		let by_slug = Comparator::comparing(|post_with_relations: &Entry<Post, List<Relation>>| {
+			post_with_relations.get_key().slug()
+		});
+		Ok(by_post
+			.entry_set()
+			.stream()
+			.sorted(by_slug)?
+			.map(move |post_with_relations| {
+				Recommendation::from(
+					post_with_relations.get_key(),
+					post_with_relations
+						.get_value()
+						.stream()
+						.map(|relation| Ok(relation.post2())),
+					per_post,
+				)
+			}))
+	}
+
+	/// Orders the relations of a single post like [`Recommender::recommend`] orders its recommended posts
+	/// and leaves out the same relations, so the position of a relation is the rank of its `post2`.
+	pub fn rank(&self, relations: Stream<Relation>) -> Result<List<Relation>, Exception> {
+		self.filter(relations)
+			.sorted(Comparator::comparing(Self::order_of_recommendation))?
+			.to_list()
+	}
+
+	fn filter(&self, relations: Stream<Relation>) -> Stream<Relation> {
+		match self.as_of {
+			Some(as_of) => {
+				let preview = self.preview;
+				relations.filter(move |relation| {
+					relation.post2().publication_status(as_of).is_recommendable()
+						&& relation
+							.post1()
+							.publication_status(as_of)
+							.receives_recommendations(preview)
+				})
+			}
+			None => relations,
+		}
+	}
+
+	/// Decreasing score, then decreasing date, then slug of the recommended post.
+	fn order_of_recommendation(relation: &Relation) -> (Reverse<i64>, Reverse<LocalDate>, Slug) {
+		(
+			Reverse(relation.score()),
+			Reverse(relation.post2().date()),
+			relation.post2().slug(),
+		)
 	}
 }
 
@@ -75,10 +141,18 @@ impl Recommender {
 mod test {
 	use super::*;
 	use crate::genealogy::relation_test_helper::RelationTestHelper;
+	use crate::output::recommendation_json::RecommendationJson;
+	use crate::post::article::Article;
+	use crate::post::description::Description;
+	use crate::post::factories::front_matter_value::FrontMatterValue;
+	use crate::post::metadata::Metadata;
+	use crate::post::tag::Tag;
 	use crate::post::test::PostTestHelper;
-	use crate::post::Post;
-	use genealogy_java_apis::list::List;
+	use crate::post::title::Title;
+	use genealogy_java_apis::optional::Optional;
 	use genealogy_java_apis::test::assert_that;
+	use genealogy_java_apis::time::LocalDateExtension;
+	use std::collections::BTreeMap;
 
 	/// ```java
 	/// class RecommenderTests {
@@ -287,4 +361,105 @@ mod test {
 			.for_many_posts__two_relations_each()
 			.unwrap();
 	}
+
+	fn create_with_slug_and_date(slug: &str, date: LocalDate) -> Post {
+		create_with_slug_date_and_metadata(slug, date, Metadata::empty())
+	}
+
+	fn create_with_slug_date_and_metadata(slug: &str, date: LocalDate, metadata: Metadata) -> Post {
+		Article::new(
+			Title::new("Title".into()).unwrap(),
+			Tag::from("[Tag]".into()).unwrap(),
+			date,
+			Description::new("description".into()).unwrap(),
+			Slug::new(slug.into()).unwrap(),
+			Optional::empty(),
+			(|| Stream::of(["".into()])).into(),
+			metadata,
+		)
+		.into()
+	}
+
+	#[test]
+	fn equal_scores__newest_first_then_by_slug() {
+		let post = create_with_slug_and_date("post", LocalDate::of(2020, 1, 1));
+		let old = create_with_slug_and_date("old", LocalDate::of(2019, 1, 1));
+		let new_b = create_with_slug_and_date("new-b", LocalDate::of(2021, 1, 1));
+		let new_a = create_with_slug_and_date("new-a", LocalDate::of(2021, 1, 1));
+		let relations = Stream::of([
+			RelationTestHelper::create(post.clone(), old.clone(), 50).unwrap(),
+			RelationTestHelper::create(post.clone(), new_b.clone(), 50).unwrap(),
+			RelationTestHelper::create(post.clone(), new_a.clone(), 50).unwrap(),
+		]);
+
+		let recommendations = Recommender::new().recommend(relations, 3).unwrap().to_list().unwrap();
+
+		assert_that(recommendations.get(0).unwrap().recommended_posts()).is_equal_to(List::of([new_a, new_b, old]));
+	}
+
+	#[test]
+	fn as_of__unpublished_posts_not_recommended_and_only_previewed() {
+		let as_of = LocalDate::of(2021, 1, 1);
+		let published = create_with_slug_and_date("published", LocalDate::of(2020, 1, 1));
+		let scheduled = create_with_slug_and_date("scheduled", LocalDate::of(2022, 1, 1));
+		let draft = create_with_slug_date_and_metadata(
+			"draft",
+			LocalDate::of(2020, 1, 1),
+			Metadata::new(BTreeMap::from([(Metadata::DRAFT(), FrontMatterValue::Bool(true))])).unwrap(),
+		);
+		let relations = || {
+			let posts = [published.clone(), scheduled.clone(), draft.clone()];
+			let mut relations = Vec::new();
+			for post1 in &posts {
+				for post2 in &posts {
+					if post1 != post2 {
+						relations.push(RelationTestHelper::create(post1.clone(), post2.clone(), 50).unwrap());
+					}
+				}
+			}
+			Stream::of(relations)
+		};
+		let recommended = |preview| {
+			Recommender::as_of(as_of, preview)
+				.recommend(relations(), 3)
+				.unwrap()
+				.map(|recommendation| Ok((recommendation.post().slug(), recommendation.recommended_posts())))
+				.to_list()
+				.unwrap()
+		};
+
+		assert_that(recommended(false)).is_equal_to(List::of([]));
+		assert_that(recommended(true)).is_equal_to(List::of([
+			(draft.slug(), List::of([published.clone()])),
+			(scheduled.slug(), List::of([published.clone()])),
+		]));
+	}
+
+	#[test]
+	fn shuffled_relations__byte_identical_output() {
+		let posts = ["a", "b", "c", "d"]
+			.map(|slug| PostTestHelper::create_with_slug(slug.into()).unwrap())
+			.to_vec();
+		let mut relations = Vec::new();
+		for post1 in &posts {
+			for post2 in &posts {
+				if post1 != post2 {
+					relations.push(RelationTestHelper::create(post1.clone(), post2.clone(), 50).unwrap());
+				}
+			}
+		}
+		let recommend = |relations: Vec<Relation>| {
+			let recommendations = Recommender::new().recommend(Stream::of(relations), 2).unwrap();
+			RecommendationJson::to_json(recommendations).unwrap().to_string()
+		};
+
+		let output = recommend(relations.clone());
+		for rotation in 1..relations.len() {
+			let mut shuffled = relations.clone();
+			shuffled.rotate_left(rotation);
+			shuffled.reverse();
+
+			assert_that(recommend(shuffled)).is_equal_to(output.clone());
+		}
+	}
 }
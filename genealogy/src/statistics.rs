@@ -0,0 +1,192 @@
+use crate::genealogy::relation_scores::RelationScores;
+use crate::post::Post;
+use crate::statistics::score_distribution::ScoreDistribution;
+use genealogy_java_apis::collection::Collection;
+use genealogy_java_apis::exception::Exception;
+use genealogy_java_apis::stream::Stream;
+use std::cmp::Reverse;
+use std::collections::BTreeMap;
+
+pub mod score_distribution;
+
+/// Statistics about the posts and their relations, to spot drift in the tagging
+/// and to check how well each genealogist separates the posts.
+pub struct Statistics {
+	posts_per_kind_and_year: BTreeMap<String, BTreeMap<String, usize>>,
+	tag_frequencies: Vec<(String, usize)>,
+	posts_without_repository: Vec<Post>,
+	talks_with_video: Vec<Post>,
+	talks_without_video: Vec<Post>,
+	score_distributions: Vec<ScoreDistribution>,
+}
+
+impl Statistics {
+	/// Name of the distribution of the aggregated scores, next to the ones of the relation types.
+	pub const AGGREGATED: &'static str = "aggregated";
+
+	pub fn compute(posts: Collection<Post>, relation_scores: Stream<RelationScores>) -> Result<Statistics, Exception> {
+		let mut posts = posts.into_iter().collect::<Vec<_>>();
+		posts.sort_by_key(Post::slug);
+
+		let mut posts_per_kind_and_year = BTreeMap::<_, BTreeMap<_, _>>::new();
+		let mut tag_frequencies = BTreeMap::new();
+		for post in &posts {
+			*posts_per_kind_and_year
+				.entry(post.kind().to_string())
+				.or_default()
+				.entry(post.date().format("%Y").to_string())
+				.or_insert(0) += 1;
+			for tag in post.tags().into_iter() {
+				*tag_frequencies.entry(tag.text().to_string()).or_insert(0) += 1;
+			}
+		}
+		let mut tag_frequencies = tag_frequencies.into_iter().collect::<Vec<_>>();
+		tag_frequencies.sort_by_key(|(tag, frequency)| (Reverse(*frequency), tag.clone()));
+
+		// talks have no repository and other kinds of posts aren't known to have one
+		let posts_without_repository = posts
+			.iter()
+			.filter(|post| match post {
+				Post::Article(article) => !article.repository().is_present(),
+				Post::Video(video) => !video.repository().is_present(),
+				Post::Talk(_) | Post::Other(_) => false,
+			})
+			.cloned()
+			.collect();
+		let (talks_with_video, talks_without_video) = posts
+			.iter()
+			.filter_map(|post| match post {
+				Post::Talk(talk) => Some((post.clone(), talk.recording().is_present())),
+				_ => None,
+			})
+			.partition::<Vec<_>, _>(|(_, has_video)| *has_video);
+
+		let mut scores_per_type = Vec::<(String, Vec<i64>)>::new();
+		let mut aggregated_scores = Vec::new();
+		for relation_scores in relation_scores.into_iterator() {
+			let relation_scores = relation_scores?;
+			for typed_relation in relation_scores.typed_relations().into_iter() {
+				let relation_type = typed_relation.r#type().value().to_string();
+				match scores_per_type.iter_mut().find(|(name, _)| *name == relation_type) {
+					Some((_, scores)) => scores.push(typed_relation.score()),
+					None => scores_per_type.push((relation_type, vec![typed_relation.score()])),
+				}
+			}
+			aggregated_scores.push(relation_scores.relation().score());
+		}
+		scores_per_type.push((Self::AGGREGATED.to_string(), aggregated_scores));
+		let score_distributions = scores_per_type
+			.into_iter()
+			.flat_map(|(name, scores)| ScoreDistribution::of(name.into(), scores))
+			.collect();
+
+		Ok(Statistics {
+			posts_per_kind_and_year,
+			tag_frequencies,
+			posts_without_repository,
+			talks_with_video: talks_with_video.into_iter().map(|(talk, _)| talk).collect(),
+			talks_without_video: talks_without_video.into_iter().map(|(talk, _)| talk).collect(),
+			score_distributions,
+		})
+	}
+
+	/// Number of posts per kind and per year, both in ascending order.
+	pub fn posts_per_kind_and_year(&self) -> BTreeMap<String, BTreeMap<String, usize>> {
+		self.posts_per_kind_and_year.clone()
+	}
+
+	/// Number of posts per tag, the most frequent tag first.
+	pub fn tag_frequencies(&self) -> Vec<(String, usize)> {
+		self.tag_frequencies.clone()
+	}
+
+	/// Articles and videos without repository, ordered by slug.
+	pub fn posts_without_repository(&self) -> Vec<Post> {
+		self.posts_without_repository.clone()
+	}
+
+	/// Talks whose recording is linked, see [`Corpus::link_talks_and_videos`](crate::post::corpus::Corpus::link_talks_and_videos).
+	/// Talks with a `videoSlug` that matches no video are without video.
+	pub fn talks_with_video(&self) -> Vec<Post> {
+		self.talks_with_video.clone()
+	}
+
+	pub fn talks_without_video(&self) -> Vec<Post> {
+		self.talks_without_video.clone()
+	}
+
+	/// The distribution of the scores of every relation type in the order of the genealogists,
+	/// followed by the one of the aggregated scores, see [`Statistics::AGGREGATED`].
+	pub fn score_distributions(&self) -> Vec<ScoreDistribution> {
+		self.score_distributions.clone()
+	}
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::genealogist::relation_type::RelationType;
+	use crate::genealogist::typed_relation::TypedRelation;
+	use crate::genealogy::relation::Relation;
+	use crate::post::corpus::Corpus;
+	use crate::post::post_type::PostType;
+	use crate::post::test::PostTestHelper;
+	use genealogy_java_apis::list::List;
+	use genealogy_java_apis::path::Path;
+	use genealogy_java_apis::string::JString;
+	use genealogy_java_apis::test::assert_that;
+
+	#[test]
+	fn posts_and_relations__counted_and_distributed() {
+		let post_a = PostTestHelper::create_with_slug("a".into()).unwrap();
+		let post_b = PostTestHelper::create_with_slug("b".into()).unwrap();
+		let tag = RelationType::new("tag".into()).unwrap();
+		let year = post_a.date().format("%Y").to_string();
+		let relation_scores = Stream::of([
+			RelationScores::new(
+				Relation::new(post_a.clone(), post_b.clone(), 40).unwrap(),
+				List::of([TypedRelation::new(post_a.clone(), post_b.clone(), tag.clone(), 40).unwrap()]),
+			),
+			RelationScores::new(
+				Relation::new(post_b.clone(), post_a.clone(), 20).unwrap(),
+				List::of([TypedRelation::new(post_b.clone(), post_a.clone(), tag, 20).unwrap()]),
+			),
+		]);
+
+		let statistics = Statistics::compute(List::of([post_b, post_a]), relation_scores).unwrap();
+
+		assert_that(statistics.posts_per_kind_and_year()["article"][&year]).is_equal_to(2);
+		assert_that(statistics.tag_frequencies()).is_equal_to(vec![("Tag".to_string(), 2)]);
+		assert_that(statistics.posts_without_repository().len()).is_equal_to(2);
+		assert_that(statistics.talks_with_video().len()).is_equal_to(0);
+		let distributions = statistics.score_distributions();
+		assert_that(distributions.len()).is_equal_to(2);
+		assert_that(distributions[0].relation_type()).is_equal_to("tag");
+		assert_that(distributions[1].relation_type()).is_equal_to(Statistics::AGGREGATED);
+		assert_that(distributions[1].mean()).is_equal_to(30.0);
+	}
+
+	#[test]
+	fn talks__with_video_only_if_recording_linked() {
+		let posts = [
+			("linked.md", PostType::Talk, "linked", "video"),
+			("dangling.md", PostType::Talk, "dangling", "missing-video"),
+			("video.md", PostType::Video, "video", "video"),
+		]
+		.map(|(file, post_type, slug, video_slug)| {
+			(
+				Path::of(file),
+				PostTestHelper::create_of_type(post_type, slug, video_slug).unwrap(),
+			)
+		});
+		let (posts, _) = Corpus::link_talks_and_videos(posts.into());
+
+		let statistics =
+			Statistics::compute(List::of(posts.into_iter().map(|(_, post)| post)), Stream::of([])).unwrap();
+
+		let slugs = |talks: Vec<Post>| talks.iter().map(|talk| talk.slug().value()).collect::<Vec<_>>();
+		assert_that(slugs(statistics.talks_with_video())).is_equal_to(vec![JString::from("linked")]);
+		assert_that(slugs(statistics.talks_without_video())).is_equal_to(vec![JString::from("dangling")]);
+	}
+}
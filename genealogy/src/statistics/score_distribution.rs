@@ -0,0 +1,75 @@
+use genealogy_java_apis::optional::Optional;
+use genealogy_java_apis::record;
+use genealogy_java_apis::string::JString;
+
+/// How the scores of one relation type, or the aggregated scores, are distributed.
+///
+/// The percentiles are computed with the nearest rank method, so they are always one of the scores.
+#[record(constructor = false, equals = false, hash = false)]
+pub struct ScoreDistribution {
+	relation_type: JString,
+	count: usize,
+	min: i64,
+	max: i64,
+	mean: f64,
+	p25: i64,
+	median: i64,
+	p75: i64,
+	p90: i64,
+	p99: i64,
+}
+
+impl ScoreDistribution {
+	/// Empty if there are no scores.
+	pub fn of(relation_type: JString, mut scores: Vec<i64>) -> Optional<ScoreDistribution> {
+		if scores.is_empty() {
+			return Optional::empty();
+		}
+
+		scores.sort_unstable();
+		let percentile = |percent: usize| {
+			let rank = (percent * scores.len()).div_ceil(100).max(1);
+			scores[rank - 1]
+		};
+		Optional::of(ScoreDistribution {
+			relation_type,
+			count: scores.len(),
+			min: scores[0],
+			max: scores[scores.len() - 1],
+			mean: (scores.iter().sum::<i64>() as f64) / (scores.len() as f64),
+			p25: percentile(25),
+			median: percentile(50),
+			p75: percentile(75),
+			p90: percentile(90),
+			p99: percentile(99),
+		})
+	}
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod test {
+	use super::*;
+	use genealogy_java_apis::test::assert_that;
+
+	#[test]
+	fn scores__nearest_rank_percentiles() {
+		let scores = (1..=10).rev().map(|score| score * 10).collect();
+
+		let distribution = ScoreDistribution::of("tag".into(), scores).get().unwrap();
+
+		assert_that(distribution.count()).is_equal_to(10);
+		assert_that(distribution.min()).is_equal_to(10);
+		assert_that(distribution.max()).is_equal_to(100);
+		assert_that(distribution.mean()).is_equal_to(55.0);
+		assert_that(distribution.p25()).is_equal_to(30);
+		assert_that(distribution.median()).is_equal_to(50);
+		assert_that(distribution.p90()).is_equal_to(90);
+		assert_that(distribution.p99()).is_equal_to(100);
+	}
+
+	#[test]
+	fn no_scores__empty() {
+		assert!(!ScoreDistribution::of("tag".into(), Vec::new()).is_present());
+	}
+}
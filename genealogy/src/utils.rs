@@ -178,10 +178,10 @@ impl Utils {
 #[macro_export]
 macro_rules! collect_equal_element {
 	() => {
-		crate::utils::Utils::collect_equal_element()
+		$crate::utils::Utils::collect_equal_element()
 	};
 	($predicate: expr) => {
-		crate::utils::Utils::collect_equal_element_with_predicate($predicate)
+		$crate::utils::Utils::collect_equal_element_with_predicate($predicate)
 	};
 }
 
@@ -0,0 +1,33 @@
+use genealogy_java_apis::optional::Optional;
+use genealogy_java_apis::path::Path;
+use genealogy_java_apis::record;
+use genealogy_java_apis::string::JString;
+
+pub mod validator;
+
+/// A problem with the content, found by the [`Validator`](validator::Validator).
+///
+/// `line` is the line number in `file`, starting at 1, and `key` the front matter key the problem
+/// is about, if the problem can be narrowed down that far.
+#[record]
+pub struct ValidationError {
+	file: Path,
+	line: Optional<usize>,
+	key: Optional<JString>,
+	message: JString,
+}
+
+impl ValidationError {
+	/// Describes the error in the format `file:line: key: message` that is common for compiler errors.
+	pub fn describe(&self) -> JString {
+		let mut description = self.file.to_string();
+		if let Ok(line) = self.line.get() {
+			description += &format!(":{}", line);
+		}
+		if let Ok(key) = self.key.get() {
+			description += &format!(": {}", key);
+		}
+		description += &format!(": {}", self.message);
+		description.into()
+	}
+}
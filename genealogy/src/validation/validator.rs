@@ -0,0 +1,580 @@
+use crate::post::corpus::Corpus;
+use crate::post::factories::factory_error::FactoryError;
+use crate::post::factories::front_matter_format::FrontMatterFormat;
+use crate::post::factories::post_factory::PostFactory;
+use crate::post::metadata::Metadata;
+use crate::post::post_type::PostType;
+use crate::post::slug_rules::SlugRules;
+use crate::post::Post;
+use crate::utils::Utils;
+use crate::validation::ValidationError;
+use genealogy_java_apis::exception::Exception;
+use genealogy_java_apis::list::List;
+use genealogy_java_apis::path::Path;
+use genealogy_java_apis::stream::Stream;
+use genealogy_java_apis::string::JString;
+use std::collections::{BTreeMap, HashMap};
+
+/// Validates all posts and collects every error instead of failing on the first one.
+///
+/// The posts are read by their factories, which return the errors of all front matter keys,
+/// see [`PostType::read_post_from_file_lines`]. Besides that, it checks the lines of YAML front matter
+/// and the corpus as a whole:
+/// * every front matter key has to be read by the factory or be known, see [`Metadata::is_known_key`]
+/// * slugs have to follow the [`SlugRules`] and be unique across all post types
+/// * the `videoSlug` of every talk has to match a video
+///
+/// The empty enum has the same effect as a private constructor, preventing instantiation.
+pub enum Validator {}
+
+impl Validator {
+	/// Validates the given files, returning all errors sorted by file and line.
+	/// An empty list means that the posts are valid.
+	pub fn validate(
+		files: impl IntoIterator<Item = (PostType, Stream<Path>)>,
+		slug_rules: &SlugRules,
+	) -> Result<List<ValidationError>, Exception> {
+		let mut contents = Vec::new();
+		for (post_type, files) in files {
+			for file in files.into_iterator() {
+				let file = file?;
+				let lines = Utils::unchecked_files_read_all_lines(file.clone());
+				contents.push(FileContent {
+					file,
+					post_type: post_type.clone(),
+					lines,
+				});
+			}
+		}
+
+		Ok(Self::validate_contents(slug_rules, contents))
+	}
+
+	fn validate_contents(slug_rules: &SlugRules, contents: Vec<FileContent>) -> List<ValidationError> {
+		let mut errors = Vec::new();
+		let posts = contents
+			.into_iter()
+			.filter_map(|content| Self::validate_file(content, &mut errors))
+			.collect::<Vec<_>>();
+		Self::validate_slug_rules(&posts, slug_rules, &mut errors);
+		Self::validate_unique_slugs(&posts, &mut errors);
+		Self::validate_video_slugs(&posts, &mut errors);
+
+		errors.sort_by_key(|error| (error.file().to_string(), error.line().get().ok()));
+		List::of(errors)
+	}
+
+	fn validate_file(content: FileContent, errors: &mut Vec<ValidationError>) -> Option<ValidatedPost> {
+		let FileContent { file, post_type, lines } = content;
+		let lines = match lines {
+			Ok(lines) => lines,
+			Err(exception) => {
+				errors.push(Self::error(&file, None, None, exception.to_string()));
+				return None;
+			}
+		};
+
+		let (lines, key_lines) = match PostFactory::front_matter_format(&lines) {
+			FrontMatterFormat::Yaml => Self::validate_yaml_front_matter(&file, lines, errors)?,
+			format => Self::validate_parsed_front_matter(&file, lines, format, errors)?,
+		};
+		Self::validate_known_keys(&file, &post_type, &key_lines, errors);
+
+		match post_type.read_post_from_file_lines(&file, lines) {
+			Ok(post) => Some(ValidatedPost { file, key_lines, post }),
+			Err(factory_errors) => {
+				errors.extend(
+					factory_errors
+						.into_iter()
+						.map(|error| Self::factory_error(&file, &key_lines, error)),
+				);
+				None
+			}
+		}
+	}
+
+	/// Checks the lines of the front matter and returns the lines of the file without those that have errors,
+	/// so that the factory can still check the other keys, together with the line numbers of the keys.
+	/// Lines that are indented or start with `-` belong to the value of the key before them.
+	fn validate_yaml_front_matter(
+		file: &Path,
+		lines: List<JString>,
+		errors: &mut Vec<ValidationError>,
+	) -> Option<(List<JString>, KeyLines)> {
+		let separator = PostFactory::FRONT_MATTER_SEPARATOR();
+		let mut numbered_lines = lines
+			.clone()
+			.into_iter()
+			.enumerate()
+			.map(|(index, line)| (index + 1, line));
+
+		let Some((start, _)) = numbered_lines.find(|(_, line)| line.strip() == separator) else {
+			errors.push(Self::error(
+				file,
+				None,
+				None,
+				"No front matter found, it has to start with a line containing only '---' or '+++', or with a JSON object.",
+			));
+			return None;
+		};
+
+		let mut key_lines = KeyLines::new(start);
+		let mut invalid_lines = Vec::new();
+		let mut closed = false;
+		for (number, line) in numbered_lines {
+			if line.strip() == separator {
+				closed = true;
+				break;
+			}
+			let line = line.to_string();
+			let is_continuation = line.starts_with([' ', '\t', '-']);
+			if is_continuation || line.trim().is_empty() || line.starts_with('#') {
+				continue;
+			}
+
+			let Some((key, _)) = line.split_once(':') else {
+				errors.push(Self::error(
+					file,
+					Some(number),
+					None,
+					"Line doesn't seem to be a key/value pair (no colon).",
+				));
+				invalid_lines.push(number);
+				continue;
+			};
+			let key = key.trim();
+			if key.is_empty() {
+				errors.push(Self::error(file, Some(number), None, "Line has no key."));
+				invalid_lines.push(number);
+				continue;
+			}
+			if let Some(previous) = key_lines.lines.insert(key.to_string(), number) {
+				errors.push(Self::error(
+					file,
+					Some(number),
+					Some(key.into()),
+					format!("Key is already defined in line {}.", previous),
+				));
+				key_lines.lines.insert(key.to_string(), previous);
+				invalid_lines.push(number);
+			}
+		}
+
+		if !closed {
+			errors.push(Self::error(
+				file,
+				Some(start),
+				None,
+				"Front matter isn't closed with a line containing only '---'.",
+			));
+			return None;
+		}
+
+		let lines = lines
+			.into_iter()
+			.enumerate()
+			.filter(|(index, _)| !invalid_lines.contains(&(index + 1)))
+			.map(|(_, line)| line);
+		Some((List::of(lines), key_lines))
+	}
+
+	/// TOML and JSON front matter can only be checked once it is parsed. The line of a key is the first line of
+	/// the front matter that starts with it, or the first line of the file if there is none, e.g. because
+	/// several keys share a line. If parsing fails, the factory reports the error.
+	fn validate_parsed_front_matter(
+		file: &Path,
+		lines: List<JString>,
+		format: FrontMatterFormat,
+		errors: &mut Vec<ValidationError>,
+	) -> Option<(List<JString>, KeyLines)> {
+		let start = 1;
+		let mut key_lines = KeyLines::new(start);
+		let Ok(front_matter_lines) = PostFactory::read_front_matter(lines.clone()).to_list() else {
+			return Some((lines, key_lines));
+		};
+		let separator = PostFactory::TOML_FRONT_MATTER_SEPARATOR();
+		if format == FrontMatterFormat::Toml && front_matter_lines.length() + 1 == lines.length() {
+			errors.push(Self::error(
+				file,
+				Some(start),
+				None,
+				format!("Front matter isn't closed with a line containing only '{}'.", separator),
+			));
+			return None;
+		}
+
+		let Ok(front_matter) = PostFactory::parse_front_matter(format, front_matter_lines.clone()) else {
+			return Some((lines, key_lines));
+		};
+		// the TOML front matter starts after the separator in the first line
+		let first_line = if format == FrontMatterFormat::Toml {
+			start + 1
+		} else {
+			start
+		};
+		for key in front_matter.keys() {
+			let key = key.to_string();
+			let number = Self::line_of_key(&front_matter_lines, &key)
+				.map(|index| first_line + index)
+				.unwrap_or(start);
+			key_lines.lines.insert(key, number);
+		}
+
+		Some((lines, key_lines))
+	}
+
+	/// The index of the first line that starts with `key` or `"key"`, followed by `=` or `:`.
+	fn line_of_key(front_matter_lines: &List<JString>, key: &str) -> Option<usize> {
+		let quoted_key = format!("\"{}\"", key);
+		front_matter_lines.clone().into_iter().position(|line| {
+			let line = line.to_string();
+			let line = line.trim_start().trim_start_matches(['{', ',']).trim_start();
+			line.strip_prefix(&quoted_key)
+				.or_else(|| line.strip_prefix(key))
+				.is_some_and(|rest| rest.trim_start().starts_with(['=', ':']))
+		})
+	}
+
+	/// Note: Kinds that aren't built-in may read any key, so they are skipped, see [`PostType::read_keys`].
+	fn validate_known_keys(file: &Path, post_type: &PostType, key_lines: &KeyLines, errors: &mut Vec<ValidationError>) {
+		let Ok(read_keys) = post_type.read_keys().get() else {
+			return;
+		};
+		for (key, number) in &key_lines.lines {
+			let key = JString::from(key.as_str());
+			if !read_keys.clone().into_iter().any(|read_key| read_key == key) && !Metadata::is_known_key(&key) {
+				errors.push(Self::error(
+					file,
+					Some(*number),
+					Some(key),
+					format!("Unknown key for {}s.", post_type.name()),
+				));
+			}
+		}
+	}
+
+	/// Errors about a key are reported at its line, the others and those of missing keys at the start of the front matter.
+	fn factory_error(file: &Path, key_lines: &KeyLines, error: FactoryError) -> ValidationError {
+		let key = error.key().get().ok();
+		let line = key
+			.as_ref()
+			.and_then(|key| key_lines.line_of(key.clone()))
+			.unwrap_or(key_lines.start);
+		Self::error(file, Some(line), key, error.exception().to_string())
+	}
+
+	fn validate_slug_rules(posts: &[ValidatedPost], slug_rules: &SlugRules, errors: &mut Vec<ValidationError>) {
+		for post in posts {
+			if let Err(exception) = slug_rules.check(&post.post.slug()) {
+				errors.push(Self::error(
+					&post.file,
+					post.key_lines.line_of(PostFactory::SLUG()),
+					Some(PostFactory::SLUG()),
+					exception.to_string(),
+				));
+			}
+		}
+	}
+
+	fn validate_unique_slugs(posts: &[ValidatedPost], errors: &mut Vec<ValidationError>) {
+		let mut posts_by_slug = BTreeMap::<String, Vec<&ValidatedPost>>::new();
+		for post in posts {
+			posts_by_slug
+				.entry(post.post.slug().value().to_string())
+				.or_default()
+				.push(post);
+		}
+
+		for (slug, posts) in posts_by_slug.into_iter().filter(|(_, posts)| posts.len() > 1) {
+			for post in &posts {
+				let others = posts
+					.iter()
+					.filter(|other| other.file != post.file)
+					.map(|other| format!("{} {}", other.post.kind(), other.file))
+					.collect::<Vec<_>>();
+				errors.push(Self::error(
+					&post.file,
+					post.key_lines.line_of(PostFactory::SLUG()),
+					Some(PostFactory::SLUG()),
+					format!("Slug '{}' is also used by {}.", slug, others.join(", ")),
+				));
+			}
+		}
+	}
+
+	/// Note: Uses the linking of the posts, see [`Corpus::link_talks_and_videos`].
+	fn validate_video_slugs(posts: &[ValidatedPost], errors: &mut Vec<ValidationError>) {
+		let posts_by_file = posts
+			.iter()
+			.map(|post| (post.file.clone(), post.post.clone()))
+			.collect::<Vec<_>>();
+		let (_, dangling_references) = Corpus::link_talks_and_videos(posts_by_file);
+
+		for dangling_reference in dangling_references {
+			let line = posts
+				.iter()
+				.find(|post| post.post.slug() == dangling_reference.talk())
+				.and_then(|post| post.key_lines.line_of(PostFactory::VIDEO()));
+			errors.push(Self::error(
+				&dangling_reference.file(),
+				line,
+				Some(PostFactory::VIDEO()),
+				format!("No video has the videoSlug '{}'.", dangling_reference.video().value()),
+			));
+		}
+	}
+
+	fn error(file: &Path, line: Option<usize>, key: Option<JString>, message: impl Into<JString>) -> ValidationError {
+		ValidationError::new(file.clone(), line.into(), key.into(), message.into())
+	}
+}
+
+/// The contents of a file, it is only read once the posts are validated.
+struct FileContent {
+	file: Path,
+	post_type: PostType,
+	lines: Result<List<JString>, Exception>,
+}
+
+/// The line numbers of the front matter keys of a file and the line the front matter starts in.
+struct KeyLines {
+	start: usize,
+	lines: HashMap<String, usize>,
+}
+
+impl KeyLines {
+	fn new(start: usize) -> Self {
+		Self {
+			start,
+			lines: HashMap::new(),
+		}
+	}
+
+	fn line_of(&self, key: JString) -> Option<usize> {
+		self.lines.get(&key.to_string()).copied()
+	}
+}
+
+/// A post that could be created, with the line numbers of its front matter keys.
+struct ValidatedPost {
+	file: Path,
+	key_lines: KeyLines,
+	post: Post,
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod test {
+	use super::*;
+	use genealogy_java_apis::optional::Optional;
+	use genealogy_java_apis::test::assert_that;
+
+	fn content(file: &str, post_type: PostType, lines: &str) -> FileContent {
+		FileContent {
+			file: Path::of(file),
+			post_type,
+			lines: Ok(List::of(lines.lines().map(JString::from))),
+		}
+	}
+
+	fn describe(errors: List<ValidationError>) -> Vec<String> {
+		errors.into_iter().map(|error| error.describe().to_string()).collect()
+	}
+
+	const ARTICLE: &str = "---\n\
+		title: Article\n\
+		tags: [java]\n\
+		date: 2020-01-23\n\
+		description: \"An article\"\n\
+		slug: article\n\
+		---\n\
+		content";
+
+	#[test]
+	fn valid_posts__no_errors() {
+		let errors = Validator::validate_contents(
+			&SlugRules::default(),
+			vec![content("article.md", PostType::Article, ARTICLE)],
+		);
+
+		assert_that(describe(errors)).is_equal_to(Vec::<String>::new());
+	}
+
+	#[test]
+	fn errors_in_front_matter__all_reported_with_line_and_key() {
+		let lines = "---\n\
+			title: Article\n\
+			tags: [java]\n\
+			date: 2020-13-45\n\
+			no colon\n\
+			unknownKey: value\n\
+			searchKeywords: ignored\n\
+			---";
+
+		let errors = Validator::validate_contents(
+			&SlugRules::default(),
+			vec![content("article.md", PostType::Article, lines)],
+		);
+
+		assert_that(describe(errors)).is_equal_to(vec![
+			"article.md:1: description: IllegalArgumentException: 'Required key 'description' not present in front matter.'"
+				.to_string(),
+			"article.md:4: date: DateTimeException: 'input is out of range'".to_string(),
+			"article.md:5: Line doesn't seem to be a key/value pair (no colon).".to_string(),
+			"article.md:6: unknownKey: Unknown key for articles.".to_string(),
+		]);
+	}
+
+	#[test]
+	fn talk_with_invalid_values_and_website_keys__errors_of_the_factory() {
+		let talk = "---\n\
+			title: Talk\n\
+			tags: [java]\n\
+			date: 2020-01-23\n\
+			description: \"A talk\"\n\
+			slides: not a URI\n\
+			videoSlug: \" \"\n\
+			repo: java-x-demo\n\
+			title: Duplicate\n\
+			---";
+
+		let errors =
+			Validator::validate_contents(&SlugRules::default(), vec![content("talk.md", PostType::Talk, talk)]);
+
+		let errors = describe(errors);
+		assert_that(errors.len()).is_equal_to(3);
+		assert!(errors[0].starts_with("talk.md:6: slides: IllegalArgumentException"));
+		assert!(errors[1].starts_with("talk.md:7: videoSlug: IllegalArgumentException"));
+		assert_that(errors[2].clone()).is_equal_to("talk.md:9: title: Key is already defined in line 2.".to_string());
+	}
+
+	#[test]
+	fn yaml_block_list_and_multi_line_value__no_errors() {
+		let lines = "---\n\
+			title: Article\n\
+			tags:\n  - java\n  - streams, collectors\n\
+			date: 2020-01-23\n\
+			description: >\n  An article\n  in two lines\n\
+			slug: article\n\
+			---";
+
+		let errors = Validator::validate_contents(
+			&SlugRules::default(),
+			vec![content("article.md", PostType::Article, lines)],
+		);
+
+		assert_that(describe(errors)).is_equal_to(Vec::<String>::new());
+	}
+
+	#[test]
+	fn toml_and_json_front_matter__errors_reported_at_key_lines() {
+		let toml = "+++\n\
+			title = \"Article\"\n\
+			tags = [\"java\"]\n\
+			date = 2020-13-45\n\
+			+++";
+		let json = "{\n\
+			  \"title\": \"Article\",\n\
+			  \"tags\": [\"java\"],\n\
+			  \"date\": \"2020-01-23\",\n\
+			  \"description\": \"An article\",\n\
+			  \"slug\": \"article\",\n\
+			  \"unknownKey\": true\n\
+			}\n\
+			content";
+
+		let errors = Validator::validate_contents(
+			&SlugRules::default(),
+			vec![
+				content("toml.md", PostType::Article, toml),
+				content("json.md", PostType::Article, json),
+			],
+		);
+
+		let errors = describe(errors);
+		assert_that(errors.len()).is_equal_to(2);
+		assert!(errors[0].starts_with("json.md:7: unknownKey: Unknown key"));
+		assert!(errors[1].starts_with("toml.md:1: IllegalArgumentException: 'Invalid TOML in front matter"));
+	}
+
+	#[test]
+	fn duplicate_slugs_across_types__reported_for_every_post() {
+		let video = "---\n\
+			title: Video\n\
+			tags: [java]\n\
+			date: 2020-01-23\n\
+			description: \"A video\"\n\
+			slug: article\n\
+			videoSlug: video\n\
+			---";
+
+		let errors = Validator::validate_contents(
+			&SlugRules::default(),
+			vec![
+				content("article.md", PostType::Article, ARTICLE),
+				content("video.md", PostType::Video, video),
+			],
+		);
+
+		assert_that(describe(errors)).is_equal_to(vec![
+			"article.md:6: slug: Slug 'article' is also used by video video.md.".to_string(),
+			"video.md:6: slug: Slug 'article' is also used by article article.md.".to_string(),
+		]);
+	}
+
+	#[test]
+	fn slugs_from_file_names__checked_against_rules_and_each_other() {
+		let without_slug = ARTICLE.replace("slug: article\n", "");
+		let rules = SlugRules::new(true, true, Optional::empty());
+
+		let errors = Validator::validate_contents(
+			&rules,
+			vec![
+				content("2020-01-23-article.md", PostType::Article, &without_slug),
+				content("Article.md", PostType::Article, &without_slug),
+				content("other.md", PostType::Article, ARTICLE),
+			],
+		);
+
+		assert_that(describe(errors)).is_equal_to(vec![
+			"2020-01-23-article.md: slug: Slug 'article' is also used by article other.md.".to_string(),
+			"Article.md: slug: IllegalArgumentException: 'Slug 'Article' has to be lowercase.'".to_string(),
+			"other.md:6: slug: Slug 'article' is also used by article 2020-01-23-article.md.".to_string(),
+		]);
+	}
+
+	#[test]
+	fn talk_with_unknown_video_slug__reported() {
+		let talk = "---\n\
+			title: Talk\n\
+			tags: [java]\n\
+			date: 2020-01-23\n\
+			description: \"A talk\"\n\
+			slug: talk\n\
+			slides: https://slides.codefx.org\n\
+			videoSlug: missing-video\n\
+			---";
+
+		let errors =
+			Validator::validate_contents(&SlugRules::default(), vec![content("talk.md", PostType::Talk, talk)]);
+
+		assert_that(describe(errors)).is_equal_to(vec![
+			"talk.md:8: videoSlug: No video has the videoSlug 'missing-video'.".to_string(),
+		]);
+	}
+
+	#[test]
+	fn unreadable_file__reported_without_line() {
+		let errors = Validator::validate_contents(
+			&SlugRules::default(),
+			vec![FileContent {
+				file: Path::of("missing.md"),
+				post_type: PostType::Article,
+				lines: Err(Exception::UncheckedIO(std::io::ErrorKind::NotFound.into())),
+			}],
+		);
+
+		assert_that(errors.length()).is_equal_to(1);
+		assert_that(errors.get(0).unwrap().line()).is_equal_to(Optional::empty());
+	}
+}